@@ -0,0 +1,68 @@
+use std::fmt;
+use std::io;
+
+
+/// Athena Engine 오류 Enum
+#[derive(Debug)]
+pub enum AthenaError {
+    /// 서버 주소 형식 오류 (주소)
+    ADDRESS_PARSE_FAILED(String),
+    /// 서버 주소 Bind 실패, Port 사용 중 등 (주소, 원인)
+    BIND_FAILED(String, io::Error),
+    /// 서버 주소 Bind 권한 없음, 1024 미만 Port 등 (주소, 원인)
+    PERMISSION_DENIED(String, io::Error),
+    /// 서버 Thread 생성 실패 (원인)
+    THREAD_SPAWN_FAILED(io::Error),
+    /// HTTPS 인증서/개인키 설정 실패 (원인)
+    TLS_CONFIG_FAILED(String),
+    /// 잘못된 Route 경로 패턴 (패턴, 원인)
+    ROUTE_PATTERN_INVALID(String, String),
+    /// 같은 경로와 일치하는 Route 가 이미 등록됨 (등록하려던 패턴, 기존 패턴)
+    ROUTE_CONFLICT(String, String)
+}
+
+
+impl AthenaError {
+    /// Bind 오류를 원인에 맞는 AthenaError 로 변환
+    ///
+    /// # Argument
+    /// address : Bind 하려던 주소
+    ///
+    /// error : Bind 오류
+    pub fn from_bind_error(address : String, error : io::Error) -> AthenaError {
+        return match error.kind() {
+            io::ErrorKind::PermissionDenied => AthenaError::PERMISSION_DENIED(address, error),
+            _ => AthenaError::BIND_FAILED(address, error)
+        };
+    }
+}
+
+
+impl fmt::Display for AthenaError {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            AthenaError::ADDRESS_PARSE_FAILED(address) => write!(f, "Invalid server address: {}", address),
+            AthenaError::BIND_FAILED(address, error) => write!(f, "Failed to bind {}: {}", address, error),
+            AthenaError::PERMISSION_DENIED(address, error) => write!(f, "Permission denied while binding {}: {}", address, error),
+            AthenaError::THREAD_SPAWN_FAILED(error) => write!(f, "Failed to spawn server thread: {}", error),
+            AthenaError::TLS_CONFIG_FAILED(message) => write!(f, "Invalid TLS configuration: {}", message),
+            AthenaError::ROUTE_PATTERN_INVALID(pattern, reason) => write!(f, "Invalid route pattern {}: {}", pattern, reason),
+            AthenaError::ROUTE_CONFLICT(pattern, existing) => write!(f, "Route {} conflicts with {}", pattern, existing)
+        };
+    }
+}
+
+
+impl std::error::Error for AthenaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match self {
+            AthenaError::ADDRESS_PARSE_FAILED(_) => None,
+            AthenaError::BIND_FAILED(_, error) => Some(error),
+            AthenaError::PERMISSION_DENIED(_, error) => Some(error),
+            AthenaError::THREAD_SPAWN_FAILED(error) => Some(error),
+            AthenaError::TLS_CONFIG_FAILED(_) => None,
+            AthenaError::ROUTE_PATTERN_INVALID(_, _) => None,
+            AthenaError::ROUTE_CONFLICT(_, _) => None
+        };
+    }
+}
@@ -1,8 +1,12 @@
 //! # Crate Athena_Engine
 //!
 //! 'Athena_Engine' is an engine that makes web servers simple.
+#![allow(non_camel_case_types, non_snake_case)]
+#![allow(clippy::needless_return, clippy::module_inception, clippy::upper_case_acronyms)]
 
 /// Module - Server
 pub mod server;
 /// Module - Log
-pub mod log;
\ No newline at end of file
+pub mod log;
+/// Module - Error
+pub mod error;
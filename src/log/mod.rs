@@ -14,9 +14,10 @@ pub enum LogTypeTag {
 /// # Examples
 ///
 /// ```
-/// log::log_writer(String::from("This is error!"), String::from("MAIN"), LogTypeTag.INFO)
-/// log::log_writer(String::from("This is error!"), String::from("main"), LogTypeTag.DEBUG)
-/// log::log_writer(String::from("This is error!"), String::from("main"), LogTypeTag.WARNING)
+/// # use AthenaEngine::log::{log_more_text_writer, LogTypeTag};
+/// println!("{}", log_more_text_writer(String::from("This is error!"), String::from("MAIN"), LogTypeTag::INFO, String::from("Hello")));
+/// println!("{}", log_more_text_writer(String::from("This is error!"), String::from("main"), LogTypeTag::DEBUG, String::from("Hello")));
+/// println!("{}", log_more_text_writer(String::from("This is error!"), String::from("main"), LogTypeTag::WARNING, String::from("Hello")));
 /// ```
 ///
 /// # Argument
@@ -53,9 +54,10 @@ pub fn log_more_text_writer(message : String, writer : String, log_tag : LogType
 /// # Examples
 ///
 /// ```
-/// log::log_writer(String::from("This is error!"), String::from("MAIN"), LogTypeTag.INFO, String::from("Hello"))
-/// log::log_writer(String::from("This is error!"), String::from("main"), LogTypeTag.DEBUG, String::from("Hello"))
-/// log::log_writer(String::from("This is error!"), String::from("main"), LogTypeTag.WARNING, String::from("Hello"))
+/// # use AthenaEngine::log::{log_text_writer, LogTypeTag};
+/// println!("{}", log_text_writer(String::from("This is error!"), String::from("MAIN"), LogTypeTag::INFO));
+/// println!("{}", log_text_writer(String::from("This is error!"), String::from("main"), LogTypeTag::DEBUG));
+/// println!("{}", log_text_writer(String::from("This is error!"), String::from("main"), LogTypeTag::WARNING));
 /// ```
 ///
 /// # Argument
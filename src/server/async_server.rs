@@ -0,0 +1,893 @@
+pub mod async_server {
+    use std::future::Future;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::pin::Pin;
+    use std::task::Poll;
+    use std::sync::Arc;
+    use std::sync::atomic::Ordering;
+    use std::time::{Duration, Instant};
+    use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio::task::JoinError;
+    #[cfg(unix)]
+    use tokio::net::{UnixListener, UnixStream};
+    use crate::server::{configure_blocking_stream, find_route, panic_response, respond_event, run_event_handlers, run_request_event, serve_upgrade, state_response_text, too_many_requests_text, websocket_handshake, ClientEvent, ConnectionGuard, ConnectionUpgrade, EventStreamTimer, Http1Connection, ReadStep, Reply, ReplyNext, RequestWork, Server, ServerBuilder, ServerContext, StreamSlot, READ_CHUNK_SIZE, RETRY_AFTER_CONNECTION_LIMIT};
+    use crate::server::request_parser::request_parser::Request;
+    use crate::server::request_reader::request_reader::{RawRequest, RequestReadError};
+    use crate::server::response_parser::response_parser::{state_response_writer, HttpStateCode, Response};
+    use crate::server::server_handle::server_handle::ServerHandle;
+    use crate::server::event_stream::event_stream::EventStream;
+    use crate::server::client_stream::client_stream::ClientStream;
+    use crate::server::middleware::middleware::run_middlewares;
+    use crate::server::proxy::proxy::read_proxy_header_async;
+    use crate::server::listener::listener::{Listener, UNIX_CLIENT_ADDRESS};
+    use crate::server::virtual_host::virtual_host::VirtualHost;
+    use crate::log::{log_more_text_writer, log_text_writer, LogTypeTag};
+    use crate::error::AthenaError;
+
+
+    /// 현재 파일 정보 반환
+    fn get_this_name() -> String {
+        return String::from("main/server/async_server");
+    }
+
+
+    /// 비동기 Response 이벤트 결과
+    pub type AsyncResponseFuture = Pin<Box<dyn Future<Output = Response> + Send + 'static>>;
+    /// 비동기 Response 이벤트 Handler
+    pub type AsyncResponseHandler = Box<dyn Send + Sync + Fn(Request) -> AsyncResponseFuture + 'static>;
+
+
+    impl Server {
+        /// tokio Runtime 에서 서버 실행 (서버가 종료될 때까지 현재 Thread 대기)
+        ///
+        /// # Return
+        /// 서버 주소 Bind 실패시 AthenaError
+        pub fn run_async(self) -> Result<(), AthenaError> {
+            self.start_async()?.wait();
+            return Ok(());
+        }
+
+
+        /// tokio Runtime 에서 서버 시작 (`async` feature)
+        ///
+        /// 접속 대기, TLS Handshake (tokio-rustls), 요청 읽기/응답 쓰기는 Runtime 의 Task 로 실행되므로
+        /// 대기 중인 Keep-Alive 접속이 Thread 를 차지하지 않는다.
+        /// `event_request`/`event_response` 는 `spawn_blocking` 으로 실행되고 (최대 `worker_count` 개 동시 실행),
+        /// 서버나 가상 호스트에 `event_response_async` 로 등록한 Handler 는 Runtime 에서 바로 실행된다.
+        /// HTTP/2, WebSocket 접속은 접속마다 전용 Thread 에서 Blocking 방식으로 처리하고,
+        /// 그 안의 요청도 같은 순서로 이벤트를 실행한다. (`event_response_async` 포함)
+        ///
+        /// # Examples
+        ///
+        /// ```no_run
+        /// # use AthenaEngine::error::AthenaError;
+        /// # use AthenaEngine::server::Server;
+        /// # use AthenaEngine::server::response_parser::response_parser::default_response_writer;
+        /// # fn main() -> Result<(), AthenaError> {
+        /// let handle = Server::builder()
+        ///     .event_response_async(|request| async move { default_response_writer(&request, None, None) })
+        ///     .build()
+        ///     .start_async()?;
+        /// handle.wait();
+        /// # Ok(())
+        /// # }
+        /// ```
+        ///
+        /// # Return
+        /// ServerHandle 구조체, 서버 주소 Bind 또는 Runtime 생성 실패시 AthenaError
+        pub fn start_async(self) -> Result<ServerHandle, AthenaError> {
+            let (listeners, local_addresses) = self.bind_listeners()?;
+            let handle : ServerHandle = ServerHandle::new(Arc::clone(&self.context.state), local_addresses);
+
+            // tokio Runtime 생성
+            let runtime = match tokio::runtime::Builder::new_multi_thread()
+                .thread_name("athena-async")
+                .max_blocking_threads(self.context.config.worker_count.max(1))
+                .enable_io()
+                .enable_time()
+                .build() {
+                Ok(runtime) => runtime,
+                Err(error) => return Err(AthenaError::THREAD_SPAWN_FAILED(error))
+            };
+
+            // 로그 출력
+            println!("{}", log_more_text_writer(String::from("Server started. (async)"), get_this_name(), LogTypeTag::INFO, handle.address_text()));
+
+            let context : Arc<ServerContext> = Arc::clone(&self.context);
+            let spawn_result = std::thread::Builder::new()
+                .name(String::from("athena-accept"))
+                .spawn(move || {
+                    runtime.block_on(serve_listeners(Arc::clone(&context), &listeners));
+                    drop(runtime);
+                    drop(listeners);
+
+                    // 로그 출력
+                    println!("{}", log_text_writer(String::from("Server stopped."), get_this_name(), LogTypeTag::INFO));
+
+                    context.state.mark_finished();
+                });
+            if let Err(error) = spawn_result {
+                return Err(AthenaError::THREAD_SPAWN_FAILED(error));
+            }
+
+            return Ok(handle);
+        }
+    }
+
+
+
+
+    impl ServerBuilder {
+        /// 비동기 Response 이벤트 설정 (`async` feature, `start_async` 에서 사용)
+        ///
+        /// 등록하면 `event_response` 대신 사용된다. 일치하는 Route 가 있으면 Route 가 먼저 실행된다.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use AthenaEngine::server::Server;
+        /// # use AthenaEngine::server::response_parser::response_parser::default_response_writer;
+        /// # let _ =
+        /// Server::builder().event_response_async(|request| async move {
+        ///     default_response_writer(&request, None, None)
+        /// })
+        /// # ;
+        /// ```
+        pub fn event_response_async<F, Fut>(mut self, handler : F) -> ServerBuilder
+            where F : Fn(Request) -> Fut + Send + Sync + 'static,
+                  Fut : Future<Output = Response> + Send + 'static {
+            self.event.event_response_async = Some(async_response_handler(handler));
+            return self;
+        }
+    }
+
+
+    impl VirtualHost {
+        /// 비동기 Response 이벤트 설정 (`async` feature, `start_async` 에서 사용)
+        ///
+        /// 등록하면 이 Host 의 요청에 `event_response` 대신 사용된다. 일치하는 Route 가 있으면 Route 가 먼저 실행된다.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use AthenaEngine::server::response_parser::response_parser::default_response_writer;
+        /// # use AthenaEngine::server::virtual_host::virtual_host::VirtualHost;
+        /// # let _ =
+        /// VirtualHost::new().event_response_async(|request| async move {
+        ///     default_response_writer(&request, None, None)
+        /// })
+        /// # ;
+        /// ```
+        pub fn event_response_async<F, Fut>(mut self, handler : F) -> VirtualHost
+            where F : Fn(Request) -> Fut + Send + Sync + 'static,
+                  Fut : Future<Output = Response> + Send + 'static {
+            self.event.event_response_async = Some(async_response_handler(handler));
+            return self;
+        }
+    }
+
+
+    /// 비동기 함수를 비동기 Response 이벤트 Handler 로 변환
+    fn async_response_handler<F, Fut>(handler : F) -> AsyncResponseHandler
+        where F : Fn(Request) -> Fut + Send + Sync + 'static,
+              Fut : Future<Output = Response> + Send + 'static {
+        return Box::new(move |request : Request| -> AsyncResponseFuture {
+            Box::pin(handler(request))
+        });
+    }
+
+
+    /// 모든 리스너에서 접속 대기 (종료 요청 후 모든 접속이 끝나면 반환)
+    async fn serve_listeners(context : Arc<ServerContext>, listeners : &[Listener]) {
+        let mut tasks = Vec::with_capacity(listeners.len());
+        for listener in listeners {
+            match to_async_listener(listener) {
+                Ok(listener) => tasks.push(tokio::spawn(accept_loop(Arc::clone(&context), listener))),
+                Err(error) => {
+                    // 오류 로그 작성
+                    println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::FATAL));
+                }
+            }
+        }
+        for task in tasks {
+            let _ = task.await;
+        }
+
+        // 처리 중인 접속 종료 대기
+        while context.connections.load(Ordering::SeqCst) > 0 {
+            tokio::time::sleep(context.config.shutdown_poll_interval).await;
+        }
+    }
+
+
+    /// tokio 리스너
+    enum AsyncListener {
+        TCP(TcpListener),
+        #[cfg(unix)]
+        UNIX(UnixListener)
+    }
+
+
+    /// 리스너를 tokio 리스너로 변환 (Socket 파일 정리를 위해 원래 리스너는 유지)
+    fn to_async_listener(listener : &Listener) -> std::io::Result<AsyncListener> {
+        return match listener {
+            Listener::TCP(listener) => Ok(AsyncListener::TCP(TcpListener::from_std(listener.try_clone()?)?)),
+            #[cfg(unix)]
+            Listener::UNIX(listener, _) => Ok(AsyncListener::UNIX(UnixListener::from_std(listener.try_clone()?)?))
+        };
+    }
+
+
+    /// 리스너 하나의 접속 대기 (종료 요청시 반환)
+    async fn accept_loop(context : Arc<ServerContext>, listener : AsyncListener) {
+        let poll_interval : Duration = context.config.shutdown_poll_interval;
+
+        // 연결 대기
+        while !context.state.is_shutdown() {
+            // Stream 추출
+            let accepted = match &listener {
+                AsyncListener::TCP(listener) => tokio::time::timeout(poll_interval, listener.accept()).await
+                    .map(|result| result.map(|(stream, client_address)| (AsyncStream::TCP(stream), client_address.ip().to_canonical().to_string()))),
+                #[cfg(unix)]
+                AsyncListener::UNIX(listener) => tokio::time::timeout(poll_interval, listener.accept()).await
+                    .map(|result| result.map(|(stream, _)| (AsyncStream::UNIX(stream), String::from(UNIX_CLIENT_ADDRESS))))
+            };
+            let (stream, client_ip) = match accepted {
+                Ok(Ok(accepted)) => accepted, // 작업 성공!
+                Ok(Err(error)) => { // 작업 실패, 예외 처리
+                    // 오류 로그 작성
+                    println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+                    tokio::time::sleep(poll_interval).await;
+                    continue;
+                },
+                Err(_) => continue // 대기 중인 접속 없음
+            };
+            // 최대 접속 수 확인
+            let connections : usize = context.connections.load(Ordering::SeqCst);
+            if connections >= context.config.max_connections {
+                // 로그 출력
+                println!("{}", log_more_text_writer(String::from("Connection rejected, too many open connections."), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", client_ip)));
+
+                reject_stream(&context, stream, state_response_text(&context.config, HttpStateCode::HTTP_503)).await;
+                continue;
+            }
+            // IP 별 최대 접속 수 확인
+            if !context.rate_limiter.acquire_connection(&client_ip) {
+                // 로그 출력
+                println!("{}", log_more_text_writer(String::from("Connection rejected, too many connections from this client."), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", client_ip)));
+
+                reject_stream(&context, stream, too_many_requests_text(&context.config, RETRY_AFTER_CONNECTION_LIMIT)).await;
+                continue;
+            }
+            context.connections.fetch_add(1, Ordering::SeqCst);
+
+            // 로그 출력
+            println!("{}", log_more_text_writer(String::from("Connection accepted."), get_this_name(), LogTypeTag::INFO, format!("IP:{} CONNECTIONS:{}/{}", client_ip, connections + 1, context.config.max_connections)));
+
+            let guard : ConnectionGuard = ConnectionGuard::new(&context, client_ip.clone());
+            let context : Arc<ServerContext> = Arc::clone(&context);
+            tokio::spawn(async move {
+                // 접속이 끝나면 접속 수 반환
+                let _guard : ConnectionGuard = guard;
+                handle_connection(&context, stream, client_ip).await;
+            });
+        }
+    }
+
+
+    /// tokio 클라이언트 Stream
+    enum AsyncStream {
+        TCP(TcpStream),
+        #[cfg(unix)]
+        UNIX(UnixStream)
+    }
+
+
+    /// 처리하지 않을 접속에 응답 후 종료
+    async fn reject_stream(context : &ServerContext, stream : AsyncStream, response : String) {
+        match stream {
+            AsyncStream::TCP(stream) => reject_connection(context, stream, true, response).await,
+            #[cfg(unix)]
+            AsyncStream::UNIX(stream) => reject_connection(context, stream, false, response).await
+        }
+    }
+
+
+    /// 접속을 닫기 전 마지막 응답 전송
+    ///
+    /// # Argument
+    /// is_tcp : TCP 접속 여부 (HTTPS 리스너의 TCP 접속은 Handshake 없이 평문 응답을 보낼 수 없으므로 바로 종료)
+    async fn reject_connection<S : AsyncRead + AsyncWrite + Unpin>(context : &ServerContext, mut stream : S, is_tcp : bool, response : String) {
+        #[cfg(feature = "tls")]
+        if context.tls.is_some() && is_tcp {
+            return;
+        }
+        #[cfg(not(feature = "tls"))]
+        let _ = is_tcp;
+
+        if let Err(error) = write_response(context, &mut stream, response.as_bytes()).await {
+            // 로그 출력
+            println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+            return;
+        }
+
+        // 이미 받은 요청 데이터 버리기 (남은 채로 닫으면 RST 로 응답이 버려질 수 있다)
+        let mut buffer : [u8; READ_CHUNK_SIZE] = [0; READ_CHUNK_SIZE];
+        let mut discarded : usize = 0;
+        while discarded < context.config.max_header_bytes {
+            match tokio::time::timeout(Duration::ZERO, stream.read(&mut buffer)).await {
+                Ok(Ok(read_size)) if read_size > 0 => discarded += read_size,
+                _ => break
+            }
+        }
+    }
+
+
+    /// Athena Engine Client 접근 처리 함수
+    async fn handle_connection(context : &Arc<ServerContext>, mut stream : AsyncStream, client_ip : String) {
+        let client_ip : String = match proxy_client_ip(context, &mut stream, client_ip).await {
+            Some(client_ip) => client_ip,
+            None => return
+        };
+
+        match stream {
+            // HTTPS 리스너의 TCP 접속은 TLS Handshake 후 같은 방식으로 처리
+            #[cfg(feature = "tls")]
+            AsyncStream::TCP(stream) if context.tls.is_some() => {
+                if let Some(stream) = accept_tls(context, stream, &client_ip).await {
+                    serve_stream(context, stream, true, client_ip).await;
+                }
+            },
+            AsyncStream::TCP(stream) => serve_stream(context, stream, false, client_ip).await,
+            #[cfg(unix)]
+            AsyncStream::UNIX(stream) => serve_stream(context, stream, false, client_ip).await
+        }
+    }
+
+
+    /// TCP 접속에 TLS 적용 (Handshake 는 `header_timeout` 안에 끝나야 한다)
+    #[cfg(feature = "tls")]
+    async fn accept_tls(context : &ServerContext, stream : TcpStream, client_ip : &str) -> Option<tokio_rustls::server::TlsStream<TcpStream>> {
+        let acceptor : tokio_rustls::TlsAcceptor = context.tls.as_ref()?.async_acceptor();
+        let error : String = match tokio::time::timeout(context.config.header_timeout, acceptor.accept(stream)).await {
+            Ok(Ok(stream)) => return Some(stream),
+            Ok(Err(error)) => error.to_string(),
+            Err(_) => String::from("TLS handshake timed out.")
+        };
+        // 로그 출력
+        println!("{}", log_more_text_writer(error, get_this_name(), LogTypeTag::WARNING, format!("IP:{}", client_ip)));
+
+        return None;
+    }
+
+
+    /// 요청을 처리할 tokio 클라이언트 Stream
+    trait AsyncClientStream : AsyncRead + AsyncWrite + Unpin + Send {
+        /// Blocking `ClientStream` 으로 변환 (Upgrade 된 접속을 전용 Thread 로 넘길 때 사용)
+        fn into_client_stream(self) -> std::io::Result<ClientStream>;
+    }
+
+
+    impl AsyncClientStream for TcpStream {
+        fn into_client_stream(self) -> std::io::Result<ClientStream> {
+            return Ok(ClientStream::TCP(self.into_std()?));
+        }
+    }
+
+
+    #[cfg(unix)]
+    impl AsyncClientStream for UnixStream {
+        fn into_client_stream(self) -> std::io::Result<ClientStream> {
+            return Ok(ClientStream::UNIX(self.into_std()?));
+        }
+    }
+
+
+    #[cfg(feature = "tls")]
+    impl AsyncClientStream for tokio_rustls::server::TlsStream<TcpStream> {
+        fn into_client_stream(self) -> std::io::Result<ClientStream> {
+            // 이미 복호화한 데이터는 rustls 접속에 남아 있으므로 그대로 넘긴다
+            let (stream, connection) = self.into_inner();
+            return Ok(ClientStream::TLS(Box::new(rustls::StreamOwned::new(connection, stream.into_std()?))));
+        }
+    }
+
+
+    /// 접속 하나의 요청 처리 후 Upgrade 된 접속 처리 및 접속 종료
+    ///
+    /// # Argument
+    /// secure : TLS 접속 여부
+    async fn serve_stream<S : AsyncClientStream>(context : &Arc<ServerContext>, mut stream : S, secure : bool, client_ip : String) {
+        // 스트림 접속은 끝날 때까지 스트림 접속 수에 포함
+        match serve_connection(context, &mut stream, secure, &client_ip).await {
+            // Server-Sent Events 는 Runtime 에서 전송
+            Some((ConnectionUpgrade::EVENT_STREAM(event_stream, request_ip), _slot)) => {
+                serve_event_stream(context, &mut stream, event_stream, &request_ip).await;
+                close_stream(context, &mut stream).await;
+            },
+            Some((upgrade, _slot)) => match stream.into_client_stream() {
+                Ok(stream) => run_upgrade(context, stream, upgrade, client_ip).await,
+                Err(error) => {
+                    // 로그 출력
+                    println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+                }
+            },
+            None => close_stream(context, &mut stream).await
+        }
+    }
+
+
+    /// 접속 종료 (TLS 접속은 close_notify 전송)
+    async fn close_stream<S : AsyncWrite + Unpin>(context : &ServerContext, stream : &mut S) {
+        let _ = tokio::time::timeout(context.config.write_timeout, stream.shutdown()).await;
+    }
+
+
+    /// PROXY protocol Header 로 클라이언트 주소 확인 (`header_timeout` 안에 받지 못하면 실패)
+    ///
+    /// # Return
+    /// 클라이언트 주소 (PROXY protocol 을 사용하지 않으면 접속한 주소), Header 가 잘못되었으면 None
+    async fn proxy_client_ip(context : &ServerContext, stream : &mut AsyncStream, client_ip : String) -> Option<String> {
+        if !context.config.proxy_protocol {
+            return Some(client_ip);
+        }
+
+        let read = async {
+            return match stream {
+                AsyncStream::TCP(stream) => read_proxy_header_async(stream).await,
+                #[cfg(unix)]
+                AsyncStream::UNIX(stream) => read_proxy_header_async(stream).await
+            };
+        };
+        let error : String = match tokio::time::timeout(context.config.header_timeout, read).await {
+            Ok(Ok(address)) => return Some(address.map_or(client_ip, |address| address.to_string())),
+            Ok(Err(error)) => error.to_string(),
+            Err(_) => String::from("timed out")
+        };
+        // 로그 출력
+        println!("{}", log_more_text_writer(format!("PROXY protocol header read failed: {}", error), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", client_ip)));
+
+        return None;
+    }
+
+
+    /// Upgrade 된 접속의 WebSocket Handler 또는 HTTP/2 접속 처리
+    ///
+    /// WebSocket 과 HTTP/2 는 Blocking Stream 을 사용하므로 접속이 끝날 때까지 전용 Thread 하나를 사용한다. (`max_stream_connections` 로 제한)
+    /// HTTP/2 Stream 의 비동기 Response 이벤트는 서버 Runtime 에서 실행한다.
+    async fn run_upgrade(context : &Arc<ServerContext>, stream : ClientStream, upgrade : ConnectionUpgrade, client_ip : String) {
+        configure_blocking_stream(&context.config, &stream);
+
+        let context : Arc<ServerContext> = Arc::clone(context);
+        let runtime : tokio::runtime::Handle = tokio::runtime::Handle::current();
+        run_connection_thread(move || {
+            serve_upgrade(&context, stream, upgrade, &client_ip, &|request, request_ip| {
+                runtime.block_on(run_event_handlers_async(&context, request, request_ip))
+            });
+        }).await;
+    }
+
+
+    /// 접속이 끝날 때까지 Thread 를 차지하는 작업을 전용 Thread 에서 실행하고 종료 대기
+    ///
+    /// 동기 이벤트 Handler 가 사용하는 `spawn_blocking` Thread (최대 `worker_count` 개) 를 접속이 차지하면
+    /// 접속 안의 Handler 가 실행되지 못할 수 있으므로 별도 Thread 를 사용한다. (Thread 수는 `max_stream_connections` 로 제한)
+    async fn run_connection_thread<F : FnOnce() + Send + 'static>(task : F) {
+        let (sender, receiver) = tokio::sync::oneshot::channel::<()>();
+        let spawn_result = std::thread::Builder::new()
+            .name(String::from("athena-connection"))
+            .spawn(move || {
+                task();
+                let _ = sender.send(());
+            });
+        match spawn_result {
+            // Handler 가 Panic 으로 끝나도 Sender 가 Drop 되어 대기가 끝난다
+            Ok(_) => { let _ = receiver.await; },
+            Err(error) => {
+                // 오류 로그 작성
+                println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+            }
+        }
+    }
+
+
+    /// 접속 하나에서 Keep-Alive 가 끝날 때까지 요청 처리 (동기 서버와 같은 `Http1Connection` 사용)
+    ///
+    /// # Return
+    /// WebSocket Handshake 에 성공하거나, HTTP/2 로 전환하거나, Server-Sent Events 응답을 보내면 접속을 넘길 Upgrade 요청과 예약한 스트림 접속
+    async fn serve_connection<S : AsyncRead + AsyncWrite + Unpin>(context : &Arc<ServerContext>, stream : &mut S, secure : bool, client_ip : &str) -> Option<(ConnectionUpgrade, StreamSlot)> {
+        let mut connection : Http1Connection = Http1Connection::new(context, String::from(client_ip), secure);
+
+        loop {
+            let reply : Reply = match read_request(context, &mut connection, stream).await {
+                Ok(raw_request) => match connection.on_request(context, raw_request) {
+                    RequestWork::EVENT(request, pending) => {
+                        // Request / Response 이벤트 실행
+                        let response : Option<Response> = run_event_handlers_async(context, request, &pending.request_ip).await;
+                        pending.reply(context, response)
+                    },
+                    RequestWork::WEBSOCKET(request, request_ip) => {
+                        let (request, handshake) = websocket_handshake_async(context, request, request_ip).await?;
+                        connection.websocket_reply(request, handshake)
+                    },
+                    RequestWork::REPLY(reply) => reply
+                },
+                Err(error) => connection.read_error_reply(context, error)
+            };
+            let (reply, slot) : (Reply, Option<StreamSlot>) = reply.reserve_stream(context, client_ip);
+
+            // 응답 전송
+            if let Some(text) = &reply.text {
+                if let Err(error) = write_response(context, stream, text.as_bytes()).await {
+                    // 로그 출력
+                    println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+                    return None;
+                }
+            }
+            match reply.next {
+                ReplyNext::KEEP_ALIVE => {},
+                ReplyNext::CLOSE => return None,
+                ReplyNext::UPGRADE(upgrade) => return slot.map(|slot| (upgrade, slot))
+            }
+        }
+    }
+
+
+    /// Stream 에서 요청 하나를 읽기 (Keep-Alive 접속은 다음 요청의 데이터가 도착할 때까지 대기)
+    async fn read_request<S : AsyncRead + AsyncWrite + Unpin>(context : &ServerContext, connection : &mut Http1Connection, stream : &mut S) -> Result<RawRequest, RequestReadError> {
+        let mut chunk = [0; READ_CHUNK_SIZE];
+
+        loop {
+            match connection.read_step(context, Instant::now())? {
+                ReadStep::REQUEST(raw_request) => return Ok(raw_request),
+                ReadStep::SEND(interim) => {
+                    if let Err(error) = write_response(context, stream, interim.as_bytes()).await {
+                        return Err(RequestReadError::IO(error));
+                    }
+                },
+                ReadStep::READ(timeout) => {
+                    let result = match tokio::time::timeout(timeout, stream.read(&mut chunk)).await {
+                        Ok(result) => result,
+                        Err(_) => Err(std::io::Error::from(std::io::ErrorKind::TimedOut))
+                    };
+                    connection.on_read(result.map(|size| &chunk[..size]))?;
+                }
+            }
+        }
+    }
+
+
+    /// WebSocket Upgrade 요청 확인 (Middleware 는 동기 Handler 이므로 `spawn_blocking` 으로 실행)
+    ///
+    /// # Return
+    /// 요청과 `websocket_handshake` 결과, 작업이 취소되면 None
+    async fn websocket_handshake_async(context : &Arc<ServerContext>, mut request : Request, request_ip : String) -> Option<(Request, Result<String, String>)> {
+        let task_context : Arc<ServerContext> = Arc::clone(context);
+        let result = tokio::task::spawn_blocking(move || {
+            let handshake : Result<String, String> = websocket_handshake(&task_context, &mut request, &request_ip);
+            (request, handshake)
+        }).await;
+
+        return match result {
+            Ok(result) => Some(result),
+            Err(error) => {
+                // 로그 출력
+                println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+                None
+            }
+        };
+    }
+
+
+    /// Server-Sent Events 스트림 전송
+    ///
+    /// 모든 전송기가 Drop 되거나, 클라이언트 접속이 끊기거나, 서버가 종료될 때까지 이벤트를 전송한다.
+    async fn serve_event_stream<S : AsyncRead + AsyncWrite + Unpin>(context : &ServerContext, stream : &mut S, event_stream : EventStream, client_ip : &str) {
+        // 로그 출력
+        println!("{}", log_more_text_writer(String::from("Event stream opened."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+
+        let mut timer : EventStreamTimer = EventStreamTimer::new(&context.config);
+        let mut buffer = [0; 512];
+        while !context.state.is_shutdown() {
+            // 이벤트와 클라이언트 접속 종료를 함께 대기
+            let received = tokio::time::timeout(timer.wait_time(), std::future::poll_fn(|cx| {
+                if let Poll::Ready(event) = event_stream.poll_receive(cx) {
+                    return Poll::Ready(Some(event));
+                }
+                let mut read_buffer : ReadBuf = ReadBuf::new(&mut buffer);
+                return match Pin::new(&mut *stream).poll_read(cx, &mut read_buffer) {
+                    // 스트림 전송 중 클라이언트가 보낸 데이터는 버린다
+                    Poll::Ready(Ok(())) if !read_buffer.filled().is_empty() => {
+                        cx.waker().wake_by_ref();
+                        Poll::Pending
+                    },
+                    Poll::Ready(_) => Poll::Ready(None),
+                    Poll::Pending => Poll::Pending
+                };
+            })).await;
+
+            let frame : String = match received {
+                Ok(Some(Some(event))) => event.to_frame(),
+                // 스트림 종료 또는 클라이언트 접속 종료
+                Ok(Some(None)) | Ok(None) => break,
+                Err(_) => match timer.keep_alive_frame() {
+                    Some(frame) => frame,
+                    None => continue
+                }
+            };
+            if write_response(context, stream, frame.as_bytes()).await.is_err() {
+                break;
+            }
+            timer.written();
+        }
+
+        // 로그 출력
+        println!("{}", log_more_text_writer(String::from("Event stream closed."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+    }
+
+
+    /// Request / Response 이벤트 실행
+    ///
+    /// 요청의 Host 에 비동기 Response 이벤트가 있고 일치하는 Route 가 없으면 비동기 Handler 를 서버 Runtime 에서 실행한다.
+    /// 동기 Handler 는 Runtime Thread 를 막지 않도록 `spawn_blocking` 으로 실행한다.
+    pub(in crate::server) async fn run_event_handlers_async(context : &Arc<ServerContext>, request : Request, client_ip : &str) -> Option<Response> {
+        // 알 수 없는 Host 응답, Route, 비동기 Handler 가 없는 Host 는 동기 Handler 로 처리
+        let async_event : Option<(&ClientEvent, &AsyncResponseHandler)> = context.client_event(&request)
+            .filter(|event| find_route(event, &request).is_none())
+            .and_then(|event| Some((event, event.event_response_async.as_ref()?)));
+        let (event, handler) = match async_event {
+            Some(async_event) => async_event,
+            None => {
+                let task_context : Arc<ServerContext> = Arc::clone(context);
+                let task_client_ip : String = client_ip.to_string();
+                let mut request : Request = request;
+                return match tokio::task::spawn_blocking(move || run_event_handlers(&task_context, &mut request, &task_client_ip)).await {
+                    Ok(result) => result,
+                    Err(error) => join_error_response(context, error, client_ip)
+                };
+            }
+        };
+        // Middleware 는 Blocking Thread 에서 실행하고 비동기 Response 이벤트는 서버 Runtime 에서 기다린다
+        if !event.middlewares.is_empty() {
+            return run_async_event_with_middlewares(context, request, client_ip).await;
+        }
+
+        // Request 이벤트 실행
+        let request : Request = if event.event_request.is_some() {
+            let task_context : Arc<ServerContext> = Arc::clone(context);
+            let task_client_ip : String = client_ip.to_string();
+            match tokio::task::spawn_blocking(move || {
+                run_request_event(&task_context, &request, &task_client_ip);
+                request
+            }).await {
+                Ok(request) => request,
+                Err(error) => return join_error_response(context, error, client_ip)
+            }
+        }else {
+            run_request_event(context, &request, client_ip);
+            request
+        };
+
+        // 로그 출력
+        println!("{}", log_more_text_writer(String::from("Run async response EVENT handler."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+
+        // Future 생성과 실행 중 발생한 Panic 모두 500 응답으로 변환
+        let future : AsyncResponseFuture = match panic::catch_unwind(AssertUnwindSafe(|| handler(request))) {
+            Ok(future) => future,
+            Err(payload) => return Some(panic_response(context, payload.as_ref(), client_ip))
+        };
+        return match tokio::spawn(future).await {
+            Ok(response) => Some(response),
+            Err(error) => join_error_response(context, error, client_ip)
+        };
+    }
+
+
+    /// Middleware 를 거쳐 비동기 Response 이벤트 실행
+    ///
+    /// 비동기 Handler 에는 Middleware 가 수정한 요청의 복사본을 전달한다.
+    async fn run_async_event_with_middlewares(context : &Arc<ServerContext>, mut request : Request, client_ip : &str) -> Option<Response> {
+        let task_context : Arc<ServerContext> = Arc::clone(context);
+        let task_client_ip : String = client_ip.to_string();
+        let runtime : tokio::runtime::Handle = tokio::runtime::Handle::current();
+        let result = tokio::task::spawn_blocking(move || {
+            let context : &ServerContext = &task_context;
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                run_request_event(context, &request, &task_client_ip);
+                let event : &ClientEvent = match context.client_event(&request) {
+                    Some(event) => event,
+                    None => return state_response_writer(HttpStateCode::HTTP_404, &context.config)
+                };
+                run_middlewares(&event.middlewares, &mut request, &|request| {
+                    // Middleware 가 없을 때와 같이 Route, 비동기 Response 이벤트, Response 이벤트 순서로 처리
+                    match &event.event_response_async {
+                        Some(handler) if find_route(event, request).is_none() => {
+                            // 로그 출력
+                            println!("{}", log_more_text_writer(String::from("Run async response EVENT handler."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", task_client_ip)));
+
+                            runtime.block_on(handler(request.clone()))
+                        },
+                        _ => respond_event(context, event, request, &task_client_ip)
+                            .unwrap_or_else(|| state_response_writer(HttpStateCode::HTTP_404, &context.config))
+                    }
+                })
+            }));
+            return match result {
+                Ok(response) => response,
+                Err(payload) => panic_response(context, payload.as_ref(), &task_client_ip)
+            };
+        }).await;
+
+        return match result {
+            Ok(response) => Some(response),
+            Err(error) => join_error_response(context, error, client_ip)
+        };
+    }
+
+
+    /// 실패한 Handler 작업 처리
+    ///
+    /// # Return
+    /// Panic 으로 실패했으면 `500 Internal Server Error` 응답, 취소된 경우 None
+    fn join_error_response(context : &ServerContext, error : JoinError, client_ip : &str) -> Option<Response> {
+        return match error.try_into_panic() {
+            Ok(payload) => Some(panic_response(context, payload.as_ref(), client_ip)),
+            Err(error) => {
+                // 로그 출력
+                println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+                None
+            }
+        };
+    }
+
+
+    /// 응답 전송 (`write_timeout` 적용)
+    async fn write_response<S : AsyncWrite + Unpin>(context : &ServerContext, stream : &mut S, response : &[u8]) -> std::io::Result<()> {
+        let write = async {
+            stream.write_all(response).await?;
+            stream.flush().await
+        };
+        return match tokio::time::timeout(context.config.write_timeout, write).await {
+            Ok(result) => result,
+            Err(_) => Err(std::io::Error::from(std::io::ErrorKind::TimedOut))
+        };
+    }
+
+
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::io::{Read, Write};
+        use crate::server::response_parser::response_parser::{default_state_response_writer, ResponseBody};
+        use crate::server::event_stream::event_stream::{event_stream_response, Event};
+        use crate::server::router::router::Router;
+        use crate::server::test_client::test_client::{read_response, TestRequest};
+
+
+        /// 고정 Body 응답
+        fn text(body : &str) -> Response {
+            let mut response : Response = default_state_response_writer(HttpStateCode::HTTP_200);
+            response.body = Some(ResponseBody { body_str: Some(String::from(body)) });
+            return response;
+        }
+
+
+        #[test]
+        fn runs_async_handlers_for_virtual_hosts_on_one_connection() {
+            let api = VirtualHost::new()
+                .router(Router::new().get("/sync", |_request| text("api route")).unwrap())
+                .event_response_async(|request| async move {
+                    text(&format!("api async {}", request.path().unwrap_or("")))
+                });
+            let handle = Server::builder()
+                .bind(String::from("127.0.0.1"), 0)
+                .event_response(|_request| text("server"))
+                .virtual_host(String::from("api.example.com"), api)
+                .build()
+                .start_async()
+                .unwrap();
+
+            let mut client : std::net::TcpStream = std::net::TcpStream::connect(handle.local_addr()).unwrap();
+            client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+            for (host, path, body) in [("api.example.com", "/items", "api async /items"), ("api.example.com", "/sync", "api route"), ("other.org", "/", "server")] {
+                client.write_all(&TestRequest::get(path).header("Host", host).to_bytes()).unwrap();
+                read_response(&mut client)
+                    .assert_header("Connection", "keep-alive")
+                    .assert_body(body);
+            }
+
+            handle.shutdown();
+        }
+
+
+        #[test]
+        fn hands_websocket_upgrades_to_the_handler() {
+            let handle = Server::builder()
+                .bind(String::from("127.0.0.1"), 0)
+                .websocket(String::from("/chat"), |_request, mut websocket| {
+                    let _ = websocket.send_text("hello");
+                })
+                .build()
+                .start_async()
+                .unwrap();
+
+            let mut client : std::net::TcpStream = std::net::TcpStream::connect(handle.local_addr()).unwrap();
+            client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+            client.write_all(&TestRequest::get("/chat")
+                .header("Upgrade", "websocket")
+                .header("Connection", "Upgrade")
+                .header("Sec-WebSocket-Key", "dGhlIHNhbXBsZSBub25jZQ==")
+                .header("Sec-WebSocket-Version", "13")
+                .to_bytes()).unwrap();
+            read_response(&mut client).assert_header("Sec-WebSocket-Accept", "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+            let mut frame = [0; 7];
+            client.read_exact(&mut frame).unwrap();
+            assert_eq!(&frame, b"\x81\x05hello");
+
+            handle.shutdown();
+        }
+
+
+        #[test]
+        fn answers_async_handler_panics_with_500() {
+            let handle = Server::builder()
+                .bind(String::from("127.0.0.1"), 0)
+                .event_response_async(|request| {
+                    if request.path() == Some("/before") {
+                        panic!("handler failed");
+                    }
+                    async move {
+                        tokio::task::yield_now().await;
+                        if request.path() == Some("/after") {
+                            panic!("future failed");
+                        }
+                        text("ok")
+                    }
+                })
+                .build()
+                .start_async()
+                .unwrap();
+
+            // Future 를 만들 때와 실행 중의 Panic 모두 500 응답, 접속은 유지
+            let mut client : std::net::TcpStream = std::net::TcpStream::connect(handle.local_addr()).unwrap();
+            client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+            for (path, status) in [("/before", 500), ("/after", 500), ("/", 200)] {
+                client.write_all(&TestRequest::get(path).to_bytes()).unwrap();
+                let response = read_response(&mut client);
+                assert_eq!(response.status, status);
+                response.assert_header("Connection", "keep-alive");
+            }
+
+            handle.shutdown();
+        }
+
+
+        #[test]
+        fn streams_events_on_the_runtime() {
+            let handle = Server::builder()
+                .bind(String::from("127.0.0.1"), 0)
+                .event_response_async(|request| async move {
+                    let (response, sender) = event_stream_response(&request);
+                    let id : u64 = request.last_event_id().and_then(|id| id.parse().ok()).unwrap_or(0) + 1;
+                    // 전송기를 Drop 하면 보낸 이벤트를 전송한 뒤 스트림 종료
+                    sender.send(Event::new(String::from("hello")).id(id.to_string())).unwrap();
+                    response
+                })
+                .build()
+                .start_async()
+                .unwrap();
+
+            let mut client : std::net::TcpStream = std::net::TcpStream::connect(handle.local_addr()).unwrap();
+            client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+            client.write_all(&TestRequest::get("/").header("Last-Event-ID", "9").to_bytes()).unwrap();
+            read_response(&mut client).assert_header("Content-Type", "text/event-stream; charset=UTF-8");
+            let mut body : String = String::new();
+            client.read_to_string(&mut body).unwrap();
+            assert_eq!(body, "id: 10\ndata: hello\n\n");
+
+            handle.shutdown();
+        }
+    }
+}
@@ -0,0 +1,150 @@
+pub mod client_stream {
+    use std::io::{Read, Write};
+    use std::net::{Shutdown, TcpStream};
+    #[cfg(unix)]
+    use std::os::unix::net::UnixStream;
+    use std::time::Duration;
+    #[cfg(feature = "tls")]
+    use rustls::{ServerConnection, StreamOwned};
+    #[cfg(unix)]
+    use crate::server::listener::listener::UNIX_CLIENT_ADDRESS;
+
+
+    /// 클라이언트 접속 Stream
+    ///
+    /// 평문 TCP, TLS, Unix Domain Socket 접속을 같은 방식으로 읽고 쓸 수 있도록 감싼다.
+    pub enum ClientStream {
+        /// 평문 TCP 접속
+        TCP(TcpStream),
+        /// Unix Domain Socket 접속
+        #[cfg(unix)]
+        UNIX(UnixStream),
+        /// TLS 접속 (`tls` feature)
+        #[cfg(feature = "tls")]
+        TLS(Box<StreamOwned<ServerConnection, TcpStream>>)
+    }
+
+
+    impl ClientStream {
+        /// TCP Socket 반환 (Unix Domain Socket 접속은 None)
+        fn tcp_socket(&self) -> Option<&TcpStream> {
+            return match self {
+                ClientStream::TCP(stream) => Some(stream),
+                #[cfg(unix)]
+                ClientStream::UNIX(_) => None,
+                #[cfg(feature = "tls")]
+                ClientStream::TLS(stream) => Some(&stream.sock)
+            };
+        }
+
+
+        /// 읽기 Timeout 설정
+        pub fn set_read_timeout(&self, timeout : Option<Duration>) -> std::io::Result<()> {
+            return match self {
+                #[cfg(unix)]
+                ClientStream::UNIX(stream) => stream.set_read_timeout(timeout),
+                _ => self.tcp_socket().map_or(Ok(()), |socket| socket.set_read_timeout(timeout))
+            };
+        }
+
+
+        /// 쓰기 Timeout 설정
+        pub fn set_write_timeout(&self, timeout : Option<Duration>) -> std::io::Result<()> {
+            return match self {
+                #[cfg(unix)]
+                ClientStream::UNIX(stream) => stream.set_write_timeout(timeout),
+                _ => self.tcp_socket().map_or(Ok(()), |socket| socket.set_write_timeout(timeout))
+            };
+        }
+
+
+        /// Non-blocking 설정
+        pub fn set_nonblocking(&self, nonblocking : bool) -> std::io::Result<()> {
+            return match self {
+                #[cfg(unix)]
+                ClientStream::UNIX(stream) => stream.set_nonblocking(nonblocking),
+                _ => self.tcp_socket().map_or(Ok(()), |socket| socket.set_nonblocking(nonblocking))
+            };
+        }
+
+
+        /// 클라이언트 IP 주소 반환
+        pub fn peer_ip(&self) -> String {
+            #[cfg(unix)]
+            if let ClientStream::UNIX(_) = self {
+                return String::from(UNIX_CLIENT_ADDRESS);
+            }
+
+            return match self.tcp_socket().map(|socket| socket.peer_addr()) {
+                Some(Ok(value)) => value.ip().to_canonical().to_string(),
+                _ => String::from("(NoIPAddress)")
+            };
+        }
+
+
+        /// TLS 접속 여부
+        pub fn is_secure(&self) -> bool {
+            return match self {
+                ClientStream::TCP(_) => false,
+                #[cfg(unix)]
+                ClientStream::UNIX(_) => false,
+                #[cfg(feature = "tls")]
+                ClientStream::TLS(_) => true
+            };
+        }
+
+
+        /// 접속 종료
+        pub fn shutdown(&mut self) {
+            #[cfg(feature = "tls")]
+            if let ClientStream::TLS(stream) = self {
+                // TLS close_notify 전송
+                stream.conn.send_close_notify();
+                let _ = stream.conn.complete_io(&mut stream.sock);
+            }
+            #[cfg(unix)]
+            if let ClientStream::UNIX(stream) = self {
+                let _ = stream.shutdown(Shutdown::Both);
+            }
+            if let Some(socket) = self.tcp_socket() {
+                let _ = socket.shutdown(Shutdown::Both);
+            }
+        }
+    }
+
+
+    impl Read for ClientStream {
+        fn read(&mut self, buf : &mut [u8]) -> std::io::Result<usize> {
+            return match self {
+                ClientStream::TCP(stream) => stream.read(buf),
+                #[cfg(unix)]
+                ClientStream::UNIX(stream) => stream.read(buf),
+                #[cfg(feature = "tls")]
+                ClientStream::TLS(stream) => stream.read(buf)
+            };
+        }
+    }
+
+
+    impl Write for ClientStream {
+        fn write(&mut self, buf : &[u8]) -> std::io::Result<usize> {
+            return match self {
+                ClientStream::TCP(stream) => stream.write(buf),
+                #[cfg(unix)]
+                ClientStream::UNIX(stream) => stream.write(buf),
+                #[cfg(feature = "tls")]
+                ClientStream::TLS(stream) => stream.write(buf)
+            };
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            return match self {
+                ClientStream::TCP(stream) => stream.flush(),
+                #[cfg(unix)]
+                ClientStream::UNIX(stream) => stream.flush(),
+                #[cfg(feature = "tls")]
+                ClientStream::TLS(stream) => stream.flush()
+            };
+        }
+    }
+}
@@ -0,0 +1,369 @@
+pub mod event_stream {
+    use std::collections::{HashMap, VecDeque};
+    use std::sync::{Arc, Condvar, Mutex};
+    use std::task::Waker;
+    #[cfg(feature = "async")]
+    use std::task::{Context, Poll};
+    use std::time::{Duration, Instant};
+    use crate::server::request_parser::request_parser::{HttpVersion, Request};
+    use crate::server::response_parser::response_parser::{HttpStateCode, IsResponseDataCreateSuccess, Response, ResponseBody, response_header_writer};
+    use crate::server::server_config::server_config::ServerConfig;
+
+
+    /// Server-Sent Events 이벤트
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::event_stream::event_stream::Event;
+    /// # let _ =
+    /// Event::new(String::from("{\"cpu\":42}"))
+    ///     .event(String::from("stats"))
+    ///     .id(String::from("1024"))
+    /// # ;
+    /// ```
+    #[derive(Clone, Debug, Default, PartialEq)]
+    pub struct Event {
+        /// 이벤트 이름 (`event:`, 없으면 클라이언트에서 `message` 이벤트)
+        pub event : Option<String>,
+        /// 이벤트 데이터 (`data:`, 여러 줄 가능)
+        pub data : String,
+        /// 이벤트 ID (`id:`, 재접속 시 클라이언트가 `Last-Event-ID` 로 보낸다)
+        pub id : Option<String>,
+        /// 클라이언트 재접속 대기 시간 (`retry:`)
+        pub retry : Option<Duration>
+    }
+
+    /// 이벤트 전송기 (Handler 가 보관하며 다른 Thread 로 복제하여 사용 가능)
+    ///
+    /// 모든 전송기가 Drop 되면 서버는 스트림을 끝내고 접속을 닫는다.
+    pub struct EventSender {
+        channel : Arc<Channel>
+    }
+
+    /// 응답에 담겨 서버로 전달되는 이벤트 스트림 (수신 측)
+    pub struct EventStream {
+        channel : Arc<Channel>
+    }
+
+    /// 이벤트 수신 결과
+    pub(crate) enum EventReceive {
+        EVENT(Event),
+        TIMEOUT,
+        CLOSED
+    }
+
+    /// 전송기와 스트림이 공유하는 이벤트 대기열
+    struct Channel {
+        state : Mutex<ChannelState>,
+        signal : Condvar
+    }
+
+    struct ChannelState {
+        events : VecDeque<Event>,
+        sender_count : usize,
+        receiver_closed : bool,
+        waker : Option<Waker>
+    }
+
+
+    impl Event {
+        /// 데이터만 가진 이벤트 생성
+        pub fn new(data : String) -> Event {
+            return Event {
+                data,
+                ..Event::default()
+            };
+        }
+
+
+        /// 이벤트 이름 설정
+        pub fn event(mut self, event : String) -> Event {
+            self.event = Some(event);
+            return self;
+        }
+
+
+        /// 이벤트 ID 설정
+        pub fn id(mut self, id : String) -> Event {
+            self.id = Some(id);
+            return self;
+        }
+
+
+        /// 클라이언트 재접속 대기 시간 설정
+        pub fn retry(mut self, retry : Duration) -> Event {
+            self.retry = Some(retry);
+            return self;
+        }
+
+
+        /// `text/event-stream` 형식으로 변환
+        ///
+        /// 이름과 ID 의 줄바꿈은 Frame 을 깨뜨리므로 제거하고, 데이터는 줄마다 `data:` 로 나눈다.
+        pub(crate) fn to_frame(&self) -> String {
+            let single_line = |value : &str| -> String {
+                return value.chars().filter(|character| *character != '\r' && *character != '\n').collect();
+            };
+
+            let mut frame : String = String::new();
+            if let Some(event) = &self.event {
+                frame.push_str(&format!("event: {}\n", single_line(event)));
+            }
+            if let Some(id) = &self.id {
+                frame.push_str(&format!("id: {}\n", single_line(id)));
+            }
+            if let Some(retry) = &self.retry {
+                frame.push_str(&format!("retry: {}\n", retry.as_millis()));
+            }
+            for line in self.data.split('\n') {
+                frame.push_str(&format!("data: {}\n", line.strip_suffix('\r').unwrap_or(line)));
+            }
+            frame.push('\n');
+
+            return frame;
+        }
+    }
+
+
+    impl EventSender {
+        /// 이벤트 전송
+        ///
+        /// # Return
+        /// 클라이언트 접속이 끊겨 스트림이 닫혔으면 보내지 못한 이벤트를 Err 로 반환
+        pub fn send(&self, event : Event) -> Result<(), Event> {
+            let mut state = self.channel.lock();
+            if state.receiver_closed {
+                return Err(event);
+            }
+            state.events.push_back(event);
+            state.wake();
+            self.channel.signal.notify_all();
+
+            return Ok(());
+        }
+
+
+        /// 클라이언트 접속이 끊겨 스트림이 닫혔는지 여부
+        pub fn is_closed(&self) -> bool {
+            return self.channel.lock().receiver_closed;
+        }
+    }
+
+
+    impl Clone for EventSender {
+        fn clone(&self) -> Self {
+            self.channel.lock().sender_count += 1;
+            return EventSender {
+                channel: Arc::clone(&self.channel)
+            };
+        }
+    }
+
+
+    impl Drop for EventSender {
+        fn drop(&mut self) {
+            let mut state = self.channel.lock();
+            state.sender_count -= 1;
+            if state.sender_count == 0 {
+                state.wake();
+                self.channel.signal.notify_all();
+            }
+        }
+    }
+
+
+    impl EventStream {
+        /// 이벤트 대기 (동기 서버용)
+        pub(crate) fn receive_timeout(&self, timeout : Duration) -> EventReceive {
+            let deadline : Instant = Instant::now() + timeout;
+            let mut state = self.channel.lock();
+            loop {
+                if let Some(event) = state.events.pop_front() {
+                    return EventReceive::EVENT(event);
+                }
+                if state.sender_count == 0 {
+                    return EventReceive::CLOSED;
+                }
+                let now : Instant = Instant::now();
+                if now >= deadline {
+                    return EventReceive::TIMEOUT;
+                }
+                state = match self.channel.signal.wait_timeout(state, deadline - now) {
+                    Ok((state, _)) => state,
+                    Err(error) => error.into_inner().0
+                };
+            }
+        }
+
+
+        /// 이벤트 대기 (비동기 서버용)
+        ///
+        /// # Return
+        /// 이벤트, 모든 전송기가 Drop 되어 스트림이 끝나면 None
+        #[cfg(feature = "async")]
+        pub(crate) fn poll_receive(&self, context : &mut Context<'_>) -> Poll<Option<Event>> {
+            let mut state = self.channel.lock();
+            if let Some(event) = state.events.pop_front() {
+                return Poll::Ready(Some(event));
+            }
+            if state.sender_count == 0 {
+                return Poll::Ready(None);
+            }
+            state.waker = Some(context.waker().clone());
+
+            return Poll::Pending;
+        }
+    }
+
+
+    impl Drop for EventStream {
+        /// 클라이언트 접속 종료를 전송기에 알림
+        fn drop(&mut self) {
+            let mut state = self.channel.lock();
+            state.receiver_closed = true;
+            state.events.clear();
+        }
+    }
+
+
+    impl Channel {
+        fn lock(&self) -> std::sync::MutexGuard<'_, ChannelState> {
+            return self.state.lock().unwrap_or_else(|error| error.into_inner());
+        }
+    }
+
+
+    impl ChannelState {
+        fn wake(&mut self) {
+            if let Some(waker) = self.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+
+    /// 이벤트 전송기와 스트림 생성
+    pub fn event_channel() -> (EventSender, EventStream) {
+        let channel : Arc<Channel> = Arc::new(Channel {
+            state: Mutex::new(ChannelState {
+                events: VecDeque::new(),
+                sender_count: 1,
+                receiver_closed: false,
+                waker: None
+            }),
+            signal: Condvar::new()
+        });
+
+        return (EventSender { channel: Arc::clone(&channel) }, EventStream { channel });
+    }
+
+
+    /// Server-Sent Events 응답 생성기
+    ///
+    /// 응답을 반환한 뒤에도 접속을 유지하며, 반환된 전송기로 보낸 이벤트를 클라이언트에 바로 전달한다.
+    /// 이벤트가 없는 동안에는 `ServerConfig.event_stream_keep_alive` 간격으로 주석을 보내 접속을 유지한다.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use AthenaEngine::server::Server;
+    /// # use AthenaEngine::server::event_stream::event_stream::{Event, event_stream_response};
+    /// # fn read_stats() -> String { String::from("{\"cpu\":42}") }
+    /// # let _ =
+    /// Server::builder().event_response(|request| {
+    ///     let (response, sender) = event_stream_response(request);
+    ///     let start : u64 = request.last_event_id().and_then(|id| id.parse().ok()).unwrap_or(0);
+    ///     std::thread::spawn(move || {
+    ///         for id in start + 1.. {
+    ///             let event = Event::new(read_stats()).event(String::from("stats")).id(id.to_string());
+    ///             if sender.send(event).is_err() {
+    ///                 break; // 클라이언트 접속 종료
+    ///             }
+    ///             std::thread::sleep(Duration::from_secs(1));
+    ///         }
+    ///     });
+    ///     response
+    /// })
+    /// # ;
+    /// ```
+    ///
+    /// # Argument
+    /// request : HTTP 요청 데이터
+    ///
+    /// # Return
+    /// 이벤트 스트림을 담은 Response 구조체와 이벤트 전송기
+    pub fn event_stream_response(request : &Request) -> (Response, EventSender) {
+        let (sender, stream) : (EventSender, EventStream) = event_channel();
+
+        let mut header : HashMap<String, String> = match &request.config {
+            Some(config) => response_header_writer(config),
+            None => response_header_writer(&ServerConfig::default())
+        };
+        header.insert(String::from("Content-Type"), String::from("text/event-stream; charset=UTF-8"));
+        header.insert(String::from("Cache-Control"), String::from("no-cache"));
+        // Proxy (nginx 등) 의 응답 Buffering 방지
+        header.insert(String::from("X-Accel-Buffering"), String::from("no"));
+
+        let response : Response = Response {
+            is_success: IsResponseDataCreateSuccess::SUCCESS,
+            response_code: Some(HttpStateCode::HTTP_200),
+            http_version: Some(HttpVersion::HTTP_1_1),
+            headers: Some(header),
+            cookies: None,
+            // Header 끝을 표시하기 위한 빈 Body (이벤트는 서버가 이어서 전송)
+            body: Some(ResponseBody {
+                body_str: Some(String::new())
+            }),
+            event_stream: Some(stream)
+        };
+
+        return (response, sender);
+    }
+
+
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+
+        #[test]
+        fn writes_one_data_line_per_line_and_strips_newlines_from_fields() {
+            let event : Event = Event::new(String::from("first\r\nsecond\nthird"))
+                .event(String::from("up\ndate"))
+                .id(String::from("4\r2"))
+                .retry(Duration::from_secs(3));
+
+            assert_eq!(event.to_frame(), "event: update\nid: 42\nretry: 3000\ndata: first\ndata: second\ndata: third\n\n");
+            assert_eq!(Event::new(String::new()).to_frame(), "data: \n\n");
+        }
+
+
+        #[test]
+        fn closes_the_stream_when_all_senders_are_dropped() {
+            let (sender, stream) : (EventSender, EventStream) = event_channel();
+            let clone : EventSender = sender.clone();
+            sender.send(Event::new(String::from("a"))).unwrap();
+            drop(sender);
+
+            // 남은 이벤트를 모두 받은 뒤 종료
+            assert!(matches!(stream.receive_timeout(Duration::ZERO), EventReceive::EVENT(event) if event.data == "a"));
+            assert!(matches!(stream.receive_timeout(Duration::ZERO), EventReceive::TIMEOUT));
+            drop(clone);
+            assert!(matches!(stream.receive_timeout(Duration::from_secs(1)), EventReceive::CLOSED));
+        }
+
+
+        #[test]
+        fn rejects_events_after_the_client_disconnects() {
+            let (sender, stream) : (EventSender, EventStream) = event_channel();
+            assert!(!sender.is_closed());
+            drop(stream);
+
+            assert!(sender.is_closed());
+            assert_eq!(sender.send(Event::new(String::from("late"))), Err(Event::new(String::from("late"))));
+        }
+    }
+}
@@ -0,0 +1,538 @@
+pub mod hpack {
+    use std::collections::{HashMap, VecDeque};
+    use std::sync::OnceLock;
+
+
+    /// 동적 Table 항목의 추가 크기 (RFC 7541 4.1)
+    const ENTRY_OVERHEAD : usize = 32;
+
+    /// 정적 Table (RFC 7541 Appendix A, 1 번부터)
+    const STATIC_TABLE : [(&str, &str); 61] = [
+        (":authority", ""), (":method", "GET"), (":method", "POST"), (":path", "/"),
+        (":path", "/index.html"), (":scheme", "http"), (":scheme", "https"), (":status", "200"),
+        (":status", "204"), (":status", "206"), (":status", "304"), (":status", "400"),
+        (":status", "404"), (":status", "500"), ("accept-charset", ""), ("accept-encoding", "gzip, deflate"),
+        ("accept-language", ""), ("accept-ranges", ""), ("accept", ""), ("access-control-allow-origin", ""),
+        ("age", ""), ("allow", ""), ("authorization", ""), ("cache-control", ""),
+        ("content-disposition", ""), ("content-encoding", ""), ("content-language", ""), ("content-length", ""),
+        ("content-location", ""), ("content-range", ""), ("content-type", ""), ("cookie", ""),
+        ("date", ""), ("etag", ""), ("expect", ""), ("expires", ""),
+        ("from", ""), ("host", ""), ("if-match", ""), ("if-modified-since", ""),
+        ("if-none-match", ""), ("if-range", ""), ("if-unmodified-since", ""), ("last-modified", ""),
+        ("link", ""), ("location", ""), ("max-forwards", ""), ("proxy-authenticate", ""),
+        ("proxy-authorization", ""), ("range", ""), ("referer", ""), ("refresh", ""),
+        ("retry-after", ""), ("server", ""), ("set-cookie", ""), ("strict-transport-security", ""),
+        ("transfer-encoding", ""), ("user-agent", ""), ("vary", ""), ("via", ""),
+        ("www-authenticate", "")
+    ];
+
+    /// Huffman 부호 (RFC 7541 Appendix B, 기호 순서의 (부호, Bit 길이), 마지막은 EOS)
+    const HUFFMAN_CODES : [(u32, u8); 257] = [
+        (0x1ff8, 13), (0x7fffd8, 23), (0xfffffe2, 28), (0xfffffe3, 28), (0xfffffe4, 28), (0xfffffe5, 28), (0xfffffe6, 28), (0xfffffe7, 28),
+        (0xfffffe8, 28), (0xffffea, 24), (0x3ffffffc, 30), (0xfffffe9, 28), (0xfffffea, 28), (0x3ffffffd, 30), (0xfffffeb, 28), (0xfffffec, 28),
+        (0xfffffed, 28), (0xfffffee, 28), (0xfffffef, 28), (0xffffff0, 28), (0xffffff1, 28), (0xffffff2, 28), (0x3ffffffe, 30), (0xffffff3, 28),
+        (0xffffff4, 28), (0xffffff5, 28), (0xffffff6, 28), (0xffffff7, 28), (0xffffff8, 28), (0xffffff9, 28), (0xffffffa, 28), (0xffffffb, 28),
+        (0x14, 6), (0x3f8, 10), (0x3f9, 10), (0xffa, 12), (0x1ff9, 13), (0x15, 6), (0xf8, 8), (0x7fa, 11),
+        (0x3fa, 10), (0x3fb, 10), (0xf9, 8), (0x7fb, 11), (0xfa, 8), (0x16, 6), (0x17, 6), (0x18, 6),
+        (0x0, 5), (0x1, 5), (0x2, 5), (0x19, 6), (0x1a, 6), (0x1b, 6), (0x1c, 6), (0x1d, 6),
+        (0x1e, 6), (0x1f, 6), (0x5c, 7), (0xfb, 8), (0x7ffc, 15), (0x20, 6), (0xffb, 12), (0x3fc, 10),
+        (0x1ffa, 13), (0x21, 6), (0x5d, 7), (0x5e, 7), (0x5f, 7), (0x60, 7), (0x61, 7), (0x62, 7),
+        (0x63, 7), (0x64, 7), (0x65, 7), (0x66, 7), (0x67, 7), (0x68, 7), (0x69, 7), (0x6a, 7),
+        (0x6b, 7), (0x6c, 7), (0x6d, 7), (0x6e, 7), (0x6f, 7), (0x70, 7), (0x71, 7), (0x72, 7),
+        (0xfc, 8), (0x73, 7), (0xfd, 8), (0x1ffb, 13), (0x7fff0, 19), (0x1ffc, 13), (0x3ffc, 14), (0x22, 6),
+        (0x7ffd, 15), (0x3, 5), (0x23, 6), (0x4, 5), (0x24, 6), (0x5, 5), (0x25, 6), (0x26, 6),
+        (0x27, 6), (0x6, 5), (0x74, 7), (0x75, 7), (0x28, 6), (0x29, 6), (0x2a, 6), (0x7, 5),
+        (0x2b, 6), (0x76, 7), (0x2c, 6), (0x8, 5), (0x9, 5), (0x2d, 6), (0x77, 7), (0x78, 7),
+        (0x79, 7), (0x7a, 7), (0x7b, 7), (0x7ffe, 15), (0x7fc, 11), (0x3ffd, 14), (0x1ffd, 13), (0xffffffc, 28),
+        (0xfffe6, 20), (0x3fffd2, 22), (0xfffe7, 20), (0xfffe8, 20), (0x3fffd3, 22), (0x3fffd4, 22), (0x3fffd5, 22), (0x7fffd9, 23),
+        (0x3fffd6, 22), (0x7fffda, 23), (0x7fffdb, 23), (0x7fffdc, 23), (0x7fffdd, 23), (0x7fffde, 23), (0xffffeb, 24), (0x7fffdf, 23),
+        (0xffffec, 24), (0xffffed, 24), (0x3fffd7, 22), (0x7fffe0, 23), (0xffffee, 24), (0x7fffe1, 23), (0x7fffe2, 23), (0x7fffe3, 23),
+        (0x7fffe4, 23), (0x1fffdc, 21), (0x3fffd8, 22), (0x7fffe5, 23), (0x3fffd9, 22), (0x7fffe6, 23), (0x7fffe7, 23), (0xffffef, 24),
+        (0x3fffda, 22), (0x1fffdd, 21), (0xfffe9, 20), (0x3fffdb, 22), (0x3fffdc, 22), (0x7fffe8, 23), (0x7fffe9, 23), (0x1fffde, 21),
+        (0x7fffea, 23), (0x3fffdd, 22), (0x3fffde, 22), (0xfffff0, 24), (0x1fffdf, 21), (0x3fffdf, 22), (0x7fffeb, 23), (0x7fffec, 23),
+        (0x1fffe0, 21), (0x1fffe1, 21), (0x3fffe0, 22), (0x1fffe2, 21), (0x7fffed, 23), (0x3fffe1, 22), (0x7fffee, 23), (0x7fffef, 23),
+        (0xfffea, 20), (0x3fffe2, 22), (0x3fffe3, 22), (0x3fffe4, 22), (0x7ffff0, 23), (0x3fffe5, 22), (0x3fffe6, 22), (0x7ffff1, 23),
+        (0x3ffffe0, 26), (0x3ffffe1, 26), (0xfffeb, 20), (0x7fff1, 19), (0x3fffe7, 22), (0x7ffff2, 23), (0x3fffe8, 22), (0x1ffffec, 25),
+        (0x3ffffe2, 26), (0x3ffffe3, 26), (0x3ffffe4, 26), (0x7ffffde, 27), (0x7ffffdf, 27), (0x3ffffe5, 26), (0xfffff1, 24), (0x1ffffed, 25),
+        (0x7fff2, 19), (0x1fffe3, 21), (0x3ffffe6, 26), (0x7ffffe0, 27), (0x7ffffe1, 27), (0x3ffffe7, 26), (0x7ffffe2, 27), (0xfffff2, 24),
+        (0x1fffe4, 21), (0x1fffe5, 21), (0x3ffffe8, 26), (0x3ffffe9, 26), (0xffffffd, 28), (0x7ffffe3, 27), (0x7ffffe4, 27), (0x7ffffe5, 27),
+        (0xfffec, 20), (0xfffff3, 24), (0xfffed, 20), (0x1fffe6, 21), (0x3fffe9, 22), (0x1fffe7, 21), (0x1fffe8, 21), (0x7ffff3, 23),
+        (0x3fffea, 22), (0x3fffeb, 22), (0x1ffffee, 25), (0x1ffffef, 25), (0xfffff4, 24), (0xfffff5, 24), (0x3ffffea, 26), (0x7ffff4, 23),
+        (0x3ffffeb, 26), (0x7ffffe6, 27), (0x3ffffec, 26), (0x3ffffed, 26), (0x7ffffe7, 27), (0x7ffffe8, 27), (0x7ffffe9, 27), (0x7ffffea, 27),
+        (0x7ffffeb, 27), (0xffffffe, 28), (0x7ffffec, 27), (0x7ffffed, 27), (0x7ffffee, 27), (0x7ffffef, 27), (0x7fffff0, 27), (0x3ffffee, 26),
+        (0x3fffffff, 30)
+    ];
+
+
+    /// HPACK 오류 Enum (발생 시 접속 전체를 COMPRESSION_ERROR 로 종료)
+    #[derive(Debug, PartialEq)]
+    pub enum HpackError {
+        /// Header Block 이 중간에 끝남
+        TRUNCATED,
+        /// 정수 표현이 너무 큼
+        INTEGER_OVERFLOW,
+        /// 존재하지 않는 Table 번호
+        INVALID_INDEX,
+        /// 잘못된 Huffman 부호
+        INVALID_HUFFMAN,
+        /// 허용된 크기를 넘는 동적 Table 크기 변경
+        INVALID_TABLE_SIZE,
+        /// Header Block 시작이 아닌 위치의 동적 Table 크기 변경 (RFC 7541 4.2)
+        MISPLACED_TABLE_SIZE_UPDATE,
+        /// 복호화한 Header 크기가 제한을 넘음 (동적 Table 은 Block 끝까지 반영되어 접속을 유지할 수 있다)
+        HEADER_LIST_TOO_LARGE
+    }
+
+    /// Header Block 복호기 (접속마다 하나, 동적 Table 유지)
+    pub struct Decoder {
+        dynamic_table : VecDeque<(String, String)>,
+        table_size : usize,
+        max_table_size : usize,
+        /// SETTINGS_HEADER_TABLE_SIZE 로 알린 최대 크기
+        allowed_table_size : usize
+    }
+
+    /// Header Block 부호기
+    ///
+    /// 동적 Table 을 사용하지 않으므로 (Literal without Indexing) 클라이언트의 Table 크기 설정과 관계없이 동작한다.
+    pub struct Encoder {}
+
+
+    impl std::fmt::Display for HpackError {
+        fn fmt(&self, f : &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            return match self {
+                HpackError::TRUNCATED => write!(f, "HPACK header block truncated."),
+                HpackError::INTEGER_OVERFLOW => write!(f, "HPACK integer overflow."),
+                HpackError::INVALID_INDEX => write!(f, "HPACK table index out of range."),
+                HpackError::INVALID_HUFFMAN => write!(f, "HPACK invalid Huffman string."),
+                HpackError::INVALID_TABLE_SIZE => write!(f, "HPACK dynamic table size update exceeds the limit."),
+                HpackError::MISPLACED_TABLE_SIZE_UPDATE => write!(f, "HPACK dynamic table size update after a header field."),
+                HpackError::HEADER_LIST_TOO_LARGE => write!(f, "HPACK decoded header list exceeds the limit.")
+            };
+        }
+    }
+
+
+    impl Decoder {
+        /// 복호기 생성
+        ///
+        /// # Argument
+        /// max_table_size : SETTINGS_HEADER_TABLE_SIZE 로 알린 동적 Table 최대 크기
+        pub fn new(max_table_size : usize) -> Decoder {
+            return Decoder {
+                dynamic_table: VecDeque::new(),
+                table_size: 0,
+                max_table_size,
+                allowed_table_size: max_table_size
+            };
+        }
+
+
+        /// Header Block 복호화
+        ///
+        /// 작은 Block 이 큰 동적 Table 항목을 반복 참조하여 크게 늘어나지 않도록 복호화하는 동안 크기를 확인한다.
+        /// 제한을 넘은 뒤에는 항목을 복사하지 않고 동적 Table 만 Block 끝까지 반영한다.
+        ///
+        /// # Argument
+        /// block : Header Block
+        ///
+        /// max_header_bytes : 복호화한 Header 의 최대 크기 (RFC 7541 4.1 기준 크기)
+        ///
+        /// # Return
+        /// 순서대로 (이름, 값) 목록, 실패시 HpackError
+        pub fn decode(&mut self, block : &[u8], max_header_bytes : usize) -> Result<Vec<(String, String)>, HpackError> {
+            let mut headers : Vec<(String, String)> = Vec::new();
+            let mut header_bytes : usize = 0;
+            let mut field_seen : bool = false;
+            let mut position : usize = 0;
+
+            while position < block.len() {
+                let first : u8 = block[position];
+                let header : (String, String) = if first & 0x80 != 0 {
+                    // Indexed Header Field
+                    let index : usize = decode_integer(block, &mut position, 7)?;
+                    let (name, value) = self.entry_ref(index)?;
+                    if header_bytes.saturating_add(name.len() + value.len() + ENTRY_OVERHEAD) > max_header_bytes {
+                        header_bytes = usize::MAX;
+                        headers = Vec::new();
+                        field_seen = true;
+                        continue;
+                    }
+                    (String::from(name), String::from(value))
+                }else if first & 0x40 != 0 {
+                    // Literal Header Field with Incremental Indexing
+                    let header : (String, String) = self.decode_literal(block, &mut position, 6)?;
+                    self.insert(header.clone());
+                    header
+                }else if first & 0x20 != 0 {
+                    // Dynamic Table Size Update (Block 시작에서만 허용)
+                    if field_seen {
+                        return Err(HpackError::MISPLACED_TABLE_SIZE_UPDATE);
+                    }
+                    let size : usize = decode_integer(block, &mut position, 5)?;
+                    if size > self.allowed_table_size {
+                        return Err(HpackError::INVALID_TABLE_SIZE);
+                    }
+                    self.max_table_size = size;
+                    self.evict(0);
+                    continue;
+                }else {
+                    // Literal Header Field without Indexing / Never Indexed
+                    self.decode_literal(block, &mut position, 4)?
+                };
+                field_seen = true;
+
+                header_bytes = header_bytes.saturating_add(header.0.len() + header.1.len() + ENTRY_OVERHEAD);
+                if header_bytes > max_header_bytes {
+                    headers = Vec::new();
+                }else {
+                    headers.push(header);
+                }
+            }
+            if header_bytes > max_header_bytes {
+                return Err(HpackError::HEADER_LIST_TOO_LARGE);
+            }
+
+            return Ok(headers);
+        }
+
+
+        /// Literal Header Field 복호화 (이름은 Table 번호 또는 문자열)
+        fn decode_literal(&self, block : &[u8], position : &mut usize, prefix : u8) -> Result<(String, String), HpackError> {
+            let index : usize = decode_integer(block, position, prefix)?;
+            let name : String = if index == 0 {
+                decode_string(block, position)?
+            }else {
+                self.entry(index)?.0
+            };
+            let value : String = decode_string(block, position)?;
+
+            return Ok((name, value));
+        }
+
+
+        /// 정적 Table 과 동적 Table 을 이은 번호로 항목 반환
+        fn entry(&self, index : usize) -> Result<(String, String), HpackError> {
+            let (name, value) = self.entry_ref(index)?;
+            return Ok((String::from(name), String::from(value)));
+        }
+
+
+        /// 정적 Table 과 동적 Table 을 이은 번호로 항목 참조 (복사하지 않음)
+        fn entry_ref(&self, index : usize) -> Result<(&str, &str), HpackError> {
+            if index == 0 {
+                return Err(HpackError::INVALID_INDEX);
+            }
+            if index <= STATIC_TABLE.len() {
+                return Ok(STATIC_TABLE[index - 1]);
+            }
+
+            return match self.dynamic_table.get(index - STATIC_TABLE.len() - 1) {
+                Some((name, value)) => Ok((name, value)),
+                None => Err(HpackError::INVALID_INDEX)
+            };
+        }
+
+
+        /// 동적 Table 에 항목 추가 (크기를 넘는 항목은 Table 을 비운다)
+        fn insert(&mut self, header : (String, String)) {
+            let size : usize = header.0.len() + header.1.len() + ENTRY_OVERHEAD;
+            self.evict(size);
+            if size <= self.max_table_size {
+                self.table_size += size;
+                self.dynamic_table.push_front(header);
+            }
+        }
+
+
+        /// `additional` 크기의 항목이 들어갈 때까지 오래된 항목 삭제
+        fn evict(&mut self, additional : usize) {
+            while self.table_size + additional > self.max_table_size {
+                match self.dynamic_table.pop_back() {
+                    Some((name, value)) => self.table_size -= name.len() + value.len() + ENTRY_OVERHEAD,
+                    None => break
+                }
+            }
+        }
+    }
+
+
+    impl Default for Encoder {
+        fn default() -> Self {
+            return Encoder::new();
+        }
+    }
+
+
+    impl Encoder {
+        pub fn new() -> Encoder {
+            return Encoder {};
+        }
+
+
+        /// Header 목록 부호화 (이름은 소문자로 전달해야 한다)
+        pub fn encode(&mut self, headers : &[(String, String)]) -> Vec<u8> {
+            let mut block : Vec<u8> = Vec::new();
+
+            for (name, value) in headers {
+                // 정적 Table 에 이름과 값이 모두 있으면 번호만 보낸다
+                if let Some(index) = STATIC_TABLE.iter().position(|(key, item)| key == name && item == value) {
+                    encode_integer(&mut block, index + 1, 7, 0x80);
+                    continue;
+                }
+
+                // Literal Header Field without Indexing
+                match STATIC_TABLE.iter().position(|(key, _)| key == name) {
+                    Some(index) => encode_integer(&mut block, index + 1, 4, 0x00),
+                    None => {
+                        block.push(0x00);
+                        encode_string(&mut block, name);
+                    }
+                }
+                encode_string(&mut block, value);
+            }
+
+            return block;
+        }
+    }
+
+
+    /// 정수 복호화 (RFC 7541 5.1)
+    fn decode_integer(block : &[u8], position : &mut usize, prefix : u8) -> Result<usize, HpackError> {
+        let mask : u8 = ((1u16 << prefix) - 1) as u8;
+        let first : u8 = *block.get(*position).ok_or(HpackError::TRUNCATED)?;
+        *position += 1;
+
+        let mut value : usize = (first & mask) as usize;
+        if value < mask as usize {
+            return Ok(value);
+        }
+
+        let mut shift : u32 = 0;
+        loop {
+            let byte : u8 = *block.get(*position).ok_or(HpackError::TRUNCATED)?;
+            *position += 1;
+            if shift > 28 {
+                return Err(HpackError::INTEGER_OVERFLOW);
+            }
+            value += ((byte & 0x7F) as usize) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+
+    /// 정수 부호화 (`flags` 는 첫 Byte 의 Prefix 밖 Bit)
+    fn encode_integer(block : &mut Vec<u8>, value : usize, prefix : u8, flags : u8) {
+        let mask : usize = (1usize << prefix) - 1;
+        if value < mask {
+            block.push(flags | value as u8);
+            return;
+        }
+
+        block.push(flags | mask as u8);
+        let mut remain : usize = value - mask;
+        while remain >= 0x80 {
+            block.push((remain & 0x7F) as u8 | 0x80);
+            remain >>= 7;
+        }
+        block.push(remain as u8);
+    }
+
+
+    /// 문자열 복호화 (RFC 7541 5.2, Huffman 부호 포함)
+    fn decode_string(block : &[u8], position : &mut usize) -> Result<String, HpackError> {
+        let huffman : bool = *block.get(*position).ok_or(HpackError::TRUNCATED)? & 0x80 != 0;
+        let length : usize = decode_integer(block, position, 7)?;
+        let end : usize = position.checked_add(length).ok_or(HpackError::TRUNCATED)?;
+        let data : &[u8] = block.get(*position..end).ok_or(HpackError::TRUNCATED)?;
+        *position = end;
+
+        let bytes : Vec<u8> = if huffman {
+            huffman_decode(data)?
+        }else {
+            data.to_vec()
+        };
+
+        return Ok(String::from_utf8_lossy(&bytes).to_string());
+    }
+
+
+    /// 문자열 부호화 (Huffman 부호를 사용하지 않는다)
+    fn encode_string(block : &mut Vec<u8>, value : &str) {
+        encode_integer(block, value.len(), 7, 0x00);
+        block.extend_from_slice(value.as_bytes());
+    }
+
+
+    /// Huffman 부호 복호화
+    fn huffman_decode(data : &[u8]) -> Result<Vec<u8>, HpackError> {
+        let table : &HashMap<(u8, u32), u16> = huffman_table();
+        let mut decoded : Vec<u8> = Vec::with_capacity(data.len() * 8 / 5);
+        let mut code : u32 = 0;
+        let mut length : u8 = 0;
+
+        for byte in data {
+            for shift in (0..8).rev() {
+                code = (code << 1) | ((byte >> shift) & 1) as u32;
+                length += 1;
+                if let Some(symbol) = table.get(&(length, code)) {
+                    // EOS 는 문자열 안에 올 수 없다
+                    if *symbol == 256 {
+                        return Err(HpackError::INVALID_HUFFMAN);
+                    }
+                    decoded.push(*symbol as u8);
+                    code = 0;
+                    length = 0;
+                }else if length >= 30 {
+                    return Err(HpackError::INVALID_HUFFMAN);
+                }
+            }
+        }
+
+        // 남은 Bit 는 7 Bit 이하의 EOS 앞부분 (모두 1) 이어야 한다
+        if length > 7 || code != (1u32 << length) - 1 {
+            return Err(HpackError::INVALID_HUFFMAN);
+        }
+
+        return Ok(decoded);
+    }
+
+
+    /// (Bit 길이, 부호) 로 기호를 찾는 Table
+    fn huffman_table() -> &'static HashMap<(u8, u32), u16> {
+        static TABLE : OnceLock<HashMap<(u8, u32), u16>> = OnceLock::new();
+
+        return TABLE.get_or_init(|| {
+            return HUFFMAN_CODES.iter().enumerate()
+                .map(|(symbol, (code, length))| ((*length, *code), symbol as u16))
+                .collect();
+        });
+    }
+
+
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+
+        /// 기본 Header 크기 제한
+        const MAX_HEADER_BYTES : usize = 16_384;
+
+
+        /// 16진수 문자열을 Byte 배열로 변환 (공백 무시)
+        fn hex(text : &str) -> Vec<u8> {
+            let digits : Vec<u8> = text.bytes().filter(|byte| !byte.is_ascii_whitespace()).collect();
+            return digits.chunks(2)
+                .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).unwrap(), 16).unwrap())
+                .collect();
+        }
+
+
+        fn headers(items : &[(&str, &str)]) -> Vec<(String, String)> {
+            return items.iter().map(|(name, value)| (String::from(*name), String::from(*value))).collect();
+        }
+
+
+        #[test]
+        fn decodes_rfc7541_c4_requests_with_huffman() {
+            let mut decoder : Decoder = Decoder::new(4096);
+
+            // C.4.1 첫 번째 요청
+            let decoded = decoder.decode(&hex("8286 8441 8cf1 e3c2 e5f2 3a6b a0ab 90f4 ff"), MAX_HEADER_BYTES).unwrap();
+            assert_eq!(decoded, headers(&[(":method", "GET"), (":scheme", "http"), (":path", "/"), (":authority", "www.example.com")]));
+            assert_eq!(decoder.table_size, 57);
+
+            // C.4.2 두 번째 요청 (동적 Table 참조)
+            let decoded = decoder.decode(&hex("8286 84be 5886 a8eb 1064 9cbf"), MAX_HEADER_BYTES).unwrap();
+            assert_eq!(decoded, headers(&[(":method", "GET"), (":scheme", "http"), (":path", "/"), (":authority", "www.example.com"), ("cache-control", "no-cache")]));
+            assert_eq!(decoder.table_size, 110);
+
+            // C.4.3 세 번째 요청
+            let decoded = decoder.decode(&hex("8287 85bf 4088 25a8 49e9 5ba9 7d7f 8925 a849 e95b b8e8 b4bf"), MAX_HEADER_BYTES).unwrap();
+            assert_eq!(decoded, headers(&[(":method", "GET"), (":scheme", "https"), (":path", "/index.html"), (":authority", "www.example.com"), ("custom-key", "custom-value")]));
+            assert_eq!(decoder.table_size, 164);
+            assert_eq!(decoder.dynamic_table, VecDeque::from(headers(&[("custom-key", "custom-value"), ("cache-control", "no-cache"), (":authority", "www.example.com")])));
+        }
+
+
+        #[test]
+        fn round_trips_encoded_headers() {
+            let items : Vec<(String, String)> = headers(&[
+                (":status", "200"),
+                (":status", "302"),
+                ("content-type", "text/html; charset=UTF-8"),
+                ("x-custom", "value"),
+                ("set-cookie", &"a".repeat(300))
+            ]);
+            let block : Vec<u8> = Encoder::new().encode(&items);
+            // 정적 Table 에 있는 항목은 번호 하나로 보낸다
+            assert_eq!(block[0], 0x88);
+
+            assert_eq!(Decoder::new(4096).decode(&block, MAX_HEADER_BYTES).unwrap(), items);
+        }
+
+
+        #[test]
+        fn round_trips_integers() {
+            for (value, prefix) in [(10, 5), (1337, 5), (42, 8), (127, 7), (0, 4), (usize::MAX >> 36, 6)] {
+                let mut block : Vec<u8> = Vec::new();
+                encode_integer(&mut block, value, prefix, 0);
+                let mut position : usize = 0;
+                assert_eq!(decode_integer(&block, &mut position, prefix), Ok(value));
+                assert_eq!(position, block.len());
+            }
+            // RFC 7541 C.1.2
+            let mut block : Vec<u8> = Vec::new();
+            encode_integer(&mut block, 1337, 5, 0);
+            assert_eq!(block, vec![0x1f, 0x9a, 0x0a]);
+
+            let mut position : usize = 0;
+            assert_eq!(decode_integer(&[0x1f, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01], &mut position, 5), Err(HpackError::INTEGER_OVERFLOW));
+        }
+
+
+        #[test]
+        fn rejects_malformed_blocks() {
+            let mut decoder : Decoder = Decoder::new(4096);
+            assert_eq!(decoder.decode(&[0x80], MAX_HEADER_BYTES), Err(HpackError::INVALID_INDEX));
+            assert_eq!(decoder.decode(&[0xbe], MAX_HEADER_BYTES), Err(HpackError::INVALID_INDEX));
+            assert_eq!(decoder.decode(&[0x41, 0x05, b'a'], MAX_HEADER_BYTES), Err(HpackError::TRUNCATED));
+            // EOS 앞부분이 아닌 Padding
+            assert_eq!(decoder.decode(&[0x41, 0x81, 0x00], MAX_HEADER_BYTES), Err(HpackError::INVALID_HUFFMAN));
+        }
+
+
+        #[test]
+        fn accepts_table_size_update_only_at_block_start() {
+            let mut decoder : Decoder = Decoder::new(4096);
+            decoder.decode(&hex("8286 8441 8cf1 e3c2 e5f2 3a6b a0ab 90f4 ff"), MAX_HEADER_BYTES).unwrap();
+
+            // 크기 0 으로 변경하면 동적 Table 을 비운다
+            assert_eq!(decoder.decode(&[0x20, 0x3f, 0xe1, 0x1f, 0x82], MAX_HEADER_BYTES), Ok(headers(&[(":method", "GET")])));
+            assert_eq!(decoder.table_size, 0);
+            assert_eq!(decoder.decode(&[0xbe], MAX_HEADER_BYTES), Err(HpackError::INVALID_INDEX));
+
+            assert_eq!(decoder.decode(&[0x82, 0x20], MAX_HEADER_BYTES), Err(HpackError::MISPLACED_TABLE_SIZE_UPDATE));
+            assert_eq!(decoder.decode(&[0x3f, 0xe2, 0x1f], MAX_HEADER_BYTES), Err(HpackError::INVALID_TABLE_SIZE));
+        }
+
+
+        #[test]
+        fn limits_decoded_size_and_keeps_table_in_sync() {
+            let mut decoder : Decoder = Decoder::new(4096);
+            // 큰 항목 하나를 동적 Table 에 넣고 1 Byte 참조로 반복
+            let mut block : Vec<u8> = vec![0x40, 0x01, b'x'];
+            encode_string(&mut block, &"a".repeat(1000));
+            block.extend_from_slice(&[0xbe; 100]);
+            // 제한을 넘은 뒤의 Literal 도 동적 Table 에 반영되어야 한다
+            block.extend_from_slice(&[0x40, 0x01, b'y', 0x01, b'z']);
+            assert_eq!(decoder.decode(&block, 4096), Err(HpackError::HEADER_LIST_TOO_LARGE));
+
+            assert_eq!(decoder.decode(&[0xbe, 0xbf], 4096), Ok(headers(&[("y", "z"), ("x", &"a".repeat(1000))])));
+        }
+    }
+}
@@ -0,0 +1,1218 @@
+pub mod http2 {
+    use std::collections::HashMap;
+    use std::io::{Read, Write};
+    use std::sync::mpsc::{self, Receiver, Sender};
+    use std::time::{Duration, Instant};
+    use crate::server::{attach_server_data, request_client_ip, set_read_timeout, too_many_requests_response, ServerContext};
+    use crate::server::client_stream::client_stream::ClientStream;
+    use crate::server::event_stream::event_stream::{EventReceive, EventStream};
+    use crate::server::hpack::hpack::{Decoder, Encoder, HpackError};
+    use crate::server::request_parser::request_parser::{HttpVersion, Request};
+    use crate::server::request_reader::request_reader::RawRequest;
+    use crate::server::response_parser::response_parser::{default_http_state_writer, HttpStateCode, Response, ResponseBody, state_response_writer};
+    use crate::server::server_config::server_config::ServerConfig;
+    use crate::log::{log_more_text_writer, log_text_writer, LogTypeTag};
+
+
+    /// 현재 파일 정보 반환
+    fn get_this_name() -> String {
+        return String::from("main/server/http2");
+    }
+
+
+    /// Prior Knowledge 접속의 첫 요청 Line (`RequestReader` 가 읽은 Connection Preface 앞부분)
+    const PREFACE_REQUEST_LINE : &str = "PRI * HTTP/2.0";
+    /// 클라이언트 Connection Preface
+    const CONNECTION_PREFACE : &[u8] = b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n";
+    /// `RequestReader` 가 요청으로 읽고 남은 Connection Preface
+    const PREFACE_REMAINDER : &[u8] = b"SM\r\n\r\n";
+
+    /// `Upgrade: h2c` 요청에 대한 응답
+    const SWITCHING_PROTOCOLS : &str = "HTTP/1.1 101 Switching Protocols\r\nConnection: Upgrade\r\nUpgrade: h2c\r\n\r\n";
+
+    /// Frame Header 크기
+    const FRAME_HEADER_SIZE : usize = 9;
+    /// 기본 Frame 최대 크기 (서버는 변경하지 않는다)
+    const DEFAULT_MAX_FRAME_SIZE : usize = 16_384;
+    /// 허용되는 Frame 최대 크기 상한
+    const MAX_FRAME_SIZE_LIMIT : usize = 16_777_215;
+    /// 기본 Flow Control Window 크기
+    const DEFAULT_WINDOW_SIZE : i64 = 65_535;
+    /// Flow Control Window 최대 크기
+    const MAX_WINDOW_SIZE : i64 = (1 << 31) - 1;
+    /// 서버가 알리는 HPACK 동적 Table 크기
+    const HEADER_TABLE_SIZE : usize = 4_096;
+    /// 접속 하나의 최대 동시 Stream 수 상한
+    const MAX_CONCURRENT_STREAMS : usize = 100;
+    /// 접속을 끊기 전까지 허용하는 취소/거절 Stream 수 (최대 동시 Stream 수의 배수)
+    const MAX_CANCELLED_STREAMS_RATIO : usize = 2;
+    /// Handler 실행 중이거나 이벤트 스트림이 열려 있을 때 읽기 대기 간격
+    const BUSY_POLL_INTERVAL : Duration = Duration::from_millis(5);
+    /// 한 번의 `read` 호출로 읽을 최대 크기
+    const READ_CHUNK_SIZE : usize = 16_384;
+
+    // Frame 종류
+    const FRAME_DATA : u8 = 0x0;
+    const FRAME_HEADERS : u8 = 0x1;
+    const FRAME_PRIORITY : u8 = 0x2;
+    const FRAME_RST_STREAM : u8 = 0x3;
+    const FRAME_SETTINGS : u8 = 0x4;
+    const FRAME_PUSH_PROMISE : u8 = 0x5;
+    const FRAME_PING : u8 = 0x6;
+    const FRAME_GOAWAY : u8 = 0x7;
+    const FRAME_WINDOW_UPDATE : u8 = 0x8;
+    const FRAME_CONTINUATION : u8 = 0x9;
+
+    // Frame Flag
+    const FLAG_END_STREAM : u8 = 0x1;
+    const FLAG_ACK : u8 = 0x1;
+    const FLAG_END_HEADERS : u8 = 0x4;
+    const FLAG_PADDED : u8 = 0x8;
+    const FLAG_PRIORITY : u8 = 0x20;
+
+    // SETTINGS 항목
+    const SETTINGS_HEADER_TABLE_SIZE : u16 = 0x1;
+    const SETTINGS_ENABLE_PUSH : u16 = 0x2;
+    const SETTINGS_MAX_CONCURRENT_STREAMS : u16 = 0x3;
+    const SETTINGS_INITIAL_WINDOW_SIZE : u16 = 0x4;
+    const SETTINGS_MAX_FRAME_SIZE : u16 = 0x5;
+
+    /// HTTP/2 에서 사용할 수 없는 접속 관련 Header
+    const CONNECTION_HEADERS : [&str; 5] = ["connection", "keep-alive", "proxy-connection", "transfer-encoding", "upgrade"];
+
+
+    /// HTTP/2 오류 코드 (RFC 7540 7)
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum ErrorCode {
+        NO_ERROR = 0x0,
+        PROTOCOL_ERROR = 0x1,
+        INTERNAL_ERROR = 0x2,
+        FLOW_CONTROL_ERROR = 0x3,
+        STREAM_CLOSED = 0x5,
+        FRAME_SIZE_ERROR = 0x6,
+        REFUSED_STREAM = 0x7,
+        COMPRESSION_ERROR = 0x9,
+        ENHANCE_YOUR_CALM = 0xb
+    }
+
+    /// HTTP/1.1 접속에서 HTTP/2 로 전환할 요청
+    pub(crate) struct Http2Upgrade {
+        /// `Upgrade: h2c` 요청 (Stream 1 로 응답), Prior Knowledge 접속이면 None
+        pub(crate) request : Option<RawRequest>,
+        /// `HTTP2-Settings` Header 로 받은 클라이언트 SETTINGS
+        pub(crate) settings : Vec<u8>,
+        /// 전환 요청 뒤에 이미 받은 데이터
+        pub(crate) buffered : Vec<u8>
+    }
+
+    /// Stream 요청을 처리할 Handler (Request 이벤트와 Response 이벤트 실행)
+    pub(crate) type StreamHandler<'a> = &'a (dyn Fn(Request) -> Option<Response> + Sync);
+
+    /// Handler 실행 결과 (Stream ID, 응답)
+    type StreamResult = (u32, Option<Response>);
+
+    /// HTTP/2 접속 상태
+    struct Connection<'a> {
+        context : &'a ServerContext,
+        client_ip : &'a str,
+        /// 읽었지만 아직 처리하지 않은 데이터
+        buffer : Vec<u8>,
+        /// 다음 `flush` 에서 보낼 데이터
+        output : Vec<u8>,
+        /// 아직 받지 못한 Connection Preface (받은 뒤에는 빈 값)
+        preface : &'static [u8],
+        /// 클라이언트의 첫 SETTINGS 수신 여부
+        settings_received : bool,
+        decoder : Decoder,
+        encoder : Encoder,
+        streams : HashMap<u32, Stream>,
+        /// 클라이언트가 연 마지막 Stream ID
+        last_stream_id : u32,
+        /// CONTINUATION 으로 이어지는 Header Block
+        continuation : Option<HeaderBlock>,
+        /// 접속 전체의 송신 Window
+        send_window : i64,
+        /// 클라이언트가 알린 Stream 초기 Window
+        initial_window : i64,
+        /// 클라이언트가 알린 Frame 최대 크기
+        max_frame_size : usize,
+        max_concurrent_streams : usize,
+        /// 결과를 기다리는 Handler 수
+        running_handlers : usize,
+        /// Handler 실행 중 클라이언트가 취소했거나 동시 Stream 제한으로 거절한 Stream 수
+        cancelled_streams : usize,
+        /// 현재 접속에서 받은 Stream 수
+        served_streams : usize,
+        /// GOAWAY 로 알린 마지막 Stream ID
+        goaway_stream_id : Option<u32>,
+        /// 클라이언트가 GOAWAY 를 보냈는지 여부
+        peer_goaway : bool
+    }
+
+    /// Stream 상태
+    struct Stream {
+        /// 클라이언트가 아직 요청을 보내는 중 (END_STREAM 수신 전)
+        receiving : bool,
+        /// HTTP/1 형식으로 만든 요청 Line 과 Header
+        head : String,
+        body : Vec<u8>,
+        /// Stream 송신 Window
+        send_window : i64,
+        /// 응답 Header 를 보낸 뒤 남은 Body
+        response : Option<PendingBody>,
+        /// 응답이 끝난 뒤 RST_STREAM(NO_ERROR) 로 요청 Body 수신을 멈출지 여부
+        reset_after_response : bool,
+        /// HEAD 요청 여부 (응답 Body 를 보내지 않음)
+        head_request : bool
+    }
+
+    /// 응답 Header 를 보낸 뒤 전송할 Body
+    struct PendingBody {
+        data : Vec<u8>,
+        /// Server-Sent Events 응답이면 이벤트 스트림 (닫히면 None)
+        event_stream : Option<EventStream>,
+        last_write : Instant
+    }
+
+    /// CONTINUATION 으로 이어지는 Header Block
+    struct HeaderBlock {
+        stream_id : u32,
+        end_stream : bool,
+        block : Vec<u8>
+    }
+
+
+    /// HTTP/2 전환 요청 확인
+    ///
+    /// 접속의 첫 요청이 Connection Preface 이면 Prior Knowledge 접속으로, 평문 접속의 `Upgrade: h2c` 요청이면
+    /// 전환 요청으로 판단한다. Body 가 있는 `Upgrade: h2c` 요청은 전환하지 않고 HTTP/1.1 로 처리한다.
+    ///
+    /// # Argument
+    /// raw_request : 읽은 요청
+    ///
+    /// served_requests : 이 요청을 포함하여 접속에서 읽은 요청 수
+    ///
+    /// secure : TLS 접속 여부
+    pub(crate) fn http2_upgrade(raw_request : &RawRequest, served_requests : usize, secure : bool) -> Option<Http2Upgrade> {
+        if served_requests == 1 && raw_request.head == PREFACE_REQUEST_LINE {
+            return Some(Http2Upgrade {
+                request: None,
+                settings: Vec::new(),
+                buffered: Vec::new()
+            });
+        }
+        if secure || !raw_request.body.is_empty() {
+            return None;
+        }
+
+        let request : Request = raw_request.to_request();
+        if request.http_version != Some(HttpVersion::HTTP_1_1) {
+            return None;
+        }
+        let has_token = |name : &str, token : &str| -> bool {
+            return request.header(name)
+                .map(|value| value.split(',').any(|item| item.trim().eq_ignore_ascii_case(token)))
+                .unwrap_or(false);
+        };
+        if !has_token("Upgrade", "h2c") || !has_token("Connection", "Upgrade") || !has_token("Connection", "HTTP2-Settings") {
+            return None;
+        }
+        let settings : Vec<u8> = base64url_decode(request.header("HTTP2-Settings")?.trim())?;
+        if !settings.len().is_multiple_of(6) {
+            return None;
+        }
+
+        return Some(Http2Upgrade {
+            request: Some(RawRequest { head: raw_request.head.clone(), body: Vec::new() }),
+            settings,
+            buffered: Vec::new()
+        });
+    }
+
+
+    /// `Upgrade: h2c` 요청이면 보낼 `101 Switching Protocols` 응답
+    pub(crate) fn switching_protocols_text(upgrade : &Http2Upgrade) -> Option<&'static str> {
+        return upgrade.request.as_ref().map(|_| SWITCHING_PROTOCOLS);
+    }
+
+
+    /// HTTP/2 접속 처리
+    ///
+    /// 완성된 Stream 마다 Thread 를 만들어 Handler 를 실행하고, 응답은 Flow Control 에 맞춰 이 Thread 에서 전송한다.
+    /// 동시 Stream 수는 `ServerConfig.worker_count` (최대 100) 로 제한한다.
+    ///
+    /// # Argument
+    /// stream : HTTP/2 로 전환된 클라이언트 Stream
+    ///
+    /// upgrade : 전환 요청
+    ///
+    /// handler : Stream 요청 처리 Handler
+    pub(in crate::server) fn serve_http2(context : &ServerContext, stream : &mut ClientStream, upgrade : Http2Upgrade, client_ip : &str, handler : StreamHandler) {
+        // 로그 출력
+        println!("{}", log_more_text_writer(String::from("HTTP/2 connection opened."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+
+        std::thread::scope(|scope| {
+            let (sender, receiver) : (Sender<StreamResult>, Receiver<StreamResult>) = mpsc::channel();
+            let dispatch = |stream_id : u32, raw_request : RawRequest| {
+                let sender : Sender<StreamResult> = sender.clone();
+                scope.spawn(move || {
+                    let response : Option<Response> = run_stream(context, raw_request, client_ip, handler);
+                    let _ = sender.send((stream_id, response));
+                });
+            };
+
+            let mut connection : Connection = Connection::new(context, client_ip, upgrade.buffered, upgrade.request.is_some());
+            if connection.apply_settings(&upgrade.settings).is_err() {
+                return;
+            }
+            connection.write_settings();
+            // Upgrade 요청은 이미 요청을 모두 받은 Stream 1 로 처리
+            if let Some(raw_request) = upgrade.request {
+                connection.open_stream(1, raw_request.head, false);
+                connection.dispatch_stream(1, &dispatch);
+            }
+            connection.run(stream, &receiver, &dispatch);
+        });
+
+        // 로그 출력
+        println!("{}", log_more_text_writer(String::from("HTTP/2 connection closed."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+    }
+
+
+    /// Stream 요청 하나 처리 (Handler Thread)
+    fn run_stream(context : &ServerContext, raw_request : RawRequest, client_ip : &str, handler : StreamHandler) -> Option<Response> {
+        // Request 패킷 분석
+        let mut request : Request = raw_request.to_request();
+        attach_server_data(context, &mut request, client_ip);
+
+        // 클라이언트 IP 별 요청 수 확인 (Proxy 뒤의 클라이언트도 구분)
+        let request_ip : String = request_client_ip(&request, client_ip);
+        if let Err(retry_after) = context.rate_limiter.check_request(&request_ip) {
+            // 로그 출력
+            println!("{}", log_more_text_writer(String::from("Request rejected, rate limit exceeded."), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", request_ip)));
+
+            return Some(too_many_requests_response(&context.config, retry_after));
+        }
+
+        return handler(request);
+    }
+
+
+    impl<'a> Connection<'a> {
+        fn new(context : &'a ServerContext, client_ip : &'a str, buffered : Vec<u8>, upgraded : bool) -> Connection<'a> {
+            return Connection {
+                context,
+                client_ip,
+                buffer: buffered,
+                output: Vec::new(),
+                // Prior Knowledge 접속은 Preface 의 요청 Line 부분을 이미 읽었다
+                preface: if upgraded { CONNECTION_PREFACE } else { PREFACE_REMAINDER },
+                settings_received: false,
+                decoder: Decoder::new(HEADER_TABLE_SIZE),
+                encoder: Encoder::new(),
+                streams: HashMap::new(),
+                last_stream_id: 0,
+                continuation: None,
+                send_window: DEFAULT_WINDOW_SIZE,
+                initial_window: DEFAULT_WINDOW_SIZE,
+                max_frame_size: DEFAULT_MAX_FRAME_SIZE,
+                max_concurrent_streams: context.config.worker_count.clamp(1, MAX_CONCURRENT_STREAMS),
+                running_handlers: 0,
+                cancelled_streams: 0,
+                served_streams: 0,
+                goaway_stream_id: None,
+                peer_goaway: false
+            };
+        }
+
+
+        /// 접속이 끝날 때까지 Frame 을 읽고 응답 전송
+        fn run(&mut self, stream : &mut ClientStream, receiver : &Receiver<StreamResult>, dispatch : &dyn Fn(u32, RawRequest)) {
+            let mut chunk = [0; READ_CHUNK_SIZE];
+            let mut last_activity : Instant = Instant::now();
+
+            // Connection Preface 와 함께 읽은 Frame 처리
+            if !self.buffer.is_empty() {
+                if let Err(error_code) = self.process_frames(dispatch) {
+                    // 로그 출력
+                    println!("{}", log_more_text_writer(format!("HTTP/2 connection error: {:?}", error_code), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", self.client_ip)));
+
+                    self.send_goaway(error_code);
+                    self.flush(stream);
+                    return;
+                }
+            }
+
+            loop {
+                // Handler 결과 전송
+                while let Ok((stream_id, response)) = receiver.try_recv() {
+                    self.running_handlers -= 1;
+                    self.start_response(stream_id, response);
+                }
+                // 서버 종료 요청시 새 Stream 을 받지 않고 열린 이벤트 스트림을 닫는다
+                if self.context.state.is_shutdown() {
+                    self.send_goaway(ErrorCode::NO_ERROR);
+                    for pending in self.streams.values_mut().filter_map(|item| item.response.as_mut()) {
+                        pending.event_stream = None;
+                    }
+                }
+                self.poll_event_streams();
+                self.send_pending_data();
+                if !self.flush(stream) {
+                    return;
+                }
+                if (self.goaway_stream_id.is_some() || self.peer_goaway) && self.streams.is_empty() && self.running_handlers == 0 {
+                    return;
+                }
+
+                // Handler 결과와 이벤트를 확인할 수 있도록 짧게 대기
+                let busy : bool = self.running_handlers > 0
+                    || self.streams.values().any(|item| item.response.as_ref().is_some_and(|pending| pending.event_stream.is_some()));
+                let timeout : Duration = match busy {
+                    true => BUSY_POLL_INTERVAL,
+                    false => self.context.config.shutdown_poll_interval
+                };
+                set_read_timeout(stream, timeout);
+                match stream.read(&mut chunk) {
+                    Ok(0) => return,
+                    Ok(size) => {
+                        last_activity = Instant::now();
+                        self.buffer.extend_from_slice(&chunk[..size]);
+                        if let Err(error_code) = self.process_frames(dispatch) {
+                            // 로그 출력
+                            println!("{}", log_more_text_writer(format!("HTTP/2 connection error: {:?}", error_code), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", self.client_ip)));
+
+                            self.send_goaway(error_code);
+                            self.flush(stream);
+                            return;
+                        }
+                    },
+                    Err(error) if matches!(error.kind(), std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut) => {
+                        if !busy && self.streams.is_empty() && last_activity.elapsed() >= self.context.config.idle_timeout {
+                            // 로그 출력
+                            println!("{}", log_more_text_writer(String::from("HTTP/2 connection timed out."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", self.client_ip)));
+
+                            self.send_goaway(ErrorCode::NO_ERROR);
+                            self.flush(stream);
+                            return;
+                        }
+                    },
+                    Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {},
+                    Err(_) => return
+                }
+            }
+        }
+
+
+        /// 모아둔 Frame 전송
+        ///
+        /// # Return
+        /// 전송 실패시 false
+        fn flush(&mut self, stream : &mut ClientStream) -> bool {
+            if self.output.is_empty() {
+                return true;
+            }
+            let result = stream.write_all(&self.output).and_then(|_| stream.flush());
+            self.output.clear();
+
+            return result.is_ok();
+        }
+
+
+        /// 받은 데이터에서 완성된 Frame 처리
+        ///
+        /// # Return
+        /// 접속 오류가 발생하면 GOAWAY 로 보낼 오류 코드
+        fn process_frames(&mut self, dispatch : &dyn Fn(u32, RawRequest)) -> Result<(), ErrorCode> {
+            // Connection Preface 확인
+            if !self.preface.is_empty() {
+                let size : usize = self.preface.len().min(self.buffer.len());
+                if self.buffer[..size] != self.preface[..size] {
+                    return Err(ErrorCode::PROTOCOL_ERROR);
+                }
+                self.buffer.drain(..size);
+                self.preface = &self.preface[size..];
+                if !self.preface.is_empty() {
+                    return Ok(());
+                }
+            }
+
+            loop {
+                if self.buffer.len() < FRAME_HEADER_SIZE {
+                    return Ok(());
+                }
+                let length : usize = (self.buffer[0] as usize) << 16 | (self.buffer[1] as usize) << 8 | self.buffer[2] as usize;
+                if length > DEFAULT_MAX_FRAME_SIZE {
+                    return Err(ErrorCode::FRAME_SIZE_ERROR);
+                }
+                if self.buffer.len() < FRAME_HEADER_SIZE + length {
+                    return Ok(());
+                }
+                let frame_type : u8 = self.buffer[3];
+                let flags : u8 = self.buffer[4];
+                let stream_id : u32 = read_u32(&self.buffer[5..9]) & 0x7FFF_FFFF;
+                let payload : Vec<u8> = self.buffer[FRAME_HEADER_SIZE..FRAME_HEADER_SIZE + length].to_vec();
+                self.buffer.drain(..FRAME_HEADER_SIZE + length);
+
+                // Preface 다음 첫 Frame 은 SETTINGS
+                if !self.settings_received {
+                    if frame_type != FRAME_SETTINGS || flags & FLAG_ACK != 0 {
+                        return Err(ErrorCode::PROTOCOL_ERROR);
+                    }
+                    self.settings_received = true;
+                }
+                // Header Block 이 끝날 때까지 같은 Stream 의 CONTINUATION 만 허용
+                if let Some(block) = &self.continuation {
+                    if frame_type != FRAME_CONTINUATION || stream_id != block.stream_id {
+                        return Err(ErrorCode::PROTOCOL_ERROR);
+                    }
+                }
+
+                self.process_frame(frame_type, flags, stream_id, payload, dispatch)?;
+            }
+        }
+
+
+        /// Frame 하나 처리
+        fn process_frame(&mut self, frame_type : u8, flags : u8, stream_id : u32, payload : Vec<u8>, dispatch : &dyn Fn(u32, RawRequest)) -> Result<(), ErrorCode> {
+            match frame_type {
+                FRAME_DATA => {
+                    if stream_id == 0 {
+                        return Err(ErrorCode::PROTOCOL_ERROR);
+                    }
+                    let length : usize = payload.len();
+                    let data : &[u8] = strip_padding(flags, &payload)?;
+                    // 받은 만큼 접속 Window 를 바로 돌려준다
+                    if length > 0 {
+                        self.write_window_update(0, length);
+                    }
+                    self.receive_data(stream_id, data, length, flags & FLAG_END_STREAM != 0, dispatch)?;
+                },
+                FRAME_HEADERS => {
+                    if stream_id == 0 || stream_id.is_multiple_of(2) {
+                        return Err(ErrorCode::PROTOCOL_ERROR);
+                    }
+                    let mut block : &[u8] = strip_padding(flags, &payload)?;
+                    if flags & FLAG_PRIORITY != 0 {
+                        if block.len() < 5 {
+                            return Err(ErrorCode::FRAME_SIZE_ERROR);
+                        }
+                        block = &block[5..];
+                    }
+                    let header_block : HeaderBlock = HeaderBlock {
+                        stream_id,
+                        end_stream: flags & FLAG_END_STREAM != 0,
+                        block: block.to_vec()
+                    };
+                    if flags & FLAG_END_HEADERS != 0 {
+                        self.receive_headers(header_block, dispatch)?;
+                    }else {
+                        self.continuation = Some(header_block);
+                    }
+                },
+                FRAME_CONTINUATION => {
+                    let mut header_block : HeaderBlock = self.continuation.take().ok_or(ErrorCode::PROTOCOL_ERROR)?;
+                    header_block.block.extend_from_slice(&payload);
+                    // 끝나지 않는 Header Block 으로 메모리를 쓰지 않도록 제한
+                    if header_block.block.len() > self.context.config.max_header_bytes.max(DEFAULT_MAX_FRAME_SIZE) {
+                        return Err(ErrorCode::ENHANCE_YOUR_CALM);
+                    }
+                    if flags & FLAG_END_HEADERS != 0 {
+                        self.receive_headers(header_block, dispatch)?;
+                    }else {
+                        self.continuation = Some(header_block);
+                    }
+                },
+                FRAME_PRIORITY => {
+                    if stream_id == 0 {
+                        return Err(ErrorCode::PROTOCOL_ERROR);
+                    }
+                    if payload.len() != 5 {
+                        self.reset_stream(stream_id, ErrorCode::FRAME_SIZE_ERROR);
+                    }
+                },
+                FRAME_RST_STREAM => {
+                    if stream_id == 0 || stream_id > self.last_stream_id {
+                        return Err(ErrorCode::PROTOCOL_ERROR);
+                    }
+                    if payload.len() != 4 {
+                        return Err(ErrorCode::FRAME_SIZE_ERROR);
+                    }
+                    // 실행 중인 Handler 의 결과는 버린다
+                    if let Some(item) = self.streams.remove(&stream_id) {
+                        if !item.receiving && item.response.is_none() {
+                            self.count_cancelled_stream()?;
+                        }
+                    }
+                },
+                FRAME_SETTINGS => {
+                    if stream_id != 0 {
+                        return Err(ErrorCode::PROTOCOL_ERROR);
+                    }
+                    if flags & FLAG_ACK != 0 {
+                        if !payload.is_empty() {
+                            return Err(ErrorCode::FRAME_SIZE_ERROR);
+                        }
+                        return Ok(());
+                    }
+                    self.apply_settings(&payload)?;
+                    write_frame(&mut self.output, FRAME_SETTINGS, FLAG_ACK, 0, &[]);
+                },
+                FRAME_PUSH_PROMISE => {
+                    // 클라이언트는 Server Push 를 보낼 수 없다
+                    return Err(ErrorCode::PROTOCOL_ERROR);
+                },
+                FRAME_PING => {
+                    if stream_id != 0 {
+                        return Err(ErrorCode::PROTOCOL_ERROR);
+                    }
+                    if payload.len() != 8 {
+                        return Err(ErrorCode::FRAME_SIZE_ERROR);
+                    }
+                    if flags & FLAG_ACK == 0 {
+                        write_frame(&mut self.output, FRAME_PING, FLAG_ACK, 0, &payload);
+                    }
+                },
+                FRAME_GOAWAY => {
+                    if stream_id != 0 {
+                        return Err(ErrorCode::PROTOCOL_ERROR);
+                    }
+                    self.peer_goaway = true;
+                },
+                FRAME_WINDOW_UPDATE => {
+                    if payload.len() != 4 {
+                        return Err(ErrorCode::FRAME_SIZE_ERROR);
+                    }
+                    let increment : i64 = (read_u32(&payload) & 0x7FFF_FFFF) as i64;
+                    if stream_id == 0 {
+                        if increment == 0 {
+                            return Err(ErrorCode::PROTOCOL_ERROR);
+                        }
+                        self.send_window += increment;
+                        if self.send_window > MAX_WINDOW_SIZE {
+                            return Err(ErrorCode::FLOW_CONTROL_ERROR);
+                        }
+                    }else if let Some(item) = self.streams.get_mut(&stream_id) {
+                        item.send_window += increment;
+                        if increment == 0 {
+                            self.reset_stream(stream_id, ErrorCode::PROTOCOL_ERROR);
+                        }else if item.send_window > MAX_WINDOW_SIZE {
+                            self.reset_stream(stream_id, ErrorCode::FLOW_CONTROL_ERROR);
+                        }
+                    }
+                },
+                // 알 수 없는 Frame 은 무시
+                _ => {}
+            }
+
+            return Ok(());
+        }
+
+
+        /// 요청 Body 수신
+        fn receive_data(&mut self, stream_id : u32, data : &[u8], length : usize, end_stream : bool, dispatch : &dyn Fn(u32, RawRequest)) -> Result<(), ErrorCode> {
+            let max_body_bytes : usize = self.context.config.max_body_bytes;
+            let item : &mut Stream = match self.streams.get_mut(&stream_id) {
+                Some(item) if item.receiving => item,
+                _ => {
+                    if stream_id > self.last_stream_id {
+                        return Err(ErrorCode::PROTOCOL_ERROR);
+                    }
+                    self.reset_stream(stream_id, ErrorCode::STREAM_CLOSED);
+                    return Ok(());
+                }
+            };
+            // 이미 거절한 요청의 Body 는 버린다
+            if item.reset_after_response {
+                return Ok(());
+            }
+
+            if item.body.len() + data.len() > max_body_bytes {
+                // 로그 출력
+                println!("{}", log_more_text_writer(String::from("Request body too large."), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", self.client_ip)));
+
+                item.reset_after_response = true;
+                let response : Response = state_response_writer(HttpStateCode::HTTP_413, &self.context.config);
+                self.start_response(stream_id, Some(response));
+                return Ok(());
+            }
+            item.body.extend_from_slice(data);
+
+            if end_stream {
+                item.receiving = false;
+                self.dispatch_stream(stream_id, dispatch);
+            }else if length > 0 {
+                self.write_window_update(stream_id, length);
+            }
+
+            return Ok(());
+        }
+
+
+        /// Header Block 수신
+        fn receive_headers(&mut self, header_block : HeaderBlock, dispatch : &dyn Fn(u32, RawRequest)) -> Result<(), ErrorCode> {
+            let stream_id : u32 = header_block.stream_id;
+            // 처리하지 않는 Stream 도 동적 Table 을 맞추기 위해 복호화한다 (크기 초과는 None)
+            let headers : Option<Vec<(String, String)>> = match self.decoder.decode(&header_block.block, self.context.config.max_header_bytes) {
+                Ok(headers) => Some(headers),
+                Err(HpackError::HEADER_LIST_TOO_LARGE) => None,
+                Err(error) => {
+                    // 로그 출력
+                    println!("{}", log_more_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", self.client_ip)));
+                    return Err(ErrorCode::COMPRESSION_ERROR);
+                }
+            };
+
+            // 요청 Body 뒤의 Trailer
+            if let Some(item) = self.streams.get_mut(&stream_id) {
+                if !item.receiving {
+                    self.reset_stream(stream_id, ErrorCode::STREAM_CLOSED);
+                }else if !header_block.end_stream {
+                    self.reset_stream(stream_id, ErrorCode::PROTOCOL_ERROR);
+                }else if !item.reset_after_response {
+                    item.receiving = false;
+                    self.dispatch_stream(stream_id, dispatch);
+                }
+                return Ok(());
+            }
+            if stream_id <= self.last_stream_id {
+                return Err(ErrorCode::PROTOCOL_ERROR);
+            }
+            self.last_stream_id = stream_id;
+
+            // GOAWAY 이후의 Stream 은 처리하지 않는다
+            if self.goaway_stream_id.is_some() {
+                return Ok(());
+            }
+            // 취소된 Stream 의 Handler 도 끝날 때까지 동시 Stream 으로 센다 (Rapid Reset 방지)
+            if self.streams.len() >= self.max_concurrent_streams || self.running_handlers >= self.max_concurrent_streams {
+                self.reset_stream(stream_id, ErrorCode::REFUSED_STREAM);
+                return self.count_cancelled_stream();
+            }
+
+            // Header 크기 확인 (RFC 7541 4.1 기준 크기)
+            let headers : Vec<(String, String)> = match headers {
+                Some(headers) => headers,
+                None => {
+                    // 로그 출력
+                    println!("{}", log_more_text_writer(String::from("Request header too large."), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", self.client_ip)));
+
+                    self.open_stream(stream_id, String::new(), !header_block.end_stream);
+                    self.reject_stream(stream_id, HttpStateCode::HTTP_431);
+                    return Ok(());
+                }
+            };
+            let head : String = match request_head(&headers) {
+                Some(head) => head,
+                None => {
+                    // 로그 출력
+                    println!("{}", log_more_text_writer(String::from("Malformed HTTP/2 request header."), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", self.client_ip)));
+
+                    self.reset_stream(stream_id, ErrorCode::PROTOCOL_ERROR);
+                    return Ok(());
+                }
+            };
+
+            self.open_stream(stream_id, head, !header_block.end_stream);
+            if header_block.end_stream {
+                self.dispatch_stream(stream_id, dispatch);
+            }
+
+            return Ok(());
+        }
+
+
+        /// 새 Stream 등록
+        fn open_stream(&mut self, stream_id : u32, head : String, receiving : bool) {
+            self.last_stream_id = self.last_stream_id.max(stream_id);
+            let head_request : bool = head.starts_with("HEAD ");
+            self.streams.insert(stream_id, Stream {
+                receiving,
+                head_request,
+                head,
+                body: Vec::new(),
+                send_window: self.initial_window,
+                response: None,
+                reset_after_response: false
+            });
+        }
+
+
+        /// 요청을 모두 받은 Stream 의 Handler 실행
+        fn dispatch_stream(&mut self, stream_id : u32, dispatch : &dyn Fn(u32, RawRequest)) {
+            let item : &mut Stream = match self.streams.get_mut(&stream_id) {
+                Some(item) => item,
+                None => return
+            };
+            let raw_request : RawRequest = RawRequest {
+                head: std::mem::take(&mut item.head),
+                body: std::mem::take(&mut item.body)
+            };
+            self.running_handlers += 1;
+            dispatch(stream_id, raw_request);
+
+            // 접속 하나에서 처리할 최대 요청 수에 도달하면 새 Stream 을 받지 않는다
+            self.served_streams += 1;
+            if self.served_streams >= self.context.config.max_keep_alive_requests {
+                self.send_goaway(ErrorCode::NO_ERROR);
+            }
+        }
+
+
+        /// Handler 없이 상태 코드로 응답하고 요청 수신 중단
+        fn reject_stream(&mut self, stream_id : u32, http_code : HttpStateCode) {
+            if let Some(item) = self.streams.get_mut(&stream_id) {
+                item.reset_after_response = item.receiving;
+            }
+            let response : Response = state_response_writer(http_code, &self.context.config);
+            self.start_response(stream_id, Some(response));
+        }
+
+
+        /// Handler 결과의 응답 Header 전송
+        fn start_response(&mut self, stream_id : u32, response : Option<Response>) {
+            // 클라이언트가 취소한 Stream
+            if !self.streams.contains_key(&stream_id) {
+                return;
+            }
+            let response : Response = match response {
+                Some(response) => response,
+                None => {
+                    self.reset_stream(stream_id, ErrorCode::INTERNAL_ERROR);
+                    return;
+                }
+            };
+
+            let (headers, mut body, event_stream) : (Vec<(String, String)>, Vec<u8>, Option<EventStream>) = response_fields(&self.context.config, response);
+            // HEAD 요청은 content-length 만 GET 응답과 같게 보낸다
+            if self.streams.get(&stream_id).is_some_and(|item| item.head_request) {
+                body.clear();
+            }
+            let end_stream : bool = body.is_empty() && event_stream.is_none();
+            let block : Vec<u8> = self.encoder.encode(&headers);
+            self.write_header_block(stream_id, &block, end_stream);
+
+            if end_stream {
+                self.finish_stream(stream_id);
+                return;
+            }
+            if let Some(item) = self.streams.get_mut(&stream_id) {
+                item.response = Some(PendingBody {
+                    data: body,
+                    event_stream,
+                    last_write: Instant::now()
+                });
+            }
+        }
+
+
+        /// 이벤트 스트림의 새 이벤트와 접속 유지용 주석을 전송할 Body 에 추가
+        fn poll_event_streams(&mut self) {
+            let keep_alive_interval : Duration = self.context.config.event_stream_keep_alive;
+            for pending in self.streams.values_mut().filter_map(|item| item.response.as_mut()) {
+                let event_stream : &EventStream = match &pending.event_stream {
+                    Some(event_stream) => event_stream,
+                    None => continue
+                };
+                loop {
+                    match event_stream.receive_timeout(Duration::ZERO) {
+                        EventReceive::EVENT(event) => {
+                            pending.data.extend_from_slice(event.to_frame().as_bytes());
+                            pending.last_write = Instant::now();
+                        },
+                        EventReceive::TIMEOUT => break,
+                        EventReceive::CLOSED => {
+                            pending.event_stream = None;
+                            break;
+                        }
+                    }
+                }
+                if pending.event_stream.is_some() && pending.data.is_empty() && pending.last_write.elapsed() >= keep_alive_interval {
+                    pending.data.extend_from_slice(b": keep-alive\n\n");
+                    pending.last_write = Instant::now();
+                }
+            }
+        }
+
+
+        /// Flow Control Window 안에서 남은 응답 Body 전송
+        fn send_pending_data(&mut self) {
+            let mut finished : Vec<u32> = Vec::new();
+
+            for (stream_id, item) in self.streams.iter_mut() {
+                let pending : &mut PendingBody = match &mut item.response {
+                    Some(pending) => pending,
+                    None => continue
+                };
+                let mut offset : usize = 0;
+                while offset < pending.data.len() && self.send_window > 0 && item.send_window > 0 {
+                    let size : usize = (pending.data.len() - offset)
+                        .min(self.send_window as usize)
+                        .min(item.send_window as usize)
+                        .min(self.max_frame_size);
+                    let end_stream : bool = offset + size == pending.data.len() && pending.event_stream.is_none();
+                    let flags : u8 = if end_stream { FLAG_END_STREAM } else { 0 };
+                    write_frame(&mut self.output, FRAME_DATA, flags, *stream_id, &pending.data[offset..offset + size]);
+                    offset += size;
+                    self.send_window -= size as i64;
+                    item.send_window -= size as i64;
+                }
+                pending.data.drain(..offset);
+
+                if pending.data.is_empty() && pending.event_stream.is_none() {
+                    // Body 를 모두 보낸 뒤 닫힌 이벤트 스트림은 빈 DATA 로 끝을 알린다
+                    if offset == 0 {
+                        write_frame(&mut self.output, FRAME_DATA, FLAG_END_STREAM, *stream_id, &[]);
+                    }
+                    finished.push(*stream_id);
+                }
+            }
+
+            for stream_id in finished {
+                self.finish_stream(stream_id);
+            }
+        }
+
+
+        /// 응답을 모두 보낸 Stream 정리
+        fn finish_stream(&mut self, stream_id : u32) {
+            if let Some(item) = self.streams.remove(&stream_id) {
+                // 클라이언트가 보내는 중인 요청 Body 는 더 받지 않는다
+                if item.receiving {
+                    write_frame(&mut self.output, FRAME_RST_STREAM, 0, stream_id, &(ErrorCode::NO_ERROR as u32).to_be_bytes());
+                }
+            }
+        }
+
+
+        /// 클라이언트 SETTINGS 적용
+        fn apply_settings(&mut self, payload : &[u8]) -> Result<(), ErrorCode> {
+            if !payload.len().is_multiple_of(6) {
+                return Err(ErrorCode::FRAME_SIZE_ERROR);
+            }
+
+            for setting in payload.chunks(6) {
+                let identifier : u16 = u16::from_be_bytes([setting[0], setting[1]]);
+                let value : u32 = read_u32(&setting[2..6]);
+                match identifier {
+                    SETTINGS_ENABLE_PUSH if value > 1 => return Err(ErrorCode::PROTOCOL_ERROR),
+                    SETTINGS_INITIAL_WINDOW_SIZE => {
+                        if value as i64 > MAX_WINDOW_SIZE {
+                            return Err(ErrorCode::FLOW_CONTROL_ERROR);
+                        }
+                        // 열린 Stream 의 Window 도 차이만큼 조정
+                        let delta : i64 = value as i64 - self.initial_window;
+                        for item in self.streams.values_mut() {
+                            item.send_window += delta;
+                            if item.send_window > MAX_WINDOW_SIZE {
+                                return Err(ErrorCode::FLOW_CONTROL_ERROR);
+                            }
+                        }
+                        self.initial_window = value as i64;
+                    },
+                    SETTINGS_MAX_FRAME_SIZE => {
+                        if (value as usize) < DEFAULT_MAX_FRAME_SIZE || value as usize > MAX_FRAME_SIZE_LIMIT {
+                            return Err(ErrorCode::PROTOCOL_ERROR);
+                        }
+                        self.max_frame_size = value as usize;
+                    },
+                    // 응답 Header 는 동적 Table 을 사용하지 않으므로 HEADER_TABLE_SIZE 는 무시
+                    _ => {}
+                }
+            }
+
+            return Ok(());
+        }
+
+
+        /// 서버 SETTINGS 전송
+        fn write_settings(&mut self) {
+            let mut payload : Vec<u8> = Vec::new();
+            for (identifier, value) in [
+                (SETTINGS_HEADER_TABLE_SIZE, HEADER_TABLE_SIZE as u32),
+                (SETTINGS_ENABLE_PUSH, 0),
+                (SETTINGS_MAX_CONCURRENT_STREAMS, self.max_concurrent_streams as u32)
+            ] {
+                payload.extend_from_slice(&identifier.to_be_bytes());
+                payload.extend_from_slice(&value.to_be_bytes());
+            }
+            write_frame(&mut self.output, FRAME_SETTINGS, 0, 0, &payload);
+        }
+
+
+        /// Header Block 전송 (Frame 최대 크기를 넘으면 CONTINUATION 으로 나눈다)
+        fn write_header_block(&mut self, stream_id : u32, block : &[u8], end_stream : bool) {
+            let mut chunks = block.chunks(self.max_frame_size).peekable();
+            let mut frame_type : u8 = FRAME_HEADERS;
+            let mut flags : u8 = if end_stream { FLAG_END_STREAM } else { 0 };
+            if chunks.peek().is_none() {
+                write_frame(&mut self.output, frame_type, flags | FLAG_END_HEADERS, stream_id, &[]);
+                return;
+            }
+            while let Some(chunk) = chunks.next() {
+                if chunks.peek().is_none() {
+                    flags |= FLAG_END_HEADERS;
+                }
+                write_frame(&mut self.output, frame_type, flags, stream_id, chunk);
+                frame_type = FRAME_CONTINUATION;
+                flags = 0;
+            }
+        }
+
+
+        /// Window 반환
+        fn write_window_update(&mut self, stream_id : u32, increment : usize) {
+            write_frame(&mut self.output, FRAME_WINDOW_UPDATE, 0, stream_id, &(increment as u32).to_be_bytes());
+        }
+
+
+        /// 취소/거절된 Stream 수 기록
+        ///
+        /// # Return
+        /// 제한을 넘으면 ENHANCE_YOUR_CALM
+        fn count_cancelled_stream(&mut self) -> Result<(), ErrorCode> {
+            self.cancelled_streams += 1;
+            if self.cancelled_streams > self.max_concurrent_streams * MAX_CANCELLED_STREAMS_RATIO {
+                // 로그 출력
+                println!("{}", log_more_text_writer(String::from("Too many cancelled HTTP/2 streams."), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", self.client_ip)));
+
+                return Err(ErrorCode::ENHANCE_YOUR_CALM);
+            }
+
+            return Ok(());
+        }
+
+
+        /// Stream 오류 전송 후 Stream 정리
+        fn reset_stream(&mut self, stream_id : u32, error_code : ErrorCode) {
+            write_frame(&mut self.output, FRAME_RST_STREAM, 0, stream_id, &(error_code as u32).to_be_bytes());
+            self.streams.remove(&stream_id);
+        }
+
+
+        /// GOAWAY 전송 (접속당 한 번)
+        fn send_goaway(&mut self, error_code : ErrorCode) {
+            if self.goaway_stream_id.is_some() {
+                return;
+            }
+            let mut payload : Vec<u8> = Vec::with_capacity(8);
+            payload.extend_from_slice(&self.last_stream_id.to_be_bytes());
+            payload.extend_from_slice(&(error_code as u32).to_be_bytes());
+            write_frame(&mut self.output, FRAME_GOAWAY, 0, 0, &payload);
+            self.goaway_stream_id = Some(self.last_stream_id);
+        }
+    }
+
+
+    /// Frame 을 전송 Buffer 에 추가
+    fn write_frame(output : &mut Vec<u8>, frame_type : u8, flags : u8, stream_id : u32, payload : &[u8]) {
+        let length : usize = payload.len();
+        output.extend_from_slice(&[(length >> 16) as u8, (length >> 8) as u8, length as u8, frame_type, flags]);
+        output.extend_from_slice(&(stream_id & 0x7FFF_FFFF).to_be_bytes());
+        output.extend_from_slice(payload);
+    }
+
+
+    /// Big Endian 32bit 정수 읽기
+    fn read_u32(data : &[u8]) -> u32 {
+        return u32::from_be_bytes([data[0], data[1], data[2], data[3]]);
+    }
+
+
+    /// PADDED Flag 가 있는 Frame 의 Padding 제거
+    fn strip_padding(flags : u8, payload : &[u8]) -> Result<&[u8], ErrorCode> {
+        if flags & FLAG_PADDED == 0 {
+            return Ok(payload);
+        }
+        let padding : usize = *payload.first().ok_or(ErrorCode::FRAME_SIZE_ERROR)? as usize;
+        if padding >= payload.len() {
+            return Err(ErrorCode::PROTOCOL_ERROR);
+        }
+
+        return Ok(&payload[1..payload.len() - padding]);
+    }
+
+
+    /// HTTP/2 요청 Header 를 `request_parser` 가 읽을 수 있는 HTTP/1 형식으로 변환
+    ///
+    /// # Return
+    /// 요청 Line 과 Header, 잘못된 요청이면 None
+    fn request_head(headers : &[(String, String)]) -> Option<String> {
+        let mut method : Option<&str> = None;
+        let mut path : Option<&str> = None;
+        let mut scheme : Option<&str> = None;
+        let mut authority : Option<&str> = None;
+        let mut lines : Vec<String> = Vec::new();
+        let mut cookies : Vec<&str> = Vec::new();
+        let mut has_host : bool = false;
+
+        for (name, value) in headers {
+            // 요청 Line 과 Header 를 나누는 줄바꿈은 값에 포함될 수 없다
+            if value.contains(['\r', '\n', '\0']) {
+                return None;
+            }
+            // Pseudo Header 는 일반 Header 보다 앞에 한 번씩만 올 수 있다
+            if let Some(pseudo) = name.strip_prefix(':') {
+                let slot : &mut Option<&str> = match pseudo {
+                    "method" => &mut method,
+                    "path" => &mut path,
+                    "scheme" => &mut scheme,
+                    "authority" => &mut authority,
+                    _ => return None
+                };
+                if slot.is_some() || !lines.is_empty() || !cookies.is_empty() {
+                    return None;
+                }
+                *slot = Some(value.as_str());
+                continue;
+            }
+            if name.is_empty() || name.bytes().any(|byte| byte.is_ascii_uppercase() || byte <= b' ' || byte == b':') {
+                return None;
+            }
+            if CONNECTION_HEADERS.contains(&name.as_str()) || (name == "te" && value != "trailers") {
+                return None;
+            }
+            // 나뉘어 온 Cookie 는 하나로 합친다
+            if name == "cookie" {
+                cookies.push(value);
+                continue;
+            }
+            has_host |= name == "host";
+            lines.push(format!("{}: {}", name, value));
+        }
+
+        let (method, path) : (&str, &str) = (method?, path?);
+        if scheme.is_none() || path.is_empty() || path.contains(' ') || method.contains(' ') {
+            return None;
+        }
+        if let (Some(authority), false) = (authority, has_host) {
+            lines.insert(0, format!("host: {}", authority));
+        }
+        if !cookies.is_empty() {
+            lines.push(format!("cookie: {}", cookies.join("; ")));
+        }
+
+        let mut head : String = format!("{} {} HTTP/2", method, path);
+        for line in lines {
+            head.push_str("\r\n");
+            head.push_str(&line);
+        }
+
+        return Some(head);
+    }
+
+
+    /// 응답을 HTTP/2 응답 Header, Body, 이벤트 스트림으로 분리
+    ///
+    /// 완성되지 않은 응답은 Handler 오류로 보고 `500 Internal Server Error` 응답으로 대체한다.
+    fn response_fields(config : &ServerConfig, mut response : Response) -> (Vec<(String, String)>, Vec<u8>, Option<EventStream>) {
+        let mut event_stream : Option<EventStream> = response.event_stream.take();
+        if !response.is_complete() {
+            // 로그 출력
+            println!("{}", log_text_writer(String::from("Incomplete handler response, responding with 500."), get_this_name(), LogTypeTag::WARNING));
+            response = state_response_writer(HttpStateCode::HTTP_500, config);
+            event_stream = None;
+        }
+
+        // 상태 코드는 "200 OK" 형식의 앞부분
+        let status : String = response.response_code.as_ref()
+            .and_then(|code| default_http_state_writer(code).split(' ').next())
+            .unwrap_or("200")
+            .to_string();
+        let mut headers : Vec<(String, String)> = vec![(String::from(":status"), status)];
+        for (name, value) in response.headers.iter().flatten() {
+            let name : String = name.to_ascii_lowercase();
+            if CONNECTION_HEADERS.contains(&name.as_str()) || name == "content-length" {
+                continue;
+            }
+            headers.push((name, value.clone()));
+        }
+        for cookie in response.cookies.iter().flatten() {
+            headers.push((String::from("set-cookie"), format!("{}={}; Path={}", cookie.name, cookie.value, cookie.path)));
+        }
+
+        let body : Vec<u8> = match response.body {
+            Some(ResponseBody { body_str: Some(body) }) => body.into_bytes(),
+            _ => Vec::new()
+        };
+        // 이벤트 스트림은 길이를 알 수 없으므로 END_STREAM 으로 끝을 표시한다
+        if event_stream.is_none() {
+            headers.push((String::from("content-length"), body.len().to_string()));
+        }
+
+        return (headers, body, event_stream);
+    }
+
+
+    /// `HTTP2-Settings` Header 의 base64url 복호화 (Padding 생략 가능)
+    fn base64url_decode(value : &str) -> Option<Vec<u8>> {
+        let mut decoded : Vec<u8> = Vec::with_capacity(value.len() * 3 / 4);
+        let mut bits : u32 = 0;
+        let mut bit_count : u32 = 0;
+
+        for byte in value.trim_end_matches('=').bytes() {
+            let sextet : u32 = match byte {
+                b'A'..=b'Z' => byte - b'A',
+                b'a'..=b'z' => byte - b'a' + 26,
+                b'0'..=b'9' => byte - b'0' + 52,
+                b'-' => 62,
+                b'_' => 63,
+                _ => return None
+            } as u32;
+            bits = bits << 6 | sextet;
+            bit_count += 6;
+            if bit_count >= 8 {
+                bit_count -= 8;
+                decoded.push((bits >> bit_count) as u8);
+                bits &= (1 << bit_count) - 1;
+            }
+        }
+
+        return Some(decoded);
+    }
+
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::server::response_parser::response_parser::IsResponseDataCreateSuccess;
+
+
+        fn header<'a>(headers : &'a [(String, String)], name : &str) -> Option<&'a str> {
+            return headers.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str());
+        }
+
+
+        #[test]
+        fn converts_complete_responses_to_http2_fields() {
+            let config : ServerConfig = ServerConfig::default();
+            let mut response : Response = state_response_writer(HttpStateCode::HTTP_404, &config);
+            response.set_header("Connection", String::from("keep-alive"));
+
+            let (headers, body, event_stream) = response_fields(&config, response);
+            assert_eq!(header(&headers, ":status"), Some("404"));
+            assert_eq!(header(&headers, "connection"), None);
+            assert_eq!(header(&headers, "content-length"), Some(body.len().to_string().as_str()));
+            assert!(event_stream.is_none());
+        }
+
+
+        #[test]
+        fn answers_incomplete_responses_with_500() {
+            let config : ServerConfig = ServerConfig::default();
+            let response : Response = Response {
+                is_success: IsResponseDataCreateSuccess::FAIL,
+                response_code: None,
+                http_version: None,
+                headers: None,
+                cookies: None,
+                body: Some(ResponseBody { body_str: Some(String::from("partial")) }),
+                event_stream: None
+            };
+
+            let (headers, body, _) = response_fields(&config, response);
+            assert_eq!(header(&headers, ":status"), Some("500"));
+            assert_ne!(body, b"partial");
+        }
+    }
+}
@@ -0,0 +1,216 @@
+pub mod listener {
+    use std::net::{IpAddr, Ipv6Addr, SocketAddr, TcpListener, ToSocketAddrs};
+    #[cfg(unix)]
+    use std::os::unix::net::UnixListener;
+    #[cfg(unix)]
+    use std::path::PathBuf;
+    use socket2::{Domain, Socket, Type};
+    use crate::server::client_stream::client_stream::ClientStream;
+    use crate::error::AthenaError;
+
+
+    /// 접속 대기 Backlog 크기
+    const LISTEN_BACKLOG : i32 = 1024;
+
+    /// Unix Domain Socket 접속의 클라이언트 주소 표시
+    pub(crate) const UNIX_CLIENT_ADDRESS : &str = "(UnixSocket)";
+
+
+    /// 서버가 접속을 대기할 주소 Enum
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum BindAddress {
+        /// TCP 주소 (IP 주소 또는 Host 이름, Port), IPv6 주소는 IPv6 접속만 받는다
+        TCP(String, u16),
+        /// IPv4 와 IPv6 접속을 모두 받는 TCP 주소 (`[::]:port`)
+        DUAL_STACK(u16),
+        /// Unix Domain Socket 경로
+        #[cfg(unix)]
+        UNIX(PathBuf)
+    }
+
+    /// 실제로 접속을 대기하고 있는 주소 Enum
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum ListenAddress {
+        /// TCP 주소
+        TCP(SocketAddr),
+        /// Unix Domain Socket 경로
+        #[cfg(unix)]
+        UNIX(PathBuf)
+    }
+
+    /// 접속 대기 중인 리스너
+    pub(crate) enum Listener {
+        TCP(TcpListener),
+        #[cfg(unix)]
+        UNIX(UnixListener, PathBuf)
+    }
+
+
+    impl std::fmt::Display for BindAddress {
+        fn fmt(&self, f : &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            return match self {
+                // IPv6 주소는 [] 로 감싼다
+                BindAddress::TCP(ip, port) if ip.contains(':') => write!(f, "[{}]:{}", ip, port),
+                BindAddress::TCP(ip, port) => write!(f, "{}:{}", ip, port),
+                BindAddress::DUAL_STACK(port) => write!(f, "[::]:{}", port),
+                #[cfg(unix)]
+                BindAddress::UNIX(path) => write!(f, "unix:{}", path.display())
+            };
+        }
+    }
+
+
+    impl std::fmt::Display for ListenAddress {
+        fn fmt(&self, f : &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            return match self {
+                ListenAddress::TCP(address) => write!(f, "{}", address),
+                #[cfg(unix)]
+                ListenAddress::UNIX(path) => write!(f, "unix:{}", path.display())
+            };
+        }
+    }
+
+
+    impl Listener {
+        /// 주소 Bind 후 Non-blocking 리스너 생성
+        ///
+        /// # Return
+        /// 리스너와 실제 접속 대기 주소, 실패시 AthenaError
+        pub(crate) fn bind(address : &BindAddress) -> Result<(Listener, ListenAddress), AthenaError> {
+            let listener : Listener = match address {
+                BindAddress::TCP(ip, port) => {
+                    // 서버 주소 분석
+                    let resolved : Vec<SocketAddr> = match (ip.as_str(), *port).to_socket_addrs() {
+                        Ok(resolved) => resolved.collect(),
+                        Err(_) => return Err(AthenaError::ADDRESS_PARSE_FAILED(address.to_string()))
+                    };
+                    if resolved.is_empty() {
+                        return Err(AthenaError::ADDRESS_PARSE_FAILED(address.to_string()));
+                    }
+
+                    // 분석된 주소 중 처음으로 Bind 에 성공한 주소 사용
+                    let mut last_error : Option<std::io::Error> = None;
+                    let mut listener : Option<TcpListener> = None;
+                    for socket_address in resolved {
+                        match bind_tcp(socket_address, true) {
+                            Ok(value) => {
+                                listener = Some(value);
+                                break;
+                            },
+                            Err(error) => last_error = Some(error)
+                        }
+                    }
+                    match listener {
+                        Some(listener) => Listener::TCP(listener),
+                        None => {
+                            let error = last_error.unwrap_or_else(|| std::io::Error::from(std::io::ErrorKind::AddrNotAvailable));
+                            return Err(AthenaError::from_bind_error(address.to_string(), error));
+                        }
+                    }
+                },
+                BindAddress::DUAL_STACK(port) => {
+                    match bind_tcp(SocketAddr::new(IpAddr::V6(Ipv6Addr::UNSPECIFIED), *port), false) {
+                        Ok(listener) => Listener::TCP(listener),
+                        Err(error) => return Err(AthenaError::from_bind_error(address.to_string(), error))
+                    }
+                },
+                #[cfg(unix)]
+                BindAddress::UNIX(path) => {
+                    remove_stale_socket(path);
+                    match UnixListener::bind(path) {
+                        Ok(listener) => Listener::UNIX(listener, path.clone()),
+                        Err(error) => return Err(AthenaError::from_bind_error(address.to_string(), error))
+                    }
+                }
+            };
+
+            // 종료 요청 확인을 위해 Non-blocking 으로 접속 대기
+            if let Err(error) = listener.set_nonblocking(true) {
+                return Err(AthenaError::BIND_FAILED(address.to_string(), error));
+            }
+            let local_address : ListenAddress = match &listener {
+                Listener::TCP(tcp_listener) => match tcp_listener.local_addr() {
+                    Ok(local_addr) => ListenAddress::TCP(local_addr),
+                    Err(error) => return Err(AthenaError::BIND_FAILED(address.to_string(), error))
+                },
+                #[cfg(unix)]
+                Listener::UNIX(_, path) => ListenAddress::UNIX(path.clone())
+            };
+
+            return Ok((listener, local_address));
+        }
+
+
+        fn set_nonblocking(&self, nonblocking : bool) -> std::io::Result<()> {
+            return match self {
+                Listener::TCP(listener) => listener.set_nonblocking(nonblocking),
+                #[cfg(unix)]
+                Listener::UNIX(listener, _) => listener.set_nonblocking(nonblocking)
+            };
+        }
+
+
+        /// 접속 수락
+        ///
+        /// # Return
+        /// 클라이언트 Stream (Non-blocking 상태) 과 클라이언트 IP 주소
+        pub(crate) fn accept(&self) -> std::io::Result<(ClientStream, String)> {
+            return match self {
+                Listener::TCP(listener) => {
+                    let (stream, client_address) = listener.accept()?;
+                    // Dual-stack 리스너의 IPv4 접속 (`::ffff:127.0.0.1`) 은 IPv4 주소로 기록
+                    Ok((ClientStream::TCP(stream), client_address.ip().to_canonical().to_string()))
+                },
+                #[cfg(unix)]
+                Listener::UNIX(listener, _) => {
+                    let (stream, _) = listener.accept()?;
+                    Ok((ClientStream::UNIX(stream), String::from(UNIX_CLIENT_ADDRESS)))
+                }
+            };
+        }
+    }
+
+
+    impl Drop for Listener {
+        /// Unix Domain Socket 파일 삭제
+        fn drop(&mut self) {
+            #[cfg(unix)]
+            if let Listener::UNIX(_, path) = self {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+
+
+    /// TCP 리스너 생성
+    ///
+    /// # Argument
+    /// address : Bind 주소
+    ///
+    /// only_v6 : IPv6 주소에서 IPv4 접속을 받지 않을지 여부 (같은 Port 의 IPv4 주소와 함께 Bind 하기 위해 사용)
+    fn bind_tcp(address : SocketAddr, only_v6 : bool) -> std::io::Result<TcpListener> {
+        let socket : Socket = Socket::new(Domain::for_address(address), Type::STREAM, None)?;
+        if address.is_ipv6() {
+            socket.set_only_v6(only_v6)?;
+        }
+        #[cfg(unix)]
+        socket.set_reuse_address(true)?;
+        socket.bind(&address.into())?;
+        socket.listen(LISTEN_BACKLOG)?;
+        return Ok(socket.into());
+    }
+
+
+    /// 이전 실행에서 남은 Unix Domain Socket 파일 삭제 (Socket 이 아닌 파일과 사용 중인 Socket 은 건드리지 않는다)
+    #[cfg(unix)]
+    fn remove_stale_socket(path : &PathBuf) {
+        use std::os::unix::fs::FileTypeExt;
+
+        if let Ok(metadata) = std::fs::symlink_metadata(path) {
+            // 접속 가능한 Socket 은 다른 Process 가 사용 중
+            if metadata.file_type().is_socket() && std::os::unix::net::UnixStream::connect(path).is_err() {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+}
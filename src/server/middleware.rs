@@ -0,0 +1,174 @@
+pub mod middleware {
+    use std::sync::Arc;
+    use crate::server::request_parser::request_parser::Request;
+    use crate::server::response_parser::response_parser::Response;
+
+
+    /// 요청과 다음 처리 단계를 받아 응답을 만드는 Middleware
+    ///
+    /// `next.run(request)` 를 호출하기 전에 요청을 수정하거나, 호출하지 않고 바로 응답하거나,
+    /// 호출 결과 Response 를 수정하여 반환할 수 있다.
+    /// `Fn(&mut Request, Next) -> Response` Closure 도 Middleware 로 사용할 수 있다 (인자 Type 명시 필요).
+    ///
+    /// WebSocket Upgrade 요청은 Handshake 전에 실행되며, `next` 를 호출하면 Handshake 를 진행하고 (반환된 응답의 수정은 무시),
+    /// 호출하지 않고 응답하면 Handshake 대신 그 응답을 보낸다.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::Server;
+    /// # use AthenaEngine::server::middleware::middleware::Next;
+    /// # use AthenaEngine::server::request_parser::request_parser::Request;
+    /// # use AthenaEngine::server::response_parser::response_parser::{default_state_response_writer, HttpStateCode};
+    /// # let _ =
+    /// Server::builder()
+    ///     .middleware(|request : &mut Request, next : Next| {
+    ///         if request.header("Authorization").is_none() {
+    ///             return default_state_response_writer(HttpStateCode::HTTP_401);
+    ///         }
+    ///         let mut response = next.run(request);
+    ///         response.set_header("X-Frame-Options", String::from("DENY"));
+    ///         response
+    ///     })
+    /// # ;
+    /// ```
+    pub trait Middleware : Send + Sync {
+        /// 요청 처리
+        ///
+        /// # Argument
+        /// request : 요청 (다음 단계에 수정한 요청이 전달된다)
+        ///
+        /// next : 다음 Middleware 또는 Handler
+        fn handle(&self, request : &mut Request, next : Next<'_>) -> Response;
+    }
+
+    /// Middleware 의 다음 처리 단계 (남은 Middleware 와 마지막 Handler)
+    pub struct Next<'a> {
+        middlewares : &'a [Arc<dyn Middleware>],
+        endpoint : &'a dyn Fn(&mut Request) -> Response
+    }
+
+
+    impl<F> Middleware for F
+        where F : Fn(&mut Request, Next<'_>) -> Response + Send + Sync {
+        fn handle(&self, request : &mut Request, next : Next<'_>) -> Response {
+            return self(request, next);
+        }
+    }
+
+
+    impl Next<'_> {
+        /// 다음 Middleware 또는 Handler 실행
+        pub fn run(self, request : &mut Request) -> Response {
+            return match self.middlewares.split_first() {
+                Some((middleware, rest)) => middleware.handle(request, Next {
+                    middlewares: rest,
+                    endpoint: self.endpoint
+                }),
+                None => (self.endpoint)(request)
+            };
+        }
+    }
+
+
+    /// Middleware 를 등록 순서대로 실행하고 마지막에 Handler 실행
+    ///
+    /// # Argument
+    /// middlewares : 먼저 등록한 Middleware 가 바깥쪽에서 실행
+    ///
+    /// endpoint : 마지막 Handler
+    pub(crate) fn run_middlewares(middlewares : &[Arc<dyn Middleware>], request : &mut Request, endpoint : &dyn Fn(&mut Request) -> Response) -> Response {
+        return Next { middlewares, endpoint }.run(request);
+    }
+
+
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::sync::Mutex;
+        use crate::server::Server;
+        use crate::server::response_parser::response_parser::{default_state_response_writer, HttpStateCode, ResponseBody};
+        use crate::server::router::router::Router;
+        use crate::server::test_client::test_client::{TestClient, TestRequest};
+
+
+        /// 실행 순서 기록
+        type Trace = Arc<Mutex<Vec<String>>>;
+
+
+        /// `next` 호출 전후를 기록하는 Middleware
+        fn tracer(name : &'static str, trace : &Trace) -> impl Fn(&mut Request, Next<'_>) -> Response + Send + Sync + 'static {
+            let trace : Trace = Arc::clone(trace);
+            return move |request : &mut Request, next : Next<'_>| {
+                trace.lock().unwrap().push(format!("{} before", name));
+                let response : Response = next.run(request);
+                trace.lock().unwrap().push(format!("{} after", name));
+                response
+            };
+        }
+
+
+        /// 실행을 기록하고 `X-User` Header 를 Body 로 반환하는 Handler
+        fn handler(trace : &Trace) -> impl Fn(&Request) -> Response + Send + Sync + 'static {
+            let trace : Trace = Arc::clone(trace);
+            return move |request : &Request| {
+                trace.lock().unwrap().push(String::from("handler"));
+                let mut response : Response = default_state_response_writer(HttpStateCode::HTTP_200);
+                response.body = Some(ResponseBody { body_str: request.header("X-User").cloned() });
+                response
+            };
+        }
+
+
+        #[test]
+        fn runs_server_router_and_route_middlewares_from_outside_in() {
+            let trace : Trace = Arc::new(Mutex::new(Vec::new()));
+            let router : Router = Router::new()
+                .get("/users/:id", handler(&trace)).unwrap()
+                .route_middleware("/users/:id", tracer("route", &trace)).unwrap()
+                .middleware(tracer("router", &trace));
+            let client : TestClient = TestClient::new(&Server::builder()
+                .middleware(tracer("first", &trace))
+                .middleware(tracer("second", &trace))
+                .router(router)
+                .build());
+
+            client.get("/users/7").assert_status(HttpStateCode::HTTP_200);
+            assert_eq!(*trace.lock().unwrap(), vec![
+                "first before", "second before", "router before", "route before",
+                "handler",
+                "route after", "router after", "second after", "first after"
+            ]);
+        }
+
+
+        #[test]
+        fn stops_the_chain_when_a_middleware_answers() {
+            let trace : Trace = Arc::new(Mutex::new(Vec::new()));
+            let client : TestClient = TestClient::new(&Server::builder()
+                .middleware(|request : &mut Request, next : Next<'_>| {
+                    let user : String = match request.header("Authorization") {
+                        Some(token) => token.clone(),
+                        None => return default_state_response_writer(HttpStateCode::HTTP_401)
+                    };
+                    // 다음 단계에 수정한 요청 전달
+                    request.http_header.get_or_insert_with(Default::default).insert(String::from("X-User"), user);
+                    let mut response : Response = next.run(request);
+                    response.set_header("X-Frame-Options", String::from("DENY"));
+                    response
+                })
+                .middleware(tracer("inner", &trace))
+                .event_response(handler(&trace))
+                .build());
+
+            client.get("/").assert_status(HttpStateCode::HTTP_401).assert_no_header("X-Frame-Options");
+            assert!(trace.lock().unwrap().is_empty());
+
+            client.send(TestRequest::get("/").header("Authorization", "athena"))
+                .assert_body("athena")
+                .assert_header("X-Frame-Options", "DENY");
+            assert_eq!(*trace.lock().unwrap(), vec!["inner before", "handler", "inner after"]);
+        }
+    }
+}
@@ -1,15 +1,35 @@
+use std::any::Any;
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::io::prelude::*;
-use std::net::TcpStream;
-use std::net::TcpListener;
-use std::time::Duration;
-use std::sync::mpsc::{self, Receiver, Sender};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
-use chrono::{DateTime, Local};
-
-use crate::server::request_parser::request_parser::{Request, request_parser};
-use crate::server::response_parser::response_parser::{Response, response_parser};
+use crate::server::request_parser::request_parser::{Method, Request};
+use crate::server::request_reader::request_reader::{RawRequest, RequestReadError, RequestReader};
+use crate::server::response_parser::response_parser::{default_http_state_writer, HttpStateCode, Response, ResponseBody, response_parser, state_response_writer};
+use crate::server::page_manager::page_manager::{AllPages, PageInfo};
+use crate::server::thread_pool::thread_pool::{QueueFullPolicy, TaskHandler, ThreadPool};
+use crate::server::server_config::server_config::ServerConfig;
+use crate::server::client_stream::client_stream::ClientStream;
+use crate::server::event_stream::event_stream::{EventReceive, EventStream};
+use crate::server::websocket::websocket::{switching_protocols_text, upgrade_required_text, websocket_accept, WebSocket, WebSocketUpgrade};
+use crate::server::http2::http2::{http2_upgrade, serve_http2, Http2Upgrade};
+use crate::server::virtual_host::virtual_host::{VirtualHost, VirtualHosts};
+use crate::server::proxy::proxy::{forwarded_client_ip, read_proxy_header};
+use crate::server::router::router::{Router, RouteMatch};
+use crate::server::middleware::middleware::{run_middlewares, Middleware};
+use crate::server::listener::listener::{BindAddress, ListenAddress, Listener};
+use crate::server::rate_limiter::rate_limiter::{RateLimiter, RateLimitPolicy};
+#[cfg(feature = "tls")]
+use crate::server::tls::tls::TlsConfig;
+#[cfg(feature = "async")]
+use crate::server::async_server::async_server::AsyncResponseHandler;
+use crate::server::server_handle::server_handle::{ServerHandle, ServerState};
 use crate::log::{log_more_text_writer, log_text_writer, LogTypeTag};
+use crate::error::AthenaError;
 
 /// Public module - request_parser
 pub mod request_parser;
@@ -17,6 +37,50 @@ pub mod request_parser;
 pub mod response_parser;
 /// Public module - page_manager
 pub mod page_manager;
+/// Public module - thread_pool
+pub mod thread_pool;
+/// Public module - request_reader
+pub mod request_reader;
+/// Public module - server_handle
+pub mod server_handle;
+/// Public module - server_config
+pub mod server_config;
+/// Public module - client_stream
+pub mod client_stream;
+/// Public module - listener
+pub mod listener;
+/// Public module - rate_limiter
+pub mod rate_limiter;
+/// Public module - event_stream
+pub mod event_stream;
+/// Public module - websocket
+pub mod websocket;
+/// Public module - hpack
+pub mod hpack;
+/// Public module - http2
+pub mod http2;
+/// Public module - test_client
+pub mod test_client;
+/// Public module - virtual_host
+pub mod virtual_host;
+/// Public module - proxy
+pub mod proxy;
+/// Public module - router
+pub mod router;
+/// Public module - middleware
+pub mod middleware;
+/// Public module - tls (`tls` feature)
+#[cfg(feature = "tls")]
+pub mod tls;
+/// Public module - async_server (`async` feature)
+#[cfg(feature = "async")]
+pub mod async_server;
+
+
+/// IP 별 최대 동시 접속 수 초과 시 `Retry-After` 시간
+const RETRY_AFTER_CONNECTION_LIMIT : Duration = Duration::from_secs(1);
+/// 한 번의 `read` 호출로 읽을 최대 크기
+const READ_CHUNK_SIZE : usize = 4096;
 
 
 /// 현재 파일 정보 반환
@@ -25,173 +89,2285 @@ fn get_this_name() -> String{
 }
 
 
-/// 클라이언트 작업 성공 여부
-enum TaskSuccess {
-    Success,
-    Error
-}
-
 /// 클라이언트 접속 이벤트 Handler
-pub type RequestHandler = Box<dyn Send + (Fn(&Request)) + 'static>;
-pub type ResponseHandler = Box<dyn Send + (Fn(&Request) -> Response) + 'static>;
+pub type RequestHandler = Box<dyn Send + Sync + Fn(&Request) + 'static>;
+pub type ResponseHandler = Box<dyn Send + Sync + Fn(&Request) -> Response + 'static>;
+/// WebSocket 경로 Handler (Upgrade 된 접속을 받아 Handler 가 끝날 때까지 사용)
+pub type WebSocketHandler = Box<dyn Send + Sync + Fn(&Request, WebSocket) + 'static>;
+/// `Expect: 100-continue` 요청의 Body 수신 여부 결정 Handler (Body 가 없는 Request 를 받는다)
+pub type ContinueHandler = Box<dyn Send + Sync + Fn(&Request) -> Result<(), HttpStateCode> + 'static>;
+/// 요청 하나의 Request / Response 이벤트 실행 함수 (요청, 클라이언트 IP)
+type EventRunner<'a> = &'a (dyn Fn(Request, &str) -> Option<Response> + Sync);
 /// 클라이언트 접속 이벤트 Struct
 pub struct ClientEvent {
     pub event_request : Option<RequestHandler>,
-    pub event_response : Option<ResponseHandler>
+    pub event_response : Option<ResponseHandler>,
+    /// 경로 패턴별 Response Handler (일치하는 Route 가 없으면 `event_response` 실행)
+    pub router : Option<Router>,
+    /// Route 와 Response 이벤트를 감싸는 Middleware (먼저 추가한 Middleware 가 바깥쪽에서 실행)
+    pub middlewares : Vec<Arc<dyn Middleware>>,
+    /// 비동기 Response 이벤트 (`async` feature, `start_async` 에서 `event_response` 대신 사용)
+    #[cfg(feature = "async")]
+    pub event_response_async : Option<AsyncResponseHandler>
 }
 
-/// 클라이언트 접속 이벤트
+/// 클라이언트 접속 이벤트 (전역 호환 API, `start_server` 에서 사용)
+#[cfg(feature = "legacy-globals")]
 pub static mut EVENT: ClientEvent = ClientEvent {
     event_request: None,
-    event_response: None
+    event_response: None,
+    router: None,
+    middlewares: Vec::new(),
+    #[cfg(feature = "async")]
+    event_response_async: None
 };
 
 
 /// Thread 실행 인자
 pub struct ThreadTaskArgs {
-    pub tcp_stream : TcpStream
+    pub stream : ClientStream,
+    pub client_ip : String
 }
 
 
-/// Athena Engine 서버 시작 함수
-///
-/// # Examples
-///
-/// ```
-/// server::start_server("127.0.0.1", 8080)
-/// ```
-///
-/// # Argument
-/// server_ip : 서버 IP 주소
+/// HTTP/1.1 요청 처리를 마치고 다른 Protocol 로 넘길 접속
+enum ConnectionUpgrade {
+    WEBSOCKET(Box<WebSocketUpgrade>),
+    HTTP2(Http2Upgrade),
+    /// Header 를 보낸 Server-Sent Events 응답의 이벤트 스트림 (요청의 클라이언트 주소)
+    EVENT_STREAM(EventStream, String)
+}
+
+
+/// 요청의 Host 로 선택한 처리 대상
+enum Site<'a> {
+    /// 가상 호스트의 페이지와 이벤트
+    VIRTUAL_HOST(&'a VirtualHost),
+    /// 일치하는 가상 호스트가 없을 때의 응답
+    UNKNOWN_HOST(&'a ResponseHandler),
+    /// 서버의 페이지와 이벤트
+    SERVER
+}
+
+
+/// 모든 Thread 가 공유하는 서버 데이터
+struct ServerContext {
+    event : ClientEvent,
+    pages : Arc<AllPages>,
+    config : Arc<ServerConfig>,
+    state : Arc<ServerState>,
+    /// HTTPS 설정 (없으면 평문 HTTP)
+    #[cfg(feature = "tls")]
+    tls : Option<TlsConfig>,
+    /// `Expect: 100-continue` 요청 확인 Handler
+    continue_handler : Option<ContinueHandler>,
+    /// 경로별 WebSocket Handler
+    websocket_handlers : HashMap<String, WebSocketHandler>,
+    /// Host 별 가상 호스트
+    virtual_hosts : VirtualHosts,
+    /// 클라이언트 IP 별 접속 수 및 요청 수 제한
+    rate_limiter : RateLimiter,
+    /// 현재 열려 있는 접속 수 (대기열 포함)
+    connections : AtomicUsize,
+    /// 현재 열려 있는 스트림 접속 수 (WebSocket, HTTP/2, Server-Sent Events)
+    stream_connections : Arc<AtomicUsize>
+}
+
+
+impl ServerContext {
+    /// 요청의 Host 에 맞는 이벤트 (알 수 없는 Host 응답을 사용하면 None)
+    fn client_event(&self, request : &Request) -> Option<&ClientEvent> {
+        return match self.site(request) {
+            Site::VIRTUAL_HOST(host) => Some(&host.event),
+            Site::UNKNOWN_HOST(_) => None,
+            Site::SERVER => Some(&self.event)
+        };
+    }
+
+
+    /// 요청의 Host 로 처리 대상 선택 (가상 호스트, 알 수 없는 Host 응답, 서버 순서)
+    fn site(&self, request : &Request) -> Site<'_> {
+        if let Some(host) = self.virtual_hosts.resolve(request.host.as_deref()) {
+            return Site::VIRTUAL_HOST(host);
+        }
+        if let Some(handler) = self.virtual_hosts.unknown_host() {
+            return Site::UNKNOWN_HOST(handler);
+        }
+
+        return Site::SERVER;
+    }
+}
+
+
+/// Athena Engine 서버
 ///
-/// server_port : 서버 Port 번호 (0 ~ 65535)
-pub fn start_server(server_ip : String, server_port : u16) {
-    // TCP 리스너 생성
-    let listener = TcpListener::bind(format!("{}:{}", server_ip, server_port)).unwrap();
-    // TCP 연결 대기
-    for stream in listener.incoming() {
-        // Stream 추출
-        match stream {
-            Ok(stream) => { // 작업 성공!
-                // Timeout 설정 - read_timeout
-                match &stream.set_read_timeout(Some(Duration::from_secs(15))) {
-                    Ok(_) => {}
-                    Err(error) => {
-                        // 오류 로그 작성
-                        println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
-                    }
-                };
-                // Timeout 설정 - read_timeout
-                match &stream.set_write_timeout(Some(Duration::from_secs(15))) {
-                    Ok(_) => {}
-                    Err(error) => {
-                        // 오류 로그 작성
-                        println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
-                    }
-                };
-                // 인자 데이터 설정
-                let arg : ThreadTaskArgs = ThreadTaskArgs {
-                    tcp_stream: stream
-                };
-                // Thread 로 작업 전송
-                std::thread::spawn(move || {
-                    handle_connection(arg);
-                });
+/// 요청/응답 이벤트, 페이지 리스트, 접속 주소를 소유하며 `Server::builder()` 로 생성한다.
+pub struct Server {
+    addresses : Vec<BindAddress>,
+    context : Arc<ServerContext>
+}
+
+/// 서버는 여러 Thread 에서 공유되므로 Send + Sync 를 보장해야 한다
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Server>();
+};
+
+/// Athena Engine 서버 생성기
+pub struct ServerBuilder {
+    addresses : Vec<BindAddress>,
+    config : ServerConfig,
+    #[cfg(feature = "tls")]
+    tls : Option<TlsConfig>,
+    event : ClientEvent,
+    continue_handler : Option<ContinueHandler>,
+    websocket_handlers : HashMap<String, WebSocketHandler>,
+    virtual_hosts : VirtualHosts,
+    pages : AllPages
+}
+
+
+impl Server {
+    /// 서버 생성기 반환
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use AthenaEngine::error::AthenaError;
+    /// # use AthenaEngine::server::Server;
+    /// # use AthenaEngine::server::page_manager::page_manager::PageInfo;
+    /// # use AthenaEngine::server::response_parser::response_parser::default_response_writer;
+    /// # fn main() -> Result<(), AthenaError> {
+    /// # let page_info = PageInfo { file_path: String::from("hello.html"), is_access: true };
+    /// let server = Server::builder()
+    ///     .bind(String::from("127.0.0.1"), 8080)
+    ///     .page(String::from("/hello.html"), page_info)
+    ///     .event_response(|request| default_response_writer(request, None, None))
+    ///     .build();
+    /// server.run()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> ServerBuilder {
+        return ServerBuilder {
+            addresses: Vec::new(),
+            config: ServerConfig::default(),
+            #[cfg(feature = "tls")]
+            tls: None,
+            event: ClientEvent {
+                event_request: None,
+                event_response: None,
+                router: None,
+                middlewares: Vec::new(),
+                #[cfg(feature = "async")]
+                event_response_async: None
             },
-            Err(error) =>  { // 작업 실패, 예외 처리
-                // 오류 로그 작성
-                println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
-            }
+            continue_handler: None,
+            websocket_handlers: HashMap::new(),
+            virtual_hosts: VirtualHosts::new(),
+            pages: AllPages::new()
         };
     }
-}
 
 
-/// Athena Engine Client 접근 처리 함수
-fn handle_connection(mut threadPoolArgs: ThreadTaskArgs) {
-    // 작업 성공 여부
-    let mut task_success = TaskSuccess::Success;
-    // HTTP 요청 읽기
-    let mut buffer = [0; 1024];
-    match &threadPoolArgs.tcp_stream.read(&mut buffer) {
-        Ok(_) => {},
-        Err(error) => {
-            task_success = TaskSuccess::Error;
+    /// 서버 접속 주소 반환 (여러 주소를 설정한 경우 첫 번째 주소)
+    pub fn address(&self) -> String {
+        return self.addresses[0].to_string();
+    }
+
+
+    /// 서버가 접속을 대기할 모든 주소 반환
+    pub fn addresses(&self) -> &[BindAddress] {
+        return &self.addresses;
+    }
+
+
+    /// 서버 실행 (서버가 종료될 때까지 현재 Thread 대기)
+    ///
+    /// # Return
+    /// 서버 주소 Bind 실패시 AthenaError
+    pub fn run(self) -> Result<(), AthenaError> {
+        self.start()?.wait();
+        return Ok(());
+    }
 
+
+    /// 서버 시작
+    ///
+    /// 접속 대기는 별도 Thread 에서 실행되며 반환된 `ServerHandle` 로 서버를 종료할 수 있다.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use AthenaEngine::error::AthenaError;
+    /// # use AthenaEngine::server::Server;
+    /// # use std::time::Duration;
+    /// # fn main() -> Result<(), AthenaError> {
+    /// # let server = Server::builder().bind(String::from("127.0.0.1"), 8080).build();
+    /// let handle = server.start()?;
+    /// // ...
+    /// handle.shutdown_with_timeout(Duration::from_secs(10));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Return
+    /// ServerHandle 구조체, 서버 주소 Bind 또는 작업 Thread 생성 실패시 AthenaError
+    pub fn start(self) -> Result<ServerHandle, AthenaError> {
+        let (listeners, local_addresses) = self.bind_listeners()?;
+        // 작업 Thread Pool 생성
+        let context = Arc::clone(&self.context);
+        let handler : TaskHandler<ThreadTaskArgs> = Arc::new(move |arg : ThreadTaskArgs| {
+            handle_connection(&context, arg);
+        });
+        let config : &ServerConfig = &self.context.config;
+        let pool : ThreadPool<ThreadTaskArgs> = ThreadPool::new(config.worker_count, config.queue_size, config.queue_full_policy, handler)?;
+        let handle : ServerHandle = ServerHandle::new(Arc::clone(&self.context.state), local_addresses);
+
+        // 로그 출력
+        println!("{}", log_more_text_writer(String::from("Server started."), get_this_name(), LogTypeTag::INFO, handle.address_text()));
+
+        #[cfg(feature = "async")]
+        if self.context.event.event_response.is_none() && self.context.event.event_response_async.is_some() {
             // 로그 출력
-            println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+            println!("{}", log_text_writer(String::from("Async response EVENT handler is only used by start_async()."), get_this_name(), LogTypeTag::WARNING));
+        }
+
+        let spawn_result = std::thread::Builder::new()
+            .name(String::from("athena-accept"))
+            .spawn(move || {
+                self.accept_loop(listeners, pool);
+            });
+        if let Err(error) = spawn_result {
+            return Err(AthenaError::THREAD_SPAWN_FAILED(error));
         }
+
+        return Ok(handle);
     }
-    // Request 데이터
-    let binding = String::from_utf8_lossy(&buffer);
-    let mut http_request : Vec<&str> = binding.split("\r\n").collect();
-    // 클라이언트 IP 주소
-    let client_ip : String = match threadPoolArgs.tcp_stream.peer_addr() {
-        Ok(value) => value.ip().to_string(),
-        Err(_) => String::from("(NoIPAddress)")
-    };
-    // 작업 성공 여부 비교
-    match task_success {
-        _Success => { // 작업 성공
-            // Request 패킷 분석
-            let request = request_parser(&http_request);
-            // Request 이벤트 실행
-            unsafe {
-                match &EVENT.event_request {
-                    Some(Box) => {
-                        // 로그 출력
-                        println!("{}", log_more_text_writer(String::from("Run request EVENT handler."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
-
-                        // 이벤트 실행
-                        Box(&request);
-                    },
-                    None => {
-                        // 로그 출력
-                        println!("{}", log_more_text_writer(String::from("Request EVENT handler failed, no registered EVENT."), get_this_name(), LogTypeTag::INFO, format!("IP: {}", client_ip)));
-                    }
-                }
-            }
-            // Response 이벤트 실행
-            unsafe {
-                match &EVENT.event_response {
-                    Some(Box) => {
-                        // 로그 출력
-                        println!("{}", log_more_text_writer(String::from("Run response EVENT handler."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
-
-                        // 이벤트 실행 결과
-                        let result : Response = Box(&request);
-                        // Response 생성
-                        let mut response = response_parser(result);
-                        // 응답 반환
-                        match threadPoolArgs.tcp_stream.write_all(response.as_bytes()) {
-                            Ok(_) => {}
-                            Err(error) => {
-                                // 로그 출력
-                                println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
-                            }
+
+
+    /// 모든 서버 주소 Bind (Non-blocking 리스너와 실제 접속 대기 주소 반환)
+    fn bind_listeners(&self) -> Result<(Vec<Listener>, Vec<ListenAddress>), AthenaError> {
+        let mut listeners : Vec<Listener> = Vec::with_capacity(self.addresses.len());
+        let mut local_addresses : Vec<ListenAddress> = Vec::with_capacity(self.addresses.len());
+
+        // 하나라도 실패하면 먼저 생성한 리스너도 닫힌다
+        for address in &self.addresses {
+            let (listener, local_address) = Listener::bind(address)?;
+            listeners.push(listener);
+            local_addresses.push(local_address);
+        }
+
+        return Ok((listeners, local_addresses));
+    }
+
+
+    /// 접속 대기 (종료 요청시 반환)
+    fn accept_loop(self, listeners : Vec<Listener>, pool : ThreadPool<ThreadTaskArgs>) {
+        let config : &ServerConfig = &self.context.config;
+        // 연결 대기
+        while !self.context.state.is_shutdown() {
+            // 이번 확인에서 접속을 하나라도 수락했는지 여부
+            let mut accepted : bool = false;
+
+            for listener in &listeners {
+                // Stream 추출
+                match listener.accept() {
+                    Ok((stream, client_ip)) => { // 작업 성공!
+                        accepted = true;
+                        // 접속한 Stream 은 Blocking 으로 처리
+                        configure_blocking_stream(config, &stream);
+                        // 최대 접속 수 확인
+                        if self.context.connections.load(Ordering::SeqCst) >= config.max_connections {
+                            // 로그 출력
+                            println!("{}", log_more_text_writer(String::from("Connection rejected, too many open connections."), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", client_ip)));
+
+                            self.reject_connection(stream, state_response_text(config, HttpStateCode::HTTP_503));
+                            continue;
+                        }
+                        // IP 별 최대 접속 수 확인
+                        if !self.context.rate_limiter.acquire_connection(&client_ip) {
+                            // 로그 출력
+                            println!("{}", log_more_text_writer(String::from("Connection rejected, too many connections from this client."), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", client_ip)));
+
+                            self.reject_connection(stream, too_many_requests_text(config, RETRY_AFTER_CONNECTION_LIMIT));
+                            continue;
                         }
-                        // 응답 전송
-                        match threadPoolArgs.tcp_stream.flush() {
-                            Ok(_) => {}
-                            Err(error) => {
-                                // 로그 출력
-                                println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
-                            }
+                        // 인자 데이터 설정
+                        let arg : ThreadTaskArgs = ThreadTaskArgs {
+                            stream,
+                            client_ip: client_ip.clone()
+                        };
+                        // Thread Pool 로 작업 전송
+                        self.context.connections.fetch_add(1, Ordering::SeqCst);
+                        if let Err(arg) = pool.execute(arg, &client_ip) {
+                            self.context.connections.fetch_sub(1, Ordering::SeqCst);
+                            self.context.rate_limiter.release_connection(&client_ip);
+                            // 대기열 초과, 503 응답
+                            self.reject_connection(arg.stream, state_response_text(config, HttpStateCode::HTTP_503));
                         }
                     },
-                    None => {
-                        // 로그 출력
-                        println!("{}", log_more_text_writer(String::from("Response EVENT handler failed, no registered EVENT."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+                    Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => {}, // 대기 중인 접속 없음
+                    Err(error) =>  { // 작업 실패, 예외 처리
+                        // 오류 로그 작성
+                        println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
                     }
-                }
+                };
             }
+
+            if !accepted {
+                std::thread::sleep(config.shutdown_poll_interval);
+            }
+        }
+
+        // 새 접속 수락 중단 후 처리 중인 작업 종료 대기
+        drop(listeners);
+        drop(pool);
+        // 전용 Thread 로 넘긴 스트림 접속 종료 대기
+        while self.context.connections.load(Ordering::SeqCst) > 0 {
+            std::thread::sleep(config.shutdown_poll_interval);
         }
-        _Error => { // 작업 실패
-            // 함수 종료
+
+        // 로그 출력
+        println!("{}", log_text_writer(String::from("Server stopped."), get_this_name(), LogTypeTag::INFO));
+
+        self.context.state.mark_finished();
+    }
+
+
+    /// 처리하지 않을 접속에 상태 코드 응답 후 종료
+    fn reject_connection(&self, mut stream : ClientStream, response : String) {
+        // HTTPS 리스너는 Handshake 없이 평문 응답을 보낼 수 없으므로 바로 종료
+        #[cfg(feature = "tls")]
+        if self.context.tls.is_some() && matches!(stream, ClientStream::TCP(_)) {
             return;
         }
+
+        write_response_text(&mut stream, &response);
+        discard_unread_data(&mut stream, self.context.config.max_header_bytes);
+    }
+}
+
+
+impl ServerBuilder {
+    /// 서버 접속 주소 추가
+    ///
+    /// 여러 번 호출하면 모든 주소에서 같은 Handler 로 접속을 처리한다.
+    /// 주소를 하나도 설정하지 않으면 `127.0.0.1:8080` 을 사용한다.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::Server;
+    /// # let _ =
+    /// Server::builder()
+    ///     .bind(String::from("0.0.0.0"), 8080)
+    ///     .bind(String::from("::"), 8080)
+    /// # ;
+    /// ```
+    ///
+    /// # Argument
+    /// server_ip : 서버 IP 주소 (IPv6 주소는 IPv6 접속만 받는다)
+    ///
+    /// server_port : 서버 Port 번호 (0 ~ 65535)
+    pub fn bind(mut self, server_ip : String, server_port : u16) -> ServerBuilder {
+        self.addresses.push(BindAddress::TCP(server_ip, server_port));
+        return self;
+    }
+
+
+    /// IPv4 와 IPv6 접속을 모두 받는 주소 추가 (`[::]:server_port`)
+    pub fn bind_dual_stack(mut self, server_port : u16) -> ServerBuilder {
+        self.addresses.push(BindAddress::DUAL_STACK(server_port));
+        return self;
+    }
+
+
+    /// Unix Domain Socket 주소 추가
+    ///
+    /// 이전 실행에서 남은 Socket 파일은 삭제 후 다시 생성하며 서버 종료시 삭제한다.
+    /// HTTPS 설정은 Unix Domain Socket 접속에 적용되지 않는다.
+    ///
+    /// # Argument
+    /// socket_path : Socket 파일 경로
+    #[cfg(unix)]
+    pub fn bind_unix(mut self, socket_path : String) -> ServerBuilder {
+        self.addresses.push(BindAddress::UNIX(std::path::PathBuf::from(socket_path)));
+        return self;
+    }
+
+
+    /// 서버 접속 주소 추가
+    pub fn bind_address(mut self, address : BindAddress) -> ServerBuilder {
+        self.addresses.push(address);
+        return self;
+    }
+
+
+    /// Request 이벤트 설정
+    pub fn event_request<F>(mut self, handler : F) -> ServerBuilder
+        where F : Fn(&Request) + Send + Sync + 'static {
+        self.event.event_request = Some(Box::new(handler));
+        return self;
+    }
+
+
+    /// Response 이벤트 설정
+    pub fn event_response<F>(mut self, handler : F) -> ServerBuilder
+        where F : Fn(&Request) -> Response + Send + Sync + 'static {
+        self.event.event_response = Some(Box::new(handler));
+        return self;
+    }
+
+
+    /// Router 설정 (일치하는 Route 가 없으면 Response 이벤트 실행, Response 이벤트가 없으면 `404 Not Found`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::error::AthenaError;
+    /// # use AthenaEngine::server::Server;
+    /// # use AthenaEngine::server::router::router::Router;
+    /// # use AthenaEngine::server::response_parser::response_parser::Response;
+    /// # use AthenaEngine::server::response_parser::response_parser::default_response_writer;
+    /// # fn user_page(_id : Option<&str>) -> Response { AthenaEngine::server::response_parser::response_parser::default_state_response_writer(AthenaEngine::server::response_parser::response_parser::HttpStateCode::HTTP_200) }
+    /// # fn main() -> Result<(), AthenaError> {
+    /// let router = Router::new()
+    ///     .route("/users/:id", |request| user_page(request.path_param("id")))?;
+    /// # let _ =
+    /// Server::builder()
+    ///     .router(router)
+    ///     .event_response(|request| default_response_writer(request, None, None))
+    /// # ;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn router(mut self, router : Router) -> ServerBuilder {
+        self.event.router = Some(router);
+        return self;
+    }
+
+
+    /// 모든 요청에 적용할 Middleware 추가 (먼저 추가한 Middleware 가 바깥쪽에서 실행)
+    ///
+    /// Request 이벤트 뒤, Route 와 Response 이벤트를 감싸서 실행한다.
+    /// 처리할 Route 와 Response 이벤트가 없으면 `next` 는 `404 Not Found` 응답을 반환한다.
+    ///
+    /// WebSocket 경로의 Upgrade 요청도 Handshake 전에 실행하며, 모든 Middleware 가 `next` 를 호출해야 Handshake 를 진행한다 (`websocket` 참고).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::Server;
+    /// # use AthenaEngine::server::request_parser::request_parser::Request;
+    /// # use std::time::Instant;
+    /// # use AthenaEngine::server::middleware::middleware::Next;
+    /// # let _ =
+    /// Server::builder()
+    ///     .middleware(|request : &mut Request, next : Next| {
+    ///         let started = Instant::now();
+    ///         let response = next.run(request);
+    ///         println!("{:?} {:?}", request.path(), started.elapsed());
+    ///         response
+    ///     })
+    /// # ;
+    /// ```
+    pub fn middleware<M>(mut self, middleware : M) -> ServerBuilder
+        where M : Middleware + 'static {
+        self.event.middlewares.push(Arc::new(middleware));
+        return self;
+    }
+
+
+    /// `Expect: 100-continue` 요청 확인 Handler 설정
+    ///
+    /// Body 를 받기 전에 Header 만 담은 Request 로 호출된다. Ok 를 반환하면 `100 Continue` 를 보내고 Body 를 받으며,
+    /// Err 를 반환하면 해당 상태 코드 (`HTTP_417`, `HTTP_413` 등) 로 응답한 뒤 접속을 닫는다.
+    /// 설정하지 않으면 모든 요청에 `100 Continue` 를 보낸다.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::Server;
+    /// # use AthenaEngine::server::response_parser::response_parser::HttpStateCode;
+    /// # let _ =
+    /// Server::builder().expect_continue(|request| {
+    ///     match request.header("Content-Type") {
+    ///         Some(value) if value.starts_with("image/") => Ok(()),
+    ///         _ => Err(HttpStateCode::HTTP_417)
+    ///     }
+    /// })
+    /// # ;
+    /// ```
+    pub fn expect_continue<F>(mut self, handler : F) -> ServerBuilder
+        where F : Fn(&Request) -> Result<(), HttpStateCode> + Send + Sync + 'static {
+        self.continue_handler = Some(Box::new(handler));
+        return self;
+    }
+
+
+    /// WebSocket 경로 Handler 등록
+    ///
+    /// 해당 경로의 `Upgrade: websocket` 요청은 Handshake (`101 Switching Protocols`) 후 Handler 에 전달되고,
+    /// Handshake 에 실패한 요청은 `426 Upgrade Required` 로 응답한다.
+    /// Handler 는 접속이 끝날 때까지 작업 Thread 하나를 사용한다.
+    ///
+    /// 경로는 요청 경로와 정확히 일치해야 하며 (경로 Parameter, Wildcard 없음), Router 를 거치지 않는다.
+    /// 가상 호스트로 온 요청도 같은 경로면 이 Handler 가 처리하고, 같은 경로의 Route 는 사용되지 않는다.
+    /// Handshake 전에 Host 에 맞는 Middleware (서버 또는 가상 호스트) 를 실행하므로 인증 Middleware 도 적용된다.
+    /// Middleware 가 `next` 를 호출하지 않고 응답하면 그 응답을 보내고 접속을 닫으며, 알 수 없는 Host 는 `unknown_host_response` 로 응답한다.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::Server;
+    /// # use AthenaEngine::server::websocket::websocket::Message;
+    /// # let _ =
+    /// Server::builder().websocket(String::from("/echo"), |_request, mut websocket| {
+    ///     while let Ok(message) = websocket.recv() {
+    ///         match message {
+    ///             Message::TEXT(text) => { let _ = websocket.send_text(&text); },
+    ///             Message::CLOSE(_) => break,
+    ///             _ => {}
+    ///         }
+    ///     }
+    /// })
+    /// # ;
+    /// ```
+    pub fn websocket<F>(mut self, path : String, handler : F) -> ServerBuilder
+        where F : Fn(&Request, WebSocket) + Send + Sync + 'static {
+        self.websocket_handlers.insert(path, Box::new(handler));
+        return self;
+    }
+
+
+    /// 가상 호스트 등록
+    ///
+    /// 요청의 `Host` Header (Port 제외, 대소문자 구분 없음) 가 일치하면 서버의 페이지와 이벤트 대신 가상 호스트의 것을 사용한다.
+    /// `*.example.com` 은 `example.com` 을 제외한 모든 하위 도메인과 일치하며, 정확한 이름이 Wildcard 보다,
+    /// 긴 Wildcard 가 짧은 Wildcard 보다 우선한다. 같은 이름을 다시 등록하면 대체한다.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::Server;
+    /// # use AthenaEngine::server::virtual_host::virtual_host::VirtualHost;
+    /// # let main_site = VirtualHost::new();
+    /// # let user_sites = VirtualHost::new();
+    /// # let _ =
+    /// Server::builder()
+    ///     .virtual_host(String::from("example.com"), main_site)
+    ///     .virtual_host(String::from("*.example.com"), user_sites)
+    ///     .default_host(String::from("example.com"))
+    /// # ;
+    /// ```
+    pub fn virtual_host(mut self, pattern : String, host : VirtualHost) -> ServerBuilder {
+        self.virtual_hosts.insert(pattern, host);
+        return self;
+    }
+
+
+    /// 일치하는 가상 호스트가 없거나 `Host` Header 가 없는 요청을 처리할 가상 호스트 설정
+    ///
+    /// # Argument
+    /// pattern : `virtual_host` 로 등록한 이름
+    pub fn default_host(mut self, pattern : String) -> ServerBuilder {
+        self.virtual_hosts.set_default_host(pattern);
+        return self;
+    }
+
+
+    /// 일치하는 가상 호스트도 기본 Host 도 없는 요청의 응답 설정
+    ///
+    /// 설정하지 않으면 서버의 페이지와 이벤트로 처리한다.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::Server;
+    /// # use AthenaEngine::server::response_parser::response_parser::{default_state_response_writer, HttpStateCode};
+    /// # let _ =
+    /// Server::builder().unknown_host_response(|_request| {
+    ///     default_state_response_writer(HttpStateCode::HTTP_404)
+    /// })
+    /// # ;
+    /// ```
+    pub fn unknown_host_response<F>(mut self, handler : F) -> ServerBuilder
+        where F : Fn(&Request) -> Response + Send + Sync + 'static {
+        self.virtual_hosts.set_unknown_host(Box::new(handler));
+        return self;
+    }
+
+
+    /// 서버 설정 (기존 설정 대체)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::Server;
+    /// # use std::time::Duration;
+    /// # use AthenaEngine::server::server_config::server_config::ServerConfig;
+    /// # let _ =
+    /// Server::builder().config(ServerConfig {
+    ///     read_timeout: Duration::from_secs(30),
+    ///     ..ServerConfig::default()
+    /// })
+    /// # ;
+    /// ```
+    pub fn config(mut self, config : ServerConfig) -> ServerBuilder {
+        self.config = config;
+        return self;
+    }
+
+
+    /// HTTPS 설정 (`tls` feature)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use AthenaEngine::error::AthenaError;
+    /// # use AthenaEngine::server::Server;
+    /// # use AthenaEngine::server::tls::tls::TlsConfig;
+    /// # fn main() -> Result<(), AthenaError> {
+    /// let tls_config = TlsConfig::from_pem_files(String::from("cert.pem"), String::from("key.pem"))?;
+    /// # let _ =
+    /// Server::builder().bind(String::from("0.0.0.0"), 443).tls(tls_config)
+    /// # ;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "tls")]
+    pub fn tls(mut self, tls_config : TlsConfig) -> ServerBuilder {
+        self.tls = Some(tls_config);
+        return self;
+    }
+
+
+    /// 작업 Thread 수 설정
+    pub fn worker_count(mut self, worker_count : usize) -> ServerBuilder {
+        self.config.worker_count = worker_count;
+        return self;
+    }
+
+
+    /// 작업 대기열 크기 설정
+    pub fn queue_size(mut self, queue_size : usize) -> ServerBuilder {
+        self.config.queue_size = queue_size;
+        return self;
+    }
+
+
+    /// 작업 대기열이 가득 찼을 때 처리 방식 설정
+    ///
+    /// `QueueFullPolicy::BLOCK` 은 대기열에 자리가 생길 때까지 접속 수락을 멈추고,
+    /// `QueueFullPolicy::REJECT` 는 `503 Service Unavailable` 을 응답한 뒤 접속을 닫는다.
+    pub fn queue_full_policy(mut self, queue_full_policy : QueueFullPolicy) -> ServerBuilder {
+        self.config.queue_full_policy = queue_full_policy;
+        return self;
+    }
+
+
+    /// 요청 Line 과 Header 의 최대 크기 설정 (초과 시 `431 Request Header Fields Too Large`)
+    pub fn max_header_bytes(mut self, max_header_bytes : usize) -> ServerBuilder {
+        self.config.max_header_bytes = max_header_bytes;
+        return self;
+    }
+
+
+    /// 요청 Body 의 최대 크기 설정 (초과 시 `413 Request Entity Too Large`)
+    pub fn max_body_bytes(mut self, max_body_bytes : usize) -> ServerBuilder {
+        self.config.max_body_bytes = max_body_bytes;
+        return self;
+    }
+
+
+    /// 클라이언트 IP 별 요청 수 제한 설정 (초과 시 `429 Too Many Requests` 와 `Retry-After` 응답)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::Server;
+    /// # use AthenaEngine::server::rate_limiter::rate_limiter::RateLimitPolicy;
+    /// # let _ =
+    /// Server::builder().rate_limit(RateLimitPolicy::TOKEN_BUCKET { capacity: 20, refill_per_second: 5.0 })
+    /// # ;
+    /// ```
+    pub fn rate_limit(mut self, policy : RateLimitPolicy) -> ServerBuilder {
+        self.config.rate_limit = Some(policy);
+        return self;
+    }
+
+
+    /// 클라이언트 IP 별 최대 동시 접속 수 설정 (초과 시 `429 Too Many Requests`)
+    pub fn max_connections_per_ip(mut self, max_connections_per_ip : usize) -> ServerBuilder {
+        self.config.max_connections_per_ip = Some(max_connections_per_ip);
+        return self;
+    }
+
+
+    /// Server-Sent Events 스트림의 접속 유지용 주석 전송 간격 설정
+    pub fn event_stream_keep_alive(mut self, interval : Duration) -> ServerBuilder {
+        self.config.event_stream_keep_alive = interval;
+        return self;
+    }
+
+
+    /// Keep-Alive 접속의 다음 요청 대기 시간 설정
+    pub fn keep_alive_timeout(mut self, keep_alive_timeout : Duration) -> ServerBuilder {
+        self.config.idle_timeout = keep_alive_timeout;
+        return self;
+    }
+
+
+    /// 요청의 첫 데이터를 받은 뒤 Header 를 모두 받을 때까지의 제한 시간 설정 (초과 시 `408 Request Timeout`)
+    pub fn header_timeout(mut self, header_timeout : Duration) -> ServerBuilder {
+        self.config.header_timeout = header_timeout;
+        return self;
+    }
+
+
+    /// 최소 Body 전송 속도 설정, 초당 Byte (미달 시 `408 Request Timeout`, 0 이면 제한 없음)
+    pub fn min_body_bytes_per_second(mut self, min_body_bytes_per_second : usize) -> ServerBuilder {
+        self.config.min_body_bytes_per_second = min_body_bytes_per_second;
+        return self;
+    }
+
+
+    /// 동시에 열어둘 최대 스트림 접속 수 설정, WebSocket / HTTP/2 / Server-Sent Events (초과 시 `503 Service Unavailable`)
+    pub fn max_stream_connections(mut self, max_stream_connections : usize) -> ServerBuilder {
+        self.config.max_stream_connections = max_stream_connections;
+        return self;
+    }
+
+
+    /// 접속 하나에서 처리할 최대 요청 수 설정 (1 이면 Keep-Alive 사용 안 함)
+    pub fn max_keep_alive_requests(mut self, max_keep_alive_requests : usize) -> ServerBuilder {
+        self.config.max_keep_alive_requests = max_keep_alive_requests;
+        return self;
+    }
+
+
+    /// 접속 하나를 Keep-Alive 로 유지할 최대 시간 설정, 접속 시작 기준 (초과 후 응답은 `Connection: close`)
+    pub fn max_keep_alive_duration(mut self, max_keep_alive_duration : Duration) -> ServerBuilder {
+        self.config.max_keep_alive_duration = max_keep_alive_duration;
+        return self;
+    }
+
+
+    /// 페이지 추가
+    ///
+    /// # Argument
+    /// page_path : HTTP 경로
+    ///
+    /// page_info : 페이지 정보
+    pub fn page(mut self, page_path : String, page_info : PageInfo) -> ServerBuilder {
+        self.pages.insert_page(page_path, page_info);
+        return self;
+    }
+
+
+    /// 페이지 리스트 설정 (기존 페이지 리스트 대체)
+    pub fn pages(mut self, pages : AllPages) -> ServerBuilder {
+        self.pages = pages;
+        return self;
+    }
+
+
+    /// 서버 생성
+    pub fn build(mut self) -> Server {
+        if self.addresses.is_empty() {
+            self.addresses.push(BindAddress::TCP(String::from("127.0.0.1"), 8080));
+        }
+
+        let rate_limiter : RateLimiter = RateLimiter::from_config(&self.config);
+
+        return Server {
+            addresses: self.addresses,
+            context: Arc::new(ServerContext {
+                event: self.event,
+                pages: Arc::new(self.pages),
+                config: Arc::new(self.config),
+                state: Arc::new(ServerState::new()),
+                #[cfg(feature = "tls")]
+                tls: self.tls,
+                continue_handler: self.continue_handler,
+                websocket_handlers: self.websocket_handlers,
+                virtual_hosts: self.virtual_hosts,
+                rate_limiter,
+                connections: AtomicUsize::new(0),
+                stream_connections: Arc::new(AtomicUsize::new(0))
+            })
+        };
+    }
+}
+
+
+/// Athena Engine 서버 시작 함수 (전역 호환 API)
+///
+/// `EVENT` 와 `ALL_PAGES` 에 설정된 내용으로 서버를 생성하여 실행한다.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use AthenaEngine::server;
+/// # fn main() -> Result<(), AthenaEngine::error::AthenaError> {
+/// server::start_server(String::from("127.0.0.1"), 8080)
+/// # }
+/// ```
+///
+/// # Argument
+/// server_ip : 서버 IP 주소
+///
+/// server_port : 서버 Port 번호 (0 ~ 65535)
+///
+/// # Return
+/// 서버 주소 Bind 실패시 AthenaError
+#[cfg(feature = "legacy-globals")]
+pub fn start_server(server_ip : String, server_port : u16) -> Result<(), AthenaError> {
+    // 전역 이벤트 및 페이지 리스트 가져오기
+    let (event, pages) = unsafe {
+        let event = &mut *std::ptr::addr_of_mut!(EVENT);
+        let all_pages = &*std::ptr::addr_of!(page_manager::page_manager::ALL_PAGES);
+        let pages = AllPages {
+            pages: all_pages.pages.clone()
+        };
+        (ClientEvent {
+            event_request: event.event_request.take(),
+            event_response: event.event_response.take(),
+            router: event.router.take(),
+            middlewares: std::mem::take(&mut event.middlewares),
+            #[cfg(feature = "async")]
+            event_response_async: event.event_response_async.take()
+        }, pages)
+    };
+
+    let mut builder = Server::builder().bind(server_ip, server_port).pages(pages);
+    builder.event = event;
+    return builder.build().run();
+}
+
+
+/// 접속한 Stream 을 Blocking 으로 전환하고 Timeout 설정
+fn configure_blocking_stream(config : &ServerConfig, stream : &ClientStream) {
+    if let Err(error) = stream.set_nonblocking(false) {
+        // 오류 로그 작성
+        println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+    }
+    // Timeout 설정 - read_timeout
+    if let Err(error) = stream.set_read_timeout(Some(config.read_timeout)) {
+        // 오류 로그 작성
+        println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+    }
+    // Timeout 설정 - write_timeout
+    if let Err(error) = stream.set_write_timeout(Some(config.write_timeout)) {
+        // 오류 로그 작성
+        println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+    }
+}
+
+
+/// 접속을 닫는 상태 코드 응답 생성
+fn state_response_text(config : &ServerConfig, http_code : HttpStateCode) -> String {
+    let mut response : Response = state_response_writer(http_code, config);
+    response.set_header("Connection", String::from("close"));
+    return response_parser(response);
+}
+
+
+/// 요청 수 제한 초과 응답 생성 (`429 Too Many Requests`, `Retry-After`)
+fn too_many_requests_text(config : &ServerConfig, retry_after : Duration) -> String {
+    let mut response : Response = too_many_requests_response(config, retry_after);
+    response.set_header("Connection", String::from("close"));
+    return response_parser(response);
+}
+
+
+/// 요청 수 제한 초과 Response 생성
+fn too_many_requests_response(config : &ServerConfig, retry_after : Duration) -> Response {
+    let mut response : Response = state_response_writer(HttpStateCode::HTTP_429, config);
+    // Retry-After 는 초 단위 (최소 1초)
+    let retry_after_secs : u64 = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+    response.set_header("Retry-After", retry_after_secs.max(1).to_string());
+    return response;
+}
+
+
+/// 접속을 닫기 전 마지막 응답 전송
+fn write_response_text(stream : &mut ClientStream, response : &str) {
+    if let Err(error) = stream.write_all(response.as_bytes()).and_then(|_| stream.flush()) {
+        // 로그 출력
+        println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+    }
+}
+
+
+/// 접속을 닫기 전 이미 받은 요청 데이터 버리기
+///
+/// 읽지 않은 데이터가 남은 채로 접속을 닫으면 RST 가 전송되어 클라이언트가 보낸 응답을 읽지 못할 수 있다.
+///
+/// # Argument
+/// max_bytes : 버릴 최대 크기
+fn discard_unread_data(stream : &mut ClientStream, max_bytes : usize) {
+    if stream.set_nonblocking(true).is_err() {
+        return;
+    }
+    let mut buffer : [u8; READ_CHUNK_SIZE] = [0; READ_CHUNK_SIZE];
+    let mut discarded : usize = 0;
+    while discarded < max_bytes {
+        match stream.read(&mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(read_size) => discarded += read_size
+        }
+    }
+}
+
+
+/// 응답 전송 전 접속 관련 Header 설정
+///
+/// # Argument
+/// response : 전송할 응답
+///
+/// keep_alive : 응답 후 접속 유지 여부
+///
+/// # Return
+/// 실제 접속 유지 여부 (응답을 변환할 수 없으면 접속을 닫는다)
+fn finalize_response(context : &ServerContext, response : &mut Response, keep_alive : bool) -> bool {
+    if !response.is_complete() {
+        return false;
     }
 
-    // 함수 종료
-    return;
-}
\ No newline at end of file
+    // Content-Length 는 실제 Body 길이로 설정 (Keep-Alive 접속의 요청 경계)
+    let body_len : usize = response.body_len();
+    response.set_header("Content-Length", body_len.to_string());
+    // 헤더 데이터 설정 - Connection
+    if keep_alive {
+        response.set_header("Connection", String::from("keep-alive"));
+        response.set_header("Keep-Alive", format!("timeout={}, max={}", context.config.idle_timeout.as_secs(), context.config.max_keep_alive_requests));
+    }else {
+        response.set_header("Connection", String::from("close"));
+    }
+
+    return keep_alive;
+}
+
+
+/// HEAD 요청의 응답 Body 제거 (`finalize_response` 뒤에 호출하여 Content-Length 는 GET 응답의 Body 길이 유지)
+fn strip_head_body(request_method : Option<Method>, response : &mut Response) {
+    if request_method == Some(Method::HEAD) {
+        response.body = Some(ResponseBody { body_str: Some(String::new()) });
+    }
+}
+
+
+/// Server-Sent Events 응답 Header 설정
+///
+/// 스트림 길이를 알 수 없으므로 Content-Length 없이 접속 종료로 응답의 끝을 표시한다.
+///
+/// # Return
+/// 응답을 전송할 수 있으면 true
+fn finalize_event_stream_response(response : &mut Response) -> bool {
+    if !response.is_complete() {
+        return false;
+    }
+
+    if let Some(headers) = &mut response.headers {
+        headers.retain(|key, _| !key.eq_ignore_ascii_case("Content-Length"));
+    }
+    response.set_header("Connection", String::from("close"));
+
+    return true;
+}
+
+
+/// Server-Sent Events 스트림 전송
+///
+/// 모든 전송기가 Drop 되거나, 클라이언트 접속이 끊기거나, 서버가 종료될 때까지 이벤트를 전송한다.
+fn serve_event_stream(context : &ServerContext, stream : &mut ClientStream, event_stream : EventStream, client_ip : &str) {
+    // 로그 출력
+    println!("{}", log_more_text_writer(String::from("Event stream opened."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+
+    let mut timer : EventStreamTimer = EventStreamTimer::new(&context.config);
+    while !context.state.is_shutdown() {
+        let frame : String = match event_stream.receive_timeout(timer.wait_time()) {
+            EventReceive::EVENT(event) => event.to_frame(),
+            EventReceive::TIMEOUT => {
+                if is_client_closed(stream) {
+                    break;
+                }
+                match timer.keep_alive_frame() {
+                    Some(frame) => frame,
+                    None => continue
+                }
+            },
+            EventReceive::CLOSED => break
+        };
+        if stream.write_all(frame.as_bytes()).and_then(|_| stream.flush()).is_err() {
+            break;
+        }
+        timer.written();
+    }
+
+    // 로그 출력
+    println!("{}", log_more_text_writer(String::from("Event stream closed."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+}
+
+
+/// Server-Sent Events 전송 간격 관리 (이벤트가 없으면 `event_stream_keep_alive` 마다 주석 전송)
+struct EventStreamTimer {
+    keep_alive_interval : Duration,
+    poll_interval : Duration,
+    /// 마지막으로 전송한 시각
+    last_write : Instant
+}
+
+
+impl EventStreamTimer {
+    fn new(config : &ServerConfig) -> EventStreamTimer {
+        return EventStreamTimer {
+            keep_alive_interval: config.event_stream_keep_alive,
+            poll_interval: config.shutdown_poll_interval,
+            last_write: Instant::now()
+        };
+    }
+
+
+    /// 다음 이벤트를 기다릴 시간 (종료 요청 확인을 위해 shutdown_poll_interval 보다 오래 대기하지 않는다)
+    fn wait_time(&self) -> Duration {
+        return self.keep_alive_interval.saturating_sub(self.last_write.elapsed()).min(self.poll_interval);
+    }
+
+
+    /// 이벤트 없이 대기 시간이 끝났을 때 보낼 Keep-Alive 주석 (아직 보낼 때가 아니면 None)
+    fn keep_alive_frame(&self) -> Option<String> {
+        if self.last_write.elapsed() < self.keep_alive_interval {
+            return None;
+        }
+        return Some(String::from(": keep-alive\n\n"));
+    }
+
+
+    /// 전송 시각 기록
+    fn written(&mut self) {
+        self.last_write = Instant::now();
+    }
+}
+
+
+/// 클라이언트가 접속을 끊었는지 확인 (스트림 전송 중 클라이언트가 보낸 데이터는 버린다)
+fn is_client_closed(stream : &mut ClientStream) -> bool {
+    if stream.set_nonblocking(true).is_err() {
+        return true;
+    }
+    let mut buffer = [0; 512];
+    let closed : bool = match stream.read(&mut buffer) {
+        Ok(0) => true,
+        Ok(_) => false,
+        Err(error) => error.kind() != std::io::ErrorKind::WouldBlock
+    };
+    let _ = stream.set_nonblocking(false);
+
+    return closed;
+}
+
+
+/// 접속한 Stream 으로 클라이언트 Stream 생성 (HTTPS 설정이 있으면 TCP 접속에 TLS 적용)
+fn open_client_stream(context : &ServerContext, stream : ClientStream) -> Option<ClientStream> {
+    #[cfg(feature = "tls")]
+    let stream : ClientStream = match (&context.tls, stream) {
+        (Some(tls), ClientStream::TCP(tcp_stream)) => {
+            return match tls.accept(tcp_stream) {
+                Ok(stream) => Some(stream),
+                Err(error) => {
+                    // 로그 출력
+                    println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+                    None
+                }
+            };
+        },
+        (_, stream) => stream
+    };
+    #[cfg(not(feature = "tls"))]
+    let _ = context;
+
+    return Some(stream);
+}
+
+
+/// Athena Engine Client 접근 처리 함수
+fn handle_connection(context : &Arc<ServerContext>, mut thread_task_args: ThreadTaskArgs) {
+    let guard : ConnectionGuard = ConnectionGuard::new(context, thread_task_args.client_ip.clone());
+    if let Some(proxy_client_ip) = proxy_client_ip(context, &mut thread_task_args.stream, &guard.client_ip) {
+        thread_task_args.client_ip = proxy_client_ip;
+        serve_connection(context, thread_task_args, guard);
+    }
+}
+
+
+/// 열린 접속 수와 IP 별 접속 수에 포함된 접속 (Drop 시 반환)
+struct ConnectionGuard {
+    context : Arc<ServerContext>,
+    /// 접속한 주소 (IP 별 접속 수 기준)
+    client_ip : String
+}
+
+
+impl ConnectionGuard {
+    fn new(context : &Arc<ServerContext>, client_ip : String) -> ConnectionGuard {
+        return ConnectionGuard {
+            context: Arc::clone(context),
+            client_ip
+        };
+    }
+}
+
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.context.rate_limiter.release_connection(&self.client_ip);
+        self.context.connections.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+
+/// 스트림 접속 수 (`max_stream_connections`) 에 포함된 접속 (Drop 시 반환)
+struct StreamSlot {
+    stream_connections : Arc<AtomicUsize>
+}
+
+
+impl StreamSlot {
+    /// 스트림 접속 하나 예약
+    ///
+    /// # Return
+    /// 스트림 접속 수가 `max_stream_connections` 에 도달했으면 None
+    fn acquire(context : &ServerContext) -> Option<StreamSlot> {
+        let max_stream_connections : usize = context.config.max_stream_connections;
+        context.stream_connections
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| (count < max_stream_connections).then_some(count + 1))
+            .ok()?;
+
+        return Some(StreamSlot { stream_connections: Arc::clone(&context.stream_connections) });
+    }
+}
+
+
+impl Drop for StreamSlot {
+    fn drop(&mut self) {
+        self.stream_connections.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+
+/// PROXY protocol Header 로 클라이언트 주소 확인
+///
+/// # Return
+/// 클라이언트 주소 (PROXY protocol 을 사용하지 않으면 접속한 주소), Header 가 잘못되었으면 None
+fn proxy_client_ip(context : &ServerContext, stream : &mut ClientStream, client_ip : &str) -> Option<String> {
+    if !context.config.proxy_protocol {
+        return Some(String::from(client_ip));
+    }
+
+    // Header 전체를 `header_timeout` 안에 받아야 한다
+    let result = read_proxy_header(&mut DeadlineReader {
+        stream: &mut *stream,
+        deadline: Instant::now() + context.config.header_timeout,
+        read_timeout: context.config.read_timeout
+    });
+    set_read_timeout(stream, context.config.read_timeout);
+
+    return match result {
+        Ok(address) => Some(address.map_or_else(|| String::from(client_ip), |address| address.to_string())),
+        Err(error) => {
+            // 로그 출력
+            println!("{}", log_more_text_writer(format!("PROXY protocol header read failed: {}", error), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", client_ip)));
+            None
+        }
+    };
+}
+
+
+/// 전체 읽기 제한 시각이 있는 Stream (읽기마다 남은 시간으로 Timeout 설정)
+struct DeadlineReader<'a> {
+    stream : &'a mut ClientStream,
+    deadline : Instant,
+    read_timeout : Duration
+}
+
+
+impl Read for DeadlineReader<'_> {
+    fn read(&mut self, buf : &mut [u8]) -> std::io::Result<usize> {
+        let now : Instant = Instant::now();
+        if now >= self.deadline {
+            return Err(std::io::Error::new(std::io::ErrorKind::TimedOut, "timed out"));
+        }
+        set_read_timeout(self.stream, self.read_timeout.min(self.deadline - now));
+        return match self.stream.read(buf) {
+            // 읽기 Timeout 은 OS 에 따라 WouldBlock 으로 반환된다
+            Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => Err(std::io::Error::new(std::io::ErrorKind::TimedOut, "timed out")),
+            result => result
+        };
+    }
+}
+
+
+/// 접속 하나의 모든 요청 처리
+///
+/// Upgrade 된 접속은 전용 Thread 로 넘기고 작업 Thread 는 바로 다음 접속을 처리한다.
+fn serve_connection(context : &Arc<ServerContext>, thread_task_args: ThreadTaskArgs, guard : ConnectionGuard) {
+    // 클라이언트 IP 주소
+    let client_ip : String = thread_task_args.client_ip;
+    // 클라이언트 Stream (HTTPS 리스너의 TCP 접속은 TLS 로 감싼다)
+    let mut stream : ClientStream = match open_client_stream(context, thread_task_args.stream) {
+        Some(stream) => stream,
+        None => return
+    };
+
+    match serve_requests(context, &mut stream, &client_ip, &|mut request, request_ip| run_event_handlers(context, &mut request, request_ip)) {
+        Some((upgrade, slot)) => hand_off_stream(context, stream, upgrade, client_ip, slot, guard),
+        None => stream.shutdown()
+    }
+}
+
+
+/// Upgrade 된 접속을 전용 Thread 에서 처리 (접속이 끝나면 스트림 접속 수와 접속 수 반환)
+///
+/// 스트림 접속 Thread 수는 `max_stream_connections` 로 제한된다.
+fn hand_off_stream(context : &Arc<ServerContext>, stream : ClientStream, upgrade : ConnectionUpgrade, client_ip : String, slot : StreamSlot, guard : ConnectionGuard) {
+    let context : Arc<ServerContext> = Arc::clone(context);
+    let spawn_result = std::thread::Builder::new()
+        .name(String::from("athena-stream"))
+        .spawn(move || {
+            let _slot : StreamSlot = slot;
+            let _guard : ConnectionGuard = guard;
+            serve_upgrade(&context, stream, upgrade, &client_ip, &|mut request, request_ip| run_event_handlers(&context, &mut request, request_ip));
+        });
+    // Thread 생성에 실패하면 접속을 닫는다
+    if let Err(error) = spawn_result {
+        // 오류 로그 작성
+        println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+    }
+}
+
+
+/// Upgrade 된 접속의 WebSocket Handler, HTTP/2 접속, Server-Sent Events 스트림 처리 (접속이 끝날 때까지 현재 Thread 사용)
+///
+/// # Argument
+/// client_ip : 접속한 주소 (PROXY protocol 주소 포함)
+///
+/// event_runner : HTTP/2 Stream 마다 이벤트를 실행할 함수
+fn serve_upgrade(context : &ServerContext, mut stream : ClientStream, upgrade : ConnectionUpgrade, client_ip : &str, event_runner : EventRunner) {
+    match upgrade {
+        ConnectionUpgrade::WEBSOCKET(upgrade) => {
+            let request_ip : String = request_client_ip(&upgrade.request, client_ip);
+            run_websocket_handler(context, stream, *upgrade, &request_ip);
+        },
+        ConnectionUpgrade::HTTP2(upgrade) => {
+            serve_http2(context, &mut stream, upgrade, client_ip, &|request| {
+                let request_ip : String = request_client_ip(&request, client_ip);
+                event_runner(request, &request_ip)
+            });
+            stream.shutdown();
+        },
+        ConnectionUpgrade::EVENT_STREAM(event_stream, request_ip) => {
+            serve_event_stream(context, &mut stream, event_stream, &request_ip);
+            stream.shutdown();
+        }
+    }
+}
+
+
+/// 접속 하나에서 Keep-Alive 가 끝날 때까지 요청 처리
+///
+/// # Return
+/// WebSocket Handshake 에 성공하거나, HTTP/2 로 전환하거나, Server-Sent Events 응답을 보내면 접속을 넘길 Upgrade 요청과 예약한 스트림 접속
+fn serve_requests(context : &ServerContext, stream : &mut ClientStream, client_ip : &str, event_runner : EventRunner) -> Option<(ConnectionUpgrade, StreamSlot)> {
+    let mut connection : Http1Connection = Http1Connection::new(context, String::from(client_ip), stream.is_secure());
+
+    loop {
+        let reply : Reply = match read_request(context, &mut connection, stream) {
+            Ok(raw_request) => match connection.on_request(context, raw_request) {
+                RequestWork::EVENT(request, pending) => {
+                    // Request / Response 이벤트 실행
+                    let response : Option<Response> = event_runner(request, &pending.request_ip);
+                    pending.reply(context, response)
+                },
+                RequestWork::WEBSOCKET(mut request, request_ip) => {
+                    let handshake : Result<String, String> = websocket_handshake(context, &mut request, &request_ip);
+                    connection.websocket_reply(request, handshake)
+                },
+                RequestWork::REPLY(reply) => reply
+            },
+            Err(error) => connection.read_error_reply(context, error)
+        };
+        let (reply, slot) : (Reply, Option<StreamSlot>) = reply.reserve_stream(context, client_ip);
+
+        // 응답 전송
+        if let Some(text) = &reply.text {
+            if let Err(error) = stream.write_all(text.as_bytes()).and_then(|_| stream.flush()) {
+                // 로그 출력
+                println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+                return None;
+            }
+        }
+        match reply.next {
+            ReplyNext::KEEP_ALIVE => {},
+            ReplyNext::CLOSE => return None,
+            ReplyNext::UPGRADE(upgrade) => return slot.map(|slot| (upgrade, slot))
+        }
+    }
+}
+
+
+/// Stream 에서 요청 하나를 읽기 (Keep-Alive 접속은 다음 요청의 데이터가 도착할 때까지 대기)
+fn read_request(context : &ServerContext, connection : &mut Http1Connection, stream : &mut ClientStream) -> Result<RawRequest, RequestReadError> {
+    let mut chunk = [0; READ_CHUNK_SIZE];
+
+    loop {
+        match connection.read_step(context, Instant::now())? {
+            ReadStep::REQUEST(raw_request) => return Ok(raw_request),
+            ReadStep::SEND(interim) => {
+                if let Err(error) = stream.write_all(interim.as_bytes()).and_then(|_| stream.flush()) {
+                    return Err(RequestReadError::IO(error));
+                }
+            },
+            ReadStep::READ(timeout) => {
+                set_read_timeout(stream, timeout);
+                let result = stream.read(&mut chunk);
+                connection.on_read(result.map(|size| &chunk[..size]))?;
+            }
+        }
+    }
+}
+
+
+/// 요청을 읽는 동안 Stream 에서 할 작업
+enum ReadStep {
+    /// 요청 하나를 모두 읽음
+    REQUEST(RawRequest),
+    /// 전송 후 계속 읽을 중간 응답 (`100 Continue`)
+    SEND(String),
+    /// 주어진 시간 안에 데이터를 읽어 `Http1Connection::on_read` 로 전달
+    READ(Duration)
+}
+
+
+/// 읽은 요청의 처리 방법
+enum RequestWork {
+    /// 이벤트를 실행하고 결과를 `PendingResponse::reply` 로 응답 생성
+    EVENT(Request, PendingResponse),
+    /// WebSocket 경로 요청 (`websocket_handshake` 결과를 `Http1Connection::websocket_reply` 로 응답 생성)
+    WEBSOCKET(Request, String),
+    /// 이벤트 없이 바로 보낼 응답
+    REPLY(Reply)
+}
+
+
+/// 이벤트 실행 결과로 응답을 만들 때 필요한 요청 정보
+struct PendingResponse {
+    /// 요청의 클라이언트 주소
+    request_ip : String,
+    request_method : Option<Method>,
+    /// 응답 후 접속 유지 여부
+    keep_alive : bool
+}
+
+
+/// 전송할 응답과 전송 후 접속 처리
+struct Reply {
+    /// 전송할 응답 (None 이면 전송하지 않음)
+    text : Option<String>,
+    next : ReplyNext
+}
+
+
+/// 응답 전송 후 접속 처리
+enum ReplyNext {
+    /// 다음 요청 대기
+    KEEP_ALIVE,
+    /// 접속 종료
+    CLOSE,
+    /// 다른 Protocol 로 접속 전환
+    UPGRADE(ConnectionUpgrade)
+}
+
+
+/// HTTP/1.1 접속 하나의 요청 처리 상태
+///
+/// 동기 서버와 `start_async` 가 같은 순서로 요청을 처리하도록 Stream 읽기/쓰기를 제외한 판단을 모두 담당한다.
+struct Http1Connection {
+    reader : RequestReader,
+    /// 접속한 주소 (PROXY protocol 주소 포함)
+    client_ip : String,
+    /// TLS 접속 여부
+    secure : bool,
+    /// 현재 접속에서 처리한 요청 수
+    served_requests : usize,
+    /// 접속 시작 시각 (`max_keep_alive_duration` 기준)
+    opened : Instant,
+    /// Keep-Alive 접속의 다음 요청 대기 제한 시각 (대기 중이 아니면 None)
+    idle_deadline : Option<Instant>
+}
+
+
+impl Http1Connection {
+    fn new(context : &ServerContext, client_ip : String, secure : bool) -> Http1Connection {
+        return Http1Connection {
+            reader: new_request_reader(&context.config),
+            client_ip,
+            secure,
+            served_requests: 0,
+            opened: Instant::now(),
+            idle_deadline: None
+        };
+    }
+
+
+    /// Stream 에서 할 다음 작업
+    ///
+    /// `read` 호출마다 `read_timeout` 을 적용하고, Header 수신 제한 시간과 최소 Body 전송 속도를 넘기면
+    /// `RequestReadError::REQUEST_TIMEOUT` 을 반환한다.
+    /// Keep-Alive 대기 시간이 끝나거나 대기 중 서버 종료 요청이 있으면 `RequestReadError::CONNECTION_CLOSED` 를 반환한다.
+    fn read_step(&mut self, context : &ServerContext, now : Instant) -> Result<ReadStep, RequestReadError> {
+        // Keep-Alive 접속의 다음 요청 대기
+        if self.served_requests > 0 && self.reader.is_idle() {
+            // 서버 종료 요청시 대기 중인 접속 종료
+            if context.state.is_shutdown() {
+                return Err(RequestReadError::CONNECTION_CLOSED);
+            }
+            // 대기 시간은 접속 유지 최대 시간을 넘지 않는다
+            let deadline : Instant = *self.idle_deadline.get_or_insert((now + context.config.idle_timeout).min(self.opened + context.config.max_keep_alive_duration));
+            if now >= deadline {
+                // 로그 출력
+                println!("{}", log_more_text_writer(String::from("Keep-alive connection timed out."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", self.client_ip)));
+                return Err(RequestReadError::CONNECTION_CLOSED);
+            }
+            // 종료 요청을 확인할 수 있도록 짧은 시간 단위로 대기
+            return Ok(ReadStep::READ(context.config.shutdown_poll_interval.min(deadline - now)));
+        }
+        self.idle_deadline = None;
+
+        if let Some(raw_request) = self.reader.poll_request()? {
+            return Ok(ReadStep::REQUEST(raw_request));
+        }
+        // Expect: 100-continue 요청은 Body 를 받기 전에 응답
+        if let Some(interim) = check_expect_continue(context, &mut self.reader, &self.client_ip)? {
+            return Ok(ReadStep::SEND(interim));
+        }
+
+        // 남은 제한 시간만큼만 대기
+        let timeout : Duration = match self.reader.time_left(now)? {
+            Some(time_left) => time_left.min(context.config.read_timeout),
+            None => context.config.read_timeout
+        };
+        return Ok(ReadStep::READ(timeout.max(Duration::from_millis(1))));
+    }
+
+
+    /// Stream 읽기 결과 반영
+    ///
+    /// # Argument
+    /// result : 읽은 데이터 (빈 데이터는 접속 종료)
+    fn on_read(&mut self, result : std::io::Result<&[u8]>) -> Result<(), RequestReadError> {
+        // Keep-Alive 대기 중 여부
+        let waiting : bool = self.idle_deadline.is_some();
+
+        return match result {
+            Ok([]) => Err(self.reader.end_of_stream_error()),
+            Ok(data) => {
+                self.reader.feed(data);
+                Ok(())
+            },
+            Err(error) if error.kind() == std::io::ErrorKind::Interrupted => Ok(()),
+            // 대기 중에는 다시 종료 요청과 대기 제한 시각 확인
+            Err(error) if waiting && matches!(error.kind(), std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut) => Ok(()),
+            Err(_) if waiting => Err(RequestReadError::CONNECTION_CLOSED),
+            Err(error) => Err(self.reader.read_error(error))
+        };
+    }
+
+
+    /// 요청 읽기 실패시 응답 (보낼 상태 코드 응답이 있으면 전송 후 접속 종료)
+    fn read_error_reply(&self, context : &ServerContext, error : RequestReadError) -> Reply {
+        if let RequestReadError::CONNECTION_CLOSED = error {
+            return Reply { text: None, next: ReplyNext::CLOSE };
+        }
+        // 로그 출력
+        println!("{}", log_more_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", self.client_ip)));
+
+        return Reply {
+            text: error.http_state_code().map(|http_code| state_response_text(&context.config, http_code)),
+            next: ReplyNext::CLOSE
+        };
+    }
+
+
+    /// 읽은 요청의 처리 방법 결정 (HTTP/2 전환, 요청 수 제한, WebSocket 경로 확인)
+    fn on_request(&mut self, context : &ServerContext, raw_request : RawRequest) -> RequestWork {
+        self.served_requests += 1;
+        // HTTP/2 전환 (요청 수 제한은 Stream 마다 확인)
+        if let Some(mut upgrade) = http2_upgrade(&raw_request, self.served_requests, self.secure) {
+            let text : Option<String> = switching_http2_text(&upgrade, &self.client_ip).map(String::from);
+            upgrade.buffered = self.reader.take_buffered_data();
+            return RequestWork::REPLY(Reply { text, next: ReplyNext::UPGRADE(ConnectionUpgrade::HTTP2(upgrade)) });
+        }
+        // Request 패킷 분석
+        let mut request : Request = raw_request.to_request();
+        attach_server_data(context, &mut request, &self.client_ip);
+        // 신뢰할 수 있는 Proxy 가 전달한 클라이언트 주소
+        let request_ip : String = request_client_ip(&request, &self.client_ip);
+        // 클라이언트 IP 별 요청 수 확인 (Proxy 뒤의 클라이언트도 구분)
+        if let Err(retry_after) = context.rate_limiter.check_request(&request_ip) {
+            // 로그 출력
+            println!("{}", log_more_text_writer(String::from("Request rejected, rate limit exceeded."), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", request_ip)));
+
+            return RequestWork::REPLY(Reply { text: Some(too_many_requests_text(&context.config, retry_after)), next: ReplyNext::CLOSE });
+        }
+        // WebSocket 경로 요청은 Handshake 후 Handler 에 접속을 넘긴다
+        if is_websocket_request(context, &request) {
+            return RequestWork::WEBSOCKET(request, request_ip);
+        }
+
+        // 응답 후 접속 유지 여부
+        let keep_alive : bool = request.is_keep_alive()
+            && self.served_requests < context.config.max_keep_alive_requests
+            && self.opened.elapsed() < context.config.max_keep_alive_duration;
+        let pending : PendingResponse = PendingResponse {
+            request_ip,
+            request_method: request.method,
+            keep_alive
+        };
+        return RequestWork::EVENT(request, pending);
+    }
+
+
+    /// WebSocket Handshake 결과로 응답 생성 (성공하면 접속을 Handler 에 넘긴다)
+    fn websocket_reply(&mut self, request : Request, handshake : Result<String, String>) -> Reply {
+        return match handshake {
+            Ok(text) => Reply {
+                text: Some(text),
+                next: ReplyNext::UPGRADE(ConnectionUpgrade::WEBSOCKET(Box::new(WebSocketUpgrade { request, buffered: self.reader.take_buffered_data() })))
+            },
+            Err(text) => Reply { text: Some(text), next: ReplyNext::CLOSE }
+        };
+    }
+}
+
+
+impl Reply {
+    /// 접속을 넘기는 응답이면 전송 전에 스트림 접속 예약
+    ///
+    /// # Return
+    /// 보낼 응답과 예약한 스트림 접속 (Upgrade 응답이 아니면 None),
+    /// 스트림 접속 수가 `max_stream_connections` 에 도달했으면 `503 Service Unavailable` 응답 후 접속 종료
+    fn reserve_stream(self, context : &ServerContext, client_ip : &str) -> (Reply, Option<StreamSlot>) {
+        if !matches!(self.next, ReplyNext::UPGRADE(_)) {
+            return (self, None);
+        }
+        if let Some(slot) = StreamSlot::acquire(context) {
+            return (self, Some(slot));
+        }
+        // 로그 출력
+        println!("{}", log_more_text_writer(String::from("Stream rejected, too many open stream connections."), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", client_ip)));
+
+        // HTTP/2 Prior Knowledge 접속은 HTTP/1.1 응답을 읽을 수 없으므로 응답 없이 종료
+        let text : Option<String> = self.text.map(|_| state_response_text(&context.config, HttpStateCode::HTTP_503));
+        return (Reply { text, next: ReplyNext::CLOSE }, None);
+    }
+}
+
+
+impl PendingResponse {
+    /// 이벤트 실행 결과로 응답 생성
+    ///
+    /// # Argument
+    /// response : 이벤트 실행 결과 (None 이면 응답 없이 접속 종료)
+    fn reply(self, context : &ServerContext, response : Option<Response>) -> Reply {
+        let mut response : Response = match response {
+            Some(response) => response,
+            None => return Reply { text: None, next: ReplyNext::CLOSE }
+        };
+        // Server-Sent Events 응답은 Header 전송 후 이벤트 스트림 전송
+        if let Some(event_stream) = response.event_stream.take() {
+            if !finalize_event_stream_response(&mut response) {
+                return Reply { text: None, next: ReplyNext::CLOSE };
+            }
+            return Reply {
+                text: Some(response_parser(response)),
+                next: ReplyNext::UPGRADE(ConnectionUpgrade::EVENT_STREAM(event_stream, self.request_ip))
+            };
+        }
+
+        // 요청 처리 중 서버 종료 요청이 있으면 응답 후 접속 종료
+        let keep_alive : bool = finalize_response(context, &mut response, self.keep_alive && !context.state.is_shutdown());
+        strip_head_body(self.request_method, &mut response);
+        return Reply {
+            text: Some(response_parser(response)),
+            next: if keep_alive { ReplyNext::KEEP_ALIVE } else { ReplyNext::CLOSE }
+        };
+    }
+}
+
+
+/// WebSocket 경로 요청 여부
+fn is_websocket_request(context : &ServerContext, request : &Request) -> bool {
+    return request.path().is_some_and(|path| context.websocket_handlers.contains_key(path));
+}
+
+
+/// WebSocket Upgrade 요청 확인
+///
+/// Handshake 전에 요청을 Host 에 맞는 Middleware 에 통과시킨다 (`websocket_middleware_response`).
+///
+/// # Return
+/// Handshake 에 성공하면 Ok(`101 Switching Protocols` 응답),
+/// 실패하면 Err(`426 Upgrade Required` 또는 Middleware 응답)
+fn websocket_handshake(context : &ServerContext, request : &mut Request, client_ip : &str) -> Result<String, String> {
+    if let Some(mut response) = websocket_middleware_response(context, request, client_ip) {
+        // 로그 출력
+        println!("{}", log_more_text_writer(String::from("WebSocket upgrade answered by middleware."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+
+        finalize_response(context, &mut response, false);
+        return Err(response_parser(response));
+    }
+
+    return match websocket_accept(request) {
+        Some(accept) => Ok(switching_protocols_text(&accept)),
+        None => {
+            // 로그 출력
+            println!("{}", log_more_text_writer(String::from("WebSocket upgrade failed, invalid handshake request."), get_this_name(), LogTypeTag::WARNING, format!("IP:{}", client_ip)));
+            Err(upgrade_required_text(&context.config))
+        }
+    };
+}
+
+
+/// WebSocket Upgrade 요청을 Host 에 맞는 Middleware 에 통과시키기
+///
+/// 모든 Middleware 가 `next` 를 호출하면 Handshake 를 진행하며, Middleware 가 바꾼 요청이 WebSocket Handler 에 전달된다.
+/// `next` 가 반환한 응답을 Middleware 가 수정해도 Handshake 응답에는 적용되지 않는다.
+///
+/// # Return
+/// Handshake 를 진행하면 None, Middleware 나 알 수 없는 Host 의 Handler 가 응답하면 Some(응답)
+fn websocket_middleware_response(context : &ServerContext, request : &mut Request, client_ip : &str) -> Option<Response> {
+    let reached : Cell<bool> = Cell::new(false);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let event : &ClientEvent = match context.site(request) {
+            Site::VIRTUAL_HOST(host) => &host.event,
+            Site::UNKNOWN_HOST(handler) => return handler(request),
+            Site::SERVER => &context.event
+        };
+        run_middlewares(&event.middlewares, request, &|_request| {
+            reached.set(true);
+            state_response_writer(HttpStateCode::HTTP_101, &context.config)
+        })
+    }));
+
+    return match result {
+        Ok(_) if reached.get() => None,
+        Ok(response) => Some(response),
+        Err(payload) => Some(panic_response(context, payload.as_ref(), client_ip))
+    };
+}
+
+
+/// `Upgrade: h2c` 요청이면 보낼 `101 Switching Protocols` 응답 (Prior Knowledge 접속이면 None)
+fn switching_http2_text(upgrade : &Http2Upgrade, client_ip : &str) -> Option<&'static str> {
+    let response : &'static str = crate::server::http2::http2::switching_protocols_text(upgrade)?;
+    // 로그 출력
+    println!("{}", log_more_text_writer(String::from("Upgrade to HTTP/2 (h2c)."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+
+    return Some(response);
+}
+
+
+/// Upgrade 된 접속으로 WebSocket Handler 실행 (Handler 가 끝나면 접속 종료)
+fn run_websocket_handler(context : &ServerContext, stream : ClientStream, upgrade : WebSocketUpgrade, client_ip : &str) {
+    let handler : &WebSocketHandler = match upgrade.request.path().and_then(|path| context.websocket_handlers.get(path)) {
+        Some(handler) => handler,
+        None => return
+    };
+
+    // 로그 출력
+    println!("{}", log_more_text_writer(String::from("Run WebSocket handler."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+
+    let websocket : WebSocket = WebSocket::new(stream, upgrade.buffered, &context.config, Arc::clone(&context.state));
+    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| handler(&upgrade.request, websocket))) {
+        log_handler_panic(payload.as_ref(), client_ip);
+    }
+
+    // 로그 출력
+    println!("{}", log_more_text_writer(String::from("WebSocket connection closed."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+}
+
+
+/// 요청에 서버 설정, Host 에 맞는 페이지 리스트, 클라이언트 주소 연결
+///
+/// # Argument
+/// client_ip : 접속한 주소 (PROXY protocol 주소 포함)
+fn attach_server_data(context : &ServerContext, request : &mut Request, client_ip : &str) {
+    request.client_ip = Some(forwarded_client_ip(&context.config.trusted_proxies, context.config.forwarded_header, client_ip, request));
+    request.pages = Some(match context.site(request) {
+        Site::VIRTUAL_HOST(host) => Arc::clone(&host.pages),
+        _ => Arc::clone(&context.pages)
+    });
+    request.config = Some(Arc::clone(&context.config));
+    request.path_params = context.client_event(request)
+        .and_then(|event| find_route(event, request))
+        .map(|route| route.params);
+}
+
+
+/// 요청 경로와 Method 에 맞는 Route
+fn find_route<'a>(event : &'a ClientEvent, request : &Request) -> Option<RouteMatch<'a>> {
+    let method : Method = request.method.unwrap_or(Method::NOT_SUPPORTED);
+    return event.router.as_ref()?.find(request.path()?, method);
+}
+
+
+/// 요청의 클라이언트 주소 (서버가 설정하지 않았으면 접속한 주소)
+fn request_client_ip(request : &Request, client_ip : &str) -> String {
+    return request.client_ip.clone().unwrap_or_else(|| String::from(client_ip));
+}
+
+
+/// Request 이벤트 실행
+fn run_request_event(context : &ServerContext, request : &Request, client_ip : &str) {
+    let event : &ClientEvent = match context.client_event(request) {
+        Some(event) => event,
+        None => return
+    };
+    match &event.event_request {
+        Some(handler) => {
+            // 로그 출력
+            println!("{}", log_more_text_writer(String::from("Run request EVENT handler."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+
+            // 이벤트 실행
+            handler(request);
+        },
+        None => {
+            // 로그 출력
+            println!("{}", log_more_text_writer(String::from("Request EVENT handler failed, no registered EVENT."), get_this_name(), LogTypeTag::INFO, format!("IP: {}", client_ip)));
+        }
+    }
+}
+
+
+/// Response 이벤트 실행 (Middleware 를 거쳐 Route 또는 Response 이벤트 실행)
+///
+/// # Return
+/// 이벤트 실행 결과, 등록된 이벤트가 없으면 None (Middleware 가 있으면 `404 Not Found`)
+fn run_response_event(context : &ServerContext, request : &mut Request, client_ip : &str) -> Option<Response> {
+    let event : &ClientEvent = match context.site(request) {
+        Site::VIRTUAL_HOST(host) => &host.event,
+        Site::UNKNOWN_HOST(handler) => {
+            // 로그 출력
+            println!("{}", log_more_text_writer(format!("Unknown host: {}", request.host.as_deref().unwrap_or("-")), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+
+            return Some(handler(request));
+        },
+        Site::SERVER => &context.event
+    };
+    if event.middlewares.is_empty() {
+        return respond_event(context, event, request, client_ip);
+    }
+
+    return Some(run_middlewares(&event.middlewares, request, &|request| {
+        respond_event(context, event, request, client_ip).unwrap_or_else(|| state_response_writer(HttpStateCode::HTTP_404, &context.config))
+    }));
+}
+
+
+/// 경로 패턴이 일치하는 Route 또는 Response 이벤트 실행
+pub(in crate::server) fn respond_event(context : &ServerContext, event : &ClientEvent, request : &mut Request, client_ip : &str) -> Option<Response> {
+    // Middleware 가 요청 경로를 바꿀 수 있으므로 다시 찾는다
+    if let Some(mut route) = find_route(event, request) {
+        // 로그 출력
+        println!("{}", log_more_text_writer(String::from("Run route handler."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+
+        request.path_params = Some(std::mem::take(&mut route.params));
+        return Some(route.respond(request));
+    }
+    return match &event.event_response {
+        Some(handler) => {
+            // 로그 출력
+            println!("{}", log_more_text_writer(String::from("Run response EVENT handler."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+
+            // 이벤트 실행 결과
+            Some(handler(request))
+        },
+        // Router 만 등록했으면 일치하는 Route 가 없는 경로는 404 응답
+        None if event.router.is_some() => Some(state_response_writer(HttpStateCode::HTTP_404, &context.config)),
+        None => {
+            // 로그 출력
+            println!("{}", log_more_text_writer(String::from("Response EVENT handler failed, no registered EVENT."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+            None
+        }
+    };
+}
+
+
+/// Request 이벤트와 Response 이벤트를 차례로 실행
+///
+/// 이벤트 Handler 에서 Panic 이 발생하면 `500 Internal Server Error` 응답을 반환한다.
+fn run_event_handlers(context : &ServerContext, request : &mut Request, client_ip : &str) -> Option<Response> {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        run_request_event(context, request, client_ip);
+        run_response_event(context, request, client_ip)
+    }));
+
+    return match result {
+        Ok(response) => response,
+        Err(payload) => Some(panic_response(context, payload.as_ref(), client_ip))
+    };
+}
+
+
+/// 이벤트 Handler 의 Panic 기록 후 `500 Internal Server Error` 응답 생성
+fn panic_response(context : &ServerContext, payload : &(dyn Any + Send), client_ip : &str) -> Response {
+    log_handler_panic(payload, client_ip);
+    return state_response_writer(HttpStateCode::HTTP_500, &context.config);
+}
+
+
+/// 이벤트 Handler 의 Panic 메시지 로그 출력
+fn log_handler_panic(payload : &(dyn Any + Send), client_ip : &str) {
+    let message : &str = match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => match payload.downcast_ref::<String>() {
+            Some(message) => message.as_str(),
+            None => "unknown panic payload"
+        }
+    };
+
+    // 로그 출력
+    println!("{}", log_more_text_writer(format!("EVENT handler panicked: {}", message), get_this_name(), LogTypeTag::FATAL, format!("IP:{}", client_ip)));
+}
+
+
+/// 서버 설정으로 HTTP 요청 Reader 생성
+fn new_request_reader(config : &ServerConfig) -> RequestReader {
+    return RequestReader::new(config.max_header_bytes, config.max_body_bytes)
+        .with_deadlines(Some(config.header_timeout), config.min_body_bytes_per_second);
+}
+
+
+/// `Expect: 100-continue` 요청의 Body 를 받을지 결정
+///
+/// # Return
+/// 보낼 `100 Continue` 응답 (해당 요청이 아니거나 클라이언트가 이미 Body 를 보낸 경우 None),
+/// Handler 가 거부하면 `RequestReadError::REJECTED`
+fn check_expect_continue(context : &ServerContext, reader : &mut RequestReader, client_ip : &str) -> Result<Option<String>, RequestReadError> {
+    let raw_request : RawRequest = match reader.take_expect_continue() {
+        Some(raw_request) => raw_request,
+        None => return Ok(None)
+    };
+
+    if let Some(handler) = &context.continue_handler {
+        let mut request : Request = raw_request.to_request();
+        attach_server_data(context, &mut request, client_ip);
+        match panic::catch_unwind(AssertUnwindSafe(|| handler(&request))) {
+            Ok(Ok(())) => {},
+            Ok(Err(http_code)) => return Err(RequestReadError::REJECTED(http_code)),
+            Err(payload) => {
+                log_handler_panic(payload.as_ref(), client_ip);
+                return Err(RequestReadError::REJECTED(HttpStateCode::HTTP_500));
+            }
+        }
+    }
+    // Body 를 이미 보내기 시작한 클라이언트에게는 보내지 않는다
+    if reader.has_buffered_data() {
+        return Ok(None);
+    }
+
+    // 로그 출력
+    println!("{}", log_more_text_writer(String::from("Send 100 Continue."), get_this_name(), LogTypeTag::INFO, format!("IP:{}", client_ip)));
+
+    return Ok(Some(format!("HTTP/1.1 {}\r\n\r\n", default_http_state_writer(&HttpStateCode::HTTP_100))));
+}
+
+
+/// Stream 읽기 Timeout 설정
+fn set_read_timeout(stream : &ClientStream, timeout : Duration) {
+    if let Err(error) = stream.set_read_timeout(Some(timeout)) {
+        // 오류 로그 작성
+        println!("{}", log_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING));
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpStream;
+    use std::sync::Mutex;
+    use crate::server::event_stream::event_stream::{event_stream_response, Event, EventSender};
+    use crate::server::test_client::test_client::{read_response, TestRequest, TestResponse};
+
+
+    /// 고정 Body 응답
+    fn text(body : &str) -> Response {
+        let mut response : Response = state_response_writer(HttpStateCode::HTTP_200, &ServerConfig::default());
+        response.body = Some(ResponseBody { body_str: Some(String::from(body)) });
+        return response;
+    }
+
+
+    /// 요청 하나를 보내고 응답 Header 까지 읽기
+    fn request(address : std::net::SocketAddr, request : TestRequest) -> (TcpStream, TestResponse) {
+        let mut client : TcpStream = TcpStream::connect(address).unwrap();
+        client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        client.write_all(&request.to_bytes()).unwrap();
+        let response : TestResponse = read_response(&mut client);
+        return (client, response);
+    }
+
+
+    #[test]
+    fn serves_event_streams_off_the_worker_pool_up_to_the_stream_limit() {
+        let senders : Arc<Mutex<Vec<EventSender>>> = Arc::new(Mutex::new(Vec::new()));
+        let handler_senders : Arc<Mutex<Vec<EventSender>>> = Arc::clone(&senders);
+        let handle : ServerHandle = Server::builder()
+            .bind(String::from("127.0.0.1"), 0)
+            .worker_count(1)
+            .max_stream_connections(1)
+            .event_response(move |request| {
+                if request.path() != Some("/events") {
+                    return text("ok");
+                }
+                let (response, sender) = event_stream_response(request);
+                handler_senders.lock().unwrap().push(sender);
+                response
+            })
+            .build()
+            .start()
+            .unwrap();
+        let address : std::net::SocketAddr = handle.local_addr();
+
+        let (stream, response) = request(address, TestRequest::get("/events"));
+        response.assert_status(HttpStateCode::HTTP_200).assert_header("Content-Type", "text/event-stream; charset=UTF-8");
+        // 스트림이 열려 있어도 하나뿐인 작업 Thread 는 다음 접속을 처리한다
+        request(address, TestRequest::get("/").header("Connection", "close")).1.assert_body("ok");
+        request(address, TestRequest::get("/events")).1.assert_status(HttpStateCode::HTTP_503);
+
+        // 클라이언트가 접속을 끊으면 스트림 접속 수 반환
+        drop(stream);
+        let deadline : Instant = Instant::now() + Duration::from_secs(5);
+        let (_stream, response) = loop {
+            let (stream, response) = request(address, TestRequest::get("/events"));
+            if response.status == 200 || Instant::now() >= deadline {
+                break (stream, response);
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        };
+        response.assert_status(HttpStateCode::HTTP_200);
+
+        // 열린 스트림은 서버 종료시 닫힌다
+        assert!(handle.shutdown_with_timeout(Duration::from_secs(5)));
+        assert!(senders.lock().unwrap().iter().all(EventSender::is_closed));
+    }
+
+
+    /// 접속이 닫혔는지 확인 (서버가 끊으면 0 Byte 읽기)
+    fn assert_closed(stream : &mut TcpStream) {
+        let mut buffer : [u8; 16] = [0; 16];
+        assert_eq!(stream.read(&mut buffer).unwrap(), 0);
+    }
+
+
+    #[test]
+    fn closes_keep_alive_connections_after_the_request_limit() {
+        let handle : ServerHandle = Server::builder()
+            .bind(String::from("127.0.0.1"), 0)
+            .max_keep_alive_requests(3)
+            .event_response(|_| text("ok"))
+            .build()
+            .start()
+            .unwrap();
+
+        // 같은 접속으로 요청 3개 처리, 마지막 응답 후 접속 종료
+        let (mut stream, response) = request(handle.local_addr(), TestRequest::get("/"));
+        response.assert_header("Connection", "keep-alive").assert_header("Keep-Alive", "timeout=5, max=3");
+        stream.write_all(&TestRequest::get("/").to_bytes()).unwrap();
+        read_response(&mut stream).assert_header("Connection", "keep-alive").assert_body("ok");
+        stream.write_all(&TestRequest::get("/").to_bytes()).unwrap();
+        read_response(&mut stream).assert_header("Connection", "close").assert_body("ok");
+        assert_closed(&mut stream);
+
+        // 클라이언트가 요청한 경우 바로 종료
+        let (mut stream, response) = request(handle.local_addr(), TestRequest::get("/").header("Connection", "close"));
+        response.assert_header("Connection", "close");
+        assert_closed(&mut stream);
+
+        assert!(handle.shutdown_with_timeout(Duration::from_secs(5)));
+    }
+
+
+    #[test]
+    fn closes_keep_alive_connections_after_the_lifetime_limit() {
+        let handle : ServerHandle = Server::builder()
+            .bind(String::from("127.0.0.1"), 0)
+            .keep_alive_timeout(Duration::from_secs(5))
+            .max_keep_alive_duration(Duration::from_millis(300))
+            .event_response(|_| text("ok"))
+            .build()
+            .start()
+            .unwrap();
+
+        // 대기 중인 접속은 대기 시간이 남아 있어도 최대 시간에 종료
+        let started : Instant = Instant::now();
+        let (mut stream, response) = request(handle.local_addr(), TestRequest::get("/"));
+        response.assert_header("Connection", "keep-alive");
+        assert_closed(&mut stream);
+        assert!(started.elapsed() < Duration::from_secs(3));
+
+        // 최대 시간이 지난 뒤의 요청은 응답 후 종료
+        let mut stream : TcpStream = TcpStream::connect(handle.local_addr()).unwrap();
+        stream.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        std::thread::sleep(Duration::from_millis(400));
+        stream.write_all(&TestRequest::get("/").to_bytes()).unwrap();
+        read_response(&mut stream).assert_header("Connection", "close").assert_body("ok");
+        assert_closed(&mut stream);
+
+        assert!(handle.shutdown_with_timeout(Duration::from_secs(5)));
+    }
+
+
+    /// 요청을 받으면 알리고 `release` 신호가 올 때까지 응답을 보류하는 서버
+    fn blocking_server() -> (ServerHandle, std::sync::mpsc::Receiver<()>, std::sync::mpsc::Sender<()>) {
+        let (entered_sender, entered) = std::sync::mpsc::channel::<()>();
+        let (release, release_receiver) = std::sync::mpsc::channel::<()>();
+        let entered_sender : Mutex<std::sync::mpsc::Sender<()>> = Mutex::new(entered_sender);
+        let release_receiver : Mutex<std::sync::mpsc::Receiver<()>> = Mutex::new(release_receiver);
+        let handle : ServerHandle = Server::builder()
+            .bind(String::from("127.0.0.1"), 0)
+            .event_response(move |_| {
+                entered_sender.lock().unwrap().send(()).unwrap();
+                release_receiver.lock().unwrap().recv().unwrap();
+                text("done")
+            })
+            .build()
+            .start()
+            .unwrap();
+        return (handle, entered, release);
+    }
+
+
+    #[test]
+    fn shutdown_waits_for_in_flight_requests() {
+        let (handle, entered, release) = blocking_server();
+        let address : std::net::SocketAddr = handle.local_addr();
+        let client = std::thread::spawn(move || request(address, TestRequest::get("/")));
+        entered.recv_timeout(Duration::from_secs(5)).unwrap();
+
+        let shutdown_handle : ServerHandle = handle.clone();
+        let shutdown = std::thread::spawn(move || shutdown_handle.shutdown_with_timeout(Duration::from_secs(5)));
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(handle.is_running());
+
+        // 처리 중인 요청은 끝까지 응답하고 접속을 닫는다
+        release.send(()).unwrap();
+        let (mut stream, response) = client.join().unwrap();
+        response.assert_body("done").assert_header("Connection", "close");
+        assert_closed(&mut stream);
+        assert!(shutdown.join().unwrap());
+        assert!(!handle.is_running());
+    }
+
+
+    #[test]
+    fn shutdown_closes_idle_keep_alive_connections() {
+        let handle : ServerHandle = Server::builder()
+            .bind(String::from("127.0.0.1"), 0)
+            .keep_alive_timeout(Duration::from_secs(30))
+            .event_response(|_| text("ok"))
+            .build()
+            .start()
+            .unwrap();
+        let (mut stream, response) = request(handle.local_addr(), TestRequest::get("/"));
+        response.assert_header("Connection", "keep-alive");
+
+        // 대기 중인 접속은 Keep-Alive 대기 시간을 기다리지 않고 종료
+        let started : Instant = Instant::now();
+        assert!(handle.shutdown_with_timeout(Duration::from_secs(5)));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_closed(&mut stream);
+    }
+
+
+    #[test]
+    fn shutdown_with_timeout_returns_false_while_requests_are_running() {
+        let (handle, entered, release) = blocking_server();
+        let address : std::net::SocketAddr = handle.local_addr();
+        let client = std::thread::spawn(move || request(address, TestRequest::get("/")));
+        entered.recv_timeout(Duration::from_secs(5)).unwrap();
+
+        // 제한 시간이 지나도 남은 요청은 계속 실행된다
+        assert!(!handle.shutdown_with_timeout(Duration::from_millis(200)));
+        assert!(handle.is_running());
+
+        release.send(()).unwrap();
+        client.join().unwrap().1.assert_body("done");
+        handle.wait();
+        assert!(!handle.is_running());
+    }
+
+
+    #[test]
+    #[cfg(unix)]
+    fn serves_unix_socket_and_dual_stack_listeners() {
+        let socket_path : std::path::PathBuf = std::env::temp_dir().join(format!("athena-test-{}.sock", std::process::id()));
+        let handle : ServerHandle = Server::builder()
+            .bind_dual_stack(0)
+            .bind_unix(socket_path.to_string_lossy().into_owned())
+            .event_response(|request| text(request.client_ip.as_deref().unwrap_or("")))
+            .build()
+            .start()
+            .unwrap();
+        let port : u16 = handle.local_addr().port();
+        assert_eq!(handle.local_addrs(), vec![
+            ListenAddress::TCP(std::net::SocketAddr::new(std::net::IpAddr::V6(std::net::Ipv6Addr::UNSPECIFIED), port)),
+            ListenAddress::UNIX(socket_path.clone())
+        ]);
+
+        // 하나의 Socket 으로 IPv4 와 IPv6 접속 처리 (IPv4 접속은 IPv4 주소로 기록)
+        request(std::net::SocketAddr::from(([127, 0, 0, 1], port)), TestRequest::get("/")).1.assert_body("127.0.0.1");
+        request(std::net::SocketAddr::from((std::net::Ipv6Addr::LOCALHOST, port)), TestRequest::get("/")).1.assert_body("::1");
+
+        let mut client : std::os::unix::net::UnixStream = std::os::unix::net::UnixStream::connect(&socket_path).unwrap();
+        client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        client.write_all(&TestRequest::get("/").to_bytes()).unwrap();
+        read_response(&mut client).assert_status(HttpStateCode::HTTP_200).assert_body(crate::server::listener::listener::UNIX_CLIENT_ADDRESS);
+        drop(client);
+
+        // 종료시 Socket 파일 삭제
+        assert!(handle.shutdown_with_timeout(Duration::from_secs(5)));
+        assert!(!socket_path.exists());
+    }
+
+
+    #[test]
+    fn rejects_clients_over_the_rate_limit() {
+        let handle : ServerHandle = Server::builder()
+            .bind(String::from("127.0.0.1"), 0)
+            .rate_limit(RateLimitPolicy::SLIDING_WINDOW { max_requests: 1, window: Duration::from_secs(60) })
+            .event_response(|_| text("ok"))
+            .build()
+            .start()
+            .unwrap();
+
+        // 요청 수 초과시 429 응답 후 접속 종료
+        let (mut stream, response) = request(handle.local_addr(), TestRequest::get("/"));
+        response.assert_body("ok");
+        stream.write_all(&TestRequest::get("/").to_bytes()).unwrap();
+        read_response(&mut stream).assert_status(HttpStateCode::HTTP_429).assert_header("Retry-After", "60").assert_header("Connection", "close");
+        assert_closed(&mut stream);
+
+        assert!(handle.shutdown_with_timeout(Duration::from_secs(5)));
+    }
+
+
+    #[test]
+    fn rejects_connections_over_the_per_ip_limit() {
+        let handle : ServerHandle = Server::builder()
+            .bind(String::from("127.0.0.1"), 0)
+            .max_connections_per_ip(1)
+            .event_response(|_| text("ok"))
+            .build()
+            .start()
+            .unwrap();
+
+        let (stream, response) = request(handle.local_addr(), TestRequest::get("/"));
+        response.assert_body("ok");
+        // 요청을 보내기 전에 429 응답 후 접속 종료
+        let mut rejected : TcpStream = TcpStream::connect(handle.local_addr()).unwrap();
+        rejected.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        read_response(&mut rejected).assert_status(HttpStateCode::HTTP_429).assert_header("Retry-After", "1");
+        assert_closed(&mut rejected);
+
+        // 접속을 닫으면 다시 허용
+        drop(stream);
+        let deadline : Instant = Instant::now() + Duration::from_secs(5);
+        let mut client : TcpStream = loop {
+            let client : TcpStream = TcpStream::connect(handle.local_addr()).unwrap();
+            client.set_read_timeout(Some(Duration::from_millis(100))).unwrap();
+            // 거부된 접속은 요청 전에 응답을 받는다
+            if client.peek(&mut [0; 1]).is_err() {
+                break client;
+            }
+            assert!(Instant::now() < deadline);
+            std::thread::sleep(Duration::from_millis(20));
+        };
+        client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        client.write_all(&TestRequest::get("/").to_bytes()).unwrap();
+        read_response(&mut client).assert_body("ok");
+
+        assert!(handle.shutdown_with_timeout(Duration::from_secs(5)));
+    }
+
+
+    #[test]
+    fn answers_handler_panics_with_500_and_keeps_serving() {
+        let handle : ServerHandle = Server::builder()
+            .bind(String::from("127.0.0.1"), 0)
+            .worker_count(1)
+            .middleware(|request : &mut Request, next : crate::server::middleware::middleware::Next| {
+                if request.path() == Some("/middleware") {
+                    panic!("middleware failed");
+                }
+                next.run(request)
+            })
+            .event_response(|request| {
+                if request.path() == Some("/handler") {
+                    panic!("handler failed");
+                }
+                text("ok")
+            })
+            .build()
+            .start()
+            .unwrap();
+
+        // Panic 은 500 응답으로 바뀌고 같은 접속과 작업 Thread 로 다음 요청을 처리한다
+        let (mut stream, response) = request(handle.local_addr(), TestRequest::get("/handler"));
+        response.assert_status(HttpStateCode::HTTP_500).assert_header("Connection", "keep-alive");
+        for (path, status) in [("/middleware", 500), ("/", 200)] {
+            stream.write_all(&TestRequest::get(path).to_bytes()).unwrap();
+            assert_eq!(read_response(&mut stream).status, status);
+        }
+        drop(stream);
+        request(handle.local_addr(), TestRequest::get("/").header("Connection", "close")).1.assert_body("ok");
+
+        assert!(handle.shutdown_with_timeout(Duration::from_secs(5)));
+    }
+
+
+    #[test]
+    fn streams_events_from_the_last_event_id() {
+        let handle : ServerHandle = Server::builder()
+            .bind(String::from("127.0.0.1"), 0)
+            .event_stream_keep_alive(Duration::from_millis(100))
+            .event_response(|request| {
+                let (response, sender) = event_stream_response(request);
+                let start : u64 = request.last_event_id().and_then(|id| id.parse().ok()).unwrap_or(0);
+                std::thread::spawn(move || {
+                    sender.send(Event::new(String::from("line one\nline two")).id((start + 1).to_string())).unwrap();
+                    sender.send(Event::new(String::from("done")).event(String::from("end")).id((start + 2).to_string())).unwrap();
+                    // 이벤트가 없는 동안 접속 유지용 주석 전송, 전송기를 Drop 하면 스트림 종료
+                    std::thread::sleep(Duration::from_millis(300));
+                });
+                response
+            })
+            .build()
+            .start()
+            .unwrap();
+
+        // 재접속한 클라이언트는 마지막으로 받은 ID 다음 이벤트부터 받는다
+        let (mut stream, response) = request(handle.local_addr(), TestRequest::get("/").header("Last-Event-ID", "5"));
+        response.assert_header("Connection", "close").assert_no_header("Content-Length");
+        let mut body : String = String::new();
+        stream.read_to_string(&mut body).unwrap();
+        assert!(body.starts_with("id: 6\ndata: line one\ndata: line two\n\nevent: end\nid: 7\ndata: done\n\n: keep-alive\n\n"), "{:?}", body);
+
+        assert!(handle.shutdown_with_timeout(Duration::from_secs(5)));
+    }
+}
@@ -18,6 +18,7 @@ pub mod page_manager {
     }
 
     /// 페이지 정보 Struct
+    #[derive(Clone)]
     pub struct PageInfo {
         pub file_path: String,
         pub is_access: bool
@@ -35,73 +36,100 @@ pub mod page_manager {
         SUCCESS, FAIL, NO_DATA
     }
 
-    /// 페이지 리스트
+    /// 페이지 리스트 (전역 호환 API)
     /// 주의: 페이지 추가시 경로는 모두 소문자로 입력
+    #[cfg(feature = "legacy-globals")]
     pub static mut ALL_PAGES : AllPages = AllPages {
         pages: None
     };
 
 
-    /// HTML 파일 Reader
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// read_page(String::from("/hello.html"))
-    /// ```
-    ///
-    /// # Argument
-    /// page_path : HTTP 경로
-    ///
-    /// all_pages : 모든 페이지 정보
-    ///
-    /// # Return
-    /// PageFileReadInfo 구조체
-    pub fn read_page(page_path : String) -> PageFileReadInfo {
-        let mut read_result : PageFileReadInfo = PageFileReadInfo {
-            value: None,
-            is_success: IsPageFileReadSuccess::NO_DATA,
-        };
+    impl AllPages {
+        /// 빈 페이지 리스트 생성
+        pub fn new() -> AllPages {
+            return AllPages {
+                pages: Some(HashMap::new())
+            };
+        }
 
-        unsafe {
-            match &ALL_PAGES.pages {
+
+        /// 페이지 추가
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use AthenaEngine::server::page_manager::page_manager::{AllPages, PageInfo};
+        /// # let mut all_pages = AllPages::new();
+        /// # let page_info = PageInfo { file_path: String::from("hello.html"), is_access: true };
+        /// all_pages.insert_page(String::from("/hello.html"), page_info)
+        /// ```
+        ///
+        /// # Argument
+        /// page_path : HTTP 경로 (소문자로 변환되어 저장)
+        ///
+        /// page_info : 페이지 정보
+        pub fn insert_page(&mut self, page_path : String, page_info : PageInfo) {
+            self.pages.get_or_insert_with(HashMap::new).insert(page_path.to_lowercase(), page_info);
+        }
+
+
+        /// HTML 파일 Reader
+        ///
+        /// # Examples
+        ///
+        /// ```no_run
+        /// # use AthenaEngine::server::page_manager::page_manager::{AllPages, PageInfo};
+        /// # let mut all_pages = AllPages::new();
+        /// # all_pages.insert_page(String::from("/hello.html"), PageInfo { file_path: String::from("hello.html"), is_access: true });
+        /// all_pages.read_page(String::from("/hello.html"))
+        /// # ;
+        /// ```
+        ///
+        /// # Argument
+        /// page_path : HTTP 경로
+        ///
+        /// # Return
+        /// PageFileReadInfo 구조체
+        pub fn read_page(&self, page_path : String) -> PageFileReadInfo {
+            let mut read_result : PageFileReadInfo = PageFileReadInfo {
+                value: None,
+                is_success: IsPageFileReadSuccess::NO_DATA,
+            };
+
+            match &self.pages {
                 Some(map) => {
-                    if map.contains_key(&page_path.to_lowercase()) {
-                        let page_info = map.get(&page_path.to_lowercase());
-                        match page_info {
-                            Some(page_info) => {
-                                if page_info.is_access {
-                                    let mut read_value = std::fs::File::open(&page_info.file_path);
-                                    match read_value {
-                                        Ok(mut value) => {
-                                            let mut contents = String::new();
-                                            match value.read_to_string(&mut contents) {
-                                                Ok(_) => {
-                                                    read_result.value = Some(contents);
-                                                    read_result.is_success = IsPageFileReadSuccess::SUCCESS;
-                                                }
-                                                Err(error) => {
-                                                    read_result.is_success = IsPageFileReadSuccess::FAIL;
-
-                                                    // 로그 출력
-                                                    println!("{}", log_more_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING, String::from(&page_info.file_path)));
-                                                }
+                    match map.get(&page_path.to_lowercase()) {
+                        Some(page_info) => {
+                            if page_info.is_access {
+                                match std::fs::File::open(&page_info.file_path) {
+                                    Ok(mut value) => {
+                                        let mut contents = String::new();
+                                        match value.read_to_string(&mut contents) {
+                                            Ok(_) => {
+                                                read_result.value = Some(contents);
+                                                read_result.is_success = IsPageFileReadSuccess::SUCCESS;
                                             }
-                                        },
-                                        Err(error) => {
-                                            read_result.is_success = IsPageFileReadSuccess::FAIL;
+                                            Err(error) => {
+                                                read_result.is_success = IsPageFileReadSuccess::FAIL;
 
-                                            // 로그 출력
-                                            println!("{}", log_more_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING, String::from(&page_info.file_path)));
+                                                // 로그 출력
+                                                println!("{}", log_more_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING, String::from(&page_info.file_path)));
+                                            }
                                         }
+                                    },
+                                    Err(error) => {
+                                        read_result.is_success = IsPageFileReadSuccess::FAIL;
+
+                                        // 로그 출력
+                                        println!("{}", log_more_text_writer(error.to_string(), get_this_name(), LogTypeTag::WARNING, String::from(&page_info.file_path)));
                                     }
-                                }else {
-                                    read_result.is_success = IsPageFileReadSuccess::FAIL;
                                 }
-                            },
-                            None => {
-                                read_result.is_success = IsPageFileReadSuccess::NO_DATA;
+                            }else {
+                                read_result.is_success = IsPageFileReadSuccess::FAIL;
                             }
+                        },
+                        None => {
+                            read_result.is_success = IsPageFileReadSuccess::NO_DATA;
                         }
                     }
                 },
@@ -109,9 +137,40 @@ pub mod page_manager {
                     read_result.is_success = IsPageFileReadSuccess::NO_DATA;
                 }
             }
+
+            return read_result;
         }
+    }
 
-        return read_result;
+
+    impl Default for AllPages {
+        fn default() -> Self {
+            return AllPages::new();
+        }
+    }
+
+
+    /// HTML 파일 Reader (전역 호환 API, `ALL_PAGES` 사용)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use AthenaEngine::server::page_manager::page_manager::read_page;
+    /// read_page(String::from("/hello.html"))
+    /// # ;
+    /// ```
+    ///
+    /// # Argument
+    /// page_path : HTTP 경로
+    ///
+    /// # Return
+    /// PageFileReadInfo 구조체
+    #[cfg(feature = "legacy-globals")]
+    pub fn read_page(page_path : String) -> PageFileReadInfo {
+        // 전역 페이지 리스트는 서버 시작 전에만 수정된다
+        unsafe {
+            return (*std::ptr::addr_of!(ALL_PAGES)).read_page(page_path);
+        }
     }
 
 
@@ -119,20 +178,20 @@ pub mod page_manager {
     pub fn page_template_parser(html : String, var : HashMap<String, GetPageTemplateVar>) -> String {
         // 기본 Tag
         let tag_root = String::from("<#>");
-        let tag_var = String::from(format!("{}var", tag_root));
-        let tag_control = String::from(format!("{}control", tag_root));
+        let tag_var = format!("{}var", tag_root);
+        let tag_control = format!("{}control", tag_root);
         // 제어문 Tag
-        let tag_control_for = String::from(format!("{}.for", tag_control));
-        let tag_control_for_end = String::from(format!("{}.for_end", tag_control));
+        let tag_control_for = format!("{}.for", tag_control);
+        let tag_control_for_end = format!("{}.for_end", tag_control);
 
         // HTML 반환
         return if html.contains(&tag_root) && html.contains("\n") {
-            let mut new_html = String::from(html.clone());
+            let mut new_html = html.clone();
             // 변수 치환
             for (var_name, fun_value) in var {
                 let value : String = fun_value();
 
-                new_html = new_html.replace(&String::from(format!("{}.{}", &tag_var, var_name)), &value);
+                new_html = new_html.replace(&format!("{}.{}", &tag_var, var_name), &value);
             }
             // 오류 확인
             if new_html.contains(&tag_var) {
@@ -143,9 +202,9 @@ pub mod page_manager {
             }
 
             // 제어문 컴파일
-            let mut control_new_html = String::from(&new_html);
-            let mut control_split = control_new_html.split("\r\n");
-            let mut control_split : Vec<&str> = control_split.collect();
+            let control_new_html = String::from(&new_html);
+            let control_split = control_new_html.split("\r\n");
+            let control_split : Vec<&str> = control_split.collect();
             let mut control_for_enable : bool = false;
             let mut control_for_replace : String = String::new();
             let mut control_for_text : String = String::new();
@@ -162,7 +221,7 @@ pub mod page_manager {
                     control_for_start = 0;
                     control_for_end = 0;
                     // For 문 분석
-                    let data = control_line.clone().replace(&tag_control_for, "").replace(" ", "");
+                    let data = control_line.replace(&tag_control_for, "").replace(" ", "");
                     // 오류 확인
                     if !data.contains(",") {
                         // 로그 출력
@@ -173,15 +232,9 @@ pub mod page_manager {
                     // For 문 데이터
                     let data : Vec<&str> = data.split(",").collect();
                     if data.len() >= 2 {
-                        control_for_start = match data[0].parse() {
-                            Ok(value) => value,
-                            Err(_) => 0
-                        };
-                        control_for_end = match data[1].parse() {
-                            Ok(value) => value,
-                            Err(_) => 0
-                        };
-                        control_for_replace.push_str(&String::from(format!("{}\r\n", &control_line)));
+                        control_for_start = data[0].parse().unwrap_or(0);
+                        control_for_end = data[1].parse().unwrap_or(0);
+                        control_for_replace.push_str(&format!("{}\r\n", &control_line));
                         control_for_enable = true;
                     }else {
                         control_for_enable = false;
@@ -189,16 +242,16 @@ pub mod page_manager {
                 }
                 // For 문 내용 확인
                 if control_for_enable && !control_line.contains(&tag_control_for) && !control_line.contains(&tag_control_for_end) {
-                    control_for_text.push_str(&control_line);
-                    control_for_replace.push_str(&String::from(format!("{}\r\n", &control_line)));
+                    control_for_text.push_str(control_line);
+                    control_for_replace.push_str(&format!("{}\r\n", &control_line));
                 }
                 // For 문 종료 확인
                 if control_line.contains(&tag_control_for_end) && control_for_enable {
                     control_for_enable = false;
-                    control_for_replace.push_str(&String::from(format!("{}\r\n", &control_line)));
+                    control_for_replace.push_str(&format!("{}\r\n", &control_line));
 
                     for _ in control_for_start..control_for_end {
-                        control_for_new_text.push_str(&String::from(format!("{}\r\n", &control_for_text)));
+                        control_for_new_text.push_str(&format!("{}\r\n", &control_for_text));
                     }
                     new_html = new_html.replace(&control_for_replace, &control_for_new_text);
                 }
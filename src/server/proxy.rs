@@ -0,0 +1,452 @@
+pub mod proxy {
+    use std::io::Read;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    use crate::server::request_parser::request_parser::Request;
+    use crate::server::listener::listener::UNIX_CLIENT_ADDRESS;
+
+
+    /// PROXY protocol v2 Header 시작 (12 Byte)
+    const V2_SIGNATURE : [u8; 12] = [0x0D, 0x0A, 0x0D, 0x0A, 0x00, 0x0D, 0x0A, 0x51, 0x55, 0x49, 0x54, 0x0A];
+    /// PROXY protocol v1 Header 시작
+    const V1_PREFIX : &[u8] = b"PROXY ";
+    /// PROXY protocol v1 Header 최대 길이 (CRLF 포함)
+    const V1_MAX_LENGTH : usize = 107;
+    /// PROXY protocol v2 Header 고정 길이 (주소 정보 제외)
+    const V2_HEADER_LENGTH : usize = 16;
+
+
+    /// 클라이언트 주소를 전달하는 신뢰할 수 있는 Proxy
+    ///
+    /// 접속한 주소가 목록에 있으면 `forwarded_header` 로 설정한 Header 의 주소를 클라이언트 주소로 사용한다.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::proxy::proxy::TrustedProxy;
+    /// # use AthenaEngine::server::server_config::server_config::ServerConfig;
+    /// let config = ServerConfig {
+    ///     trusted_proxies: vec![TrustedProxy::parse("10.0.0.0/8").unwrap(), TrustedProxy::UNIX_SOCKET],
+    ///     ..ServerConfig::default()
+    /// };
+    /// # assert_eq!(config.trusted_proxies.len(), 2);
+    /// ```
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum TrustedProxy {
+        /// `address` 의 앞 `prefix_len` Bit 가 같은 모든 주소
+        NETWORK { address : IpAddr, prefix_len : u8 },
+        /// Unix Domain Socket 으로 접속한 로컬 Proxy
+        UNIX_SOCKET
+    }
+
+    /// 신뢰할 수 있는 Proxy 가 클라이언트 주소를 기록하는 Header
+    ///
+    /// Proxy 가 기록하지 않는 Header 는 클라이언트가 임의로 보낸 값이 그대로 전달되므로 읽지 않는다.
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    pub enum ForwardedHeader {
+        /// `X-Forwarded-For: <client>, <proxy1>`
+        X_FORWARDED_FOR,
+        /// `Forwarded: for=<client>, for=<proxy1>` (RFC 7239)
+        FORWARDED
+    }
+
+    /// PROXY protocol Header 분석 결과
+    enum ProxyHeaderParse {
+        /// Header 를 끝까지 받지 못함 (전체 데이터가 최소 해당 길이만큼 필요)
+        INCOMPLETE(usize),
+        /// Header 분석 완료 (주소가 없으면 접속한 주소 사용)
+        COMPLETE(Option<IpAddr>),
+        /// 잘못된 Header
+        INVALID(&'static str)
+    }
+
+
+    impl TrustedProxy {
+        /// 주소 (`192.168.0.10`) 또는 CIDR (`10.0.0.0/8`, `fd00::/8`) 문자열 변환
+        pub fn parse(value : &str) -> Option<TrustedProxy> {
+            let (address, prefix_len) = match value.trim().split_once('/') {
+                Some((address, prefix_len)) => (address.parse::<IpAddr>().ok()?, Some(prefix_len.parse::<u8>().ok()?)),
+                None => (value.trim().parse::<IpAddr>().ok()?, None)
+            };
+            let max_prefix_len : u8 = if address.is_ipv4() { 32 } else { 128 };
+            let prefix_len : u8 = prefix_len.unwrap_or(max_prefix_len);
+            if prefix_len > max_prefix_len {
+                return None;
+            }
+
+            return Some(TrustedProxy::NETWORK { address, prefix_len });
+        }
+
+
+        /// 주소가 신뢰할 수 있는 Proxy 인지 확인
+        ///
+        /// # Argument
+        /// client_ip : 접속한 주소 (IP 주소 또는 Unix Domain Socket 표시)
+        fn contains(&self, client_ip : &str) -> bool {
+            return match self {
+                TrustedProxy::UNIX_SOCKET => client_ip == UNIX_CLIENT_ADDRESS,
+                TrustedProxy::NETWORK { address, prefix_len } => match client_ip.parse::<IpAddr>() {
+                    Ok(ip) => network_contains(address, *prefix_len, &ip),
+                    Err(_) => false
+                }
+            };
+        }
+    }
+
+
+    /// 접속 시작의 PROXY protocol v1/v2 Header 읽기
+    ///
+    /// Header 뒤의 데이터 (HTTP 요청, TLS Handshake) 를 읽지 않도록 필요한 길이만큼만 읽는다.
+    ///
+    /// # Return
+    /// Header 가 전달한 클라이언트 주소 (`LOCAL`, `UNKNOWN` 이면 None)
+    pub(crate) fn read_proxy_header<R : Read>(stream : &mut R) -> std::io::Result<Option<IpAddr>> {
+        let mut header : Vec<u8> = Vec::with_capacity(V2_HEADER_LENGTH);
+        loop {
+            match parse_proxy_header(&header) {
+                ProxyHeaderParse::INCOMPLETE(length) => {
+                    let start : usize = header.len();
+                    header.resize(length, 0);
+                    stream.read_exact(&mut header[start..])?;
+                },
+                ProxyHeaderParse::COMPLETE(address) => return Ok(address),
+                ProxyHeaderParse::INVALID(message) => return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, message))
+            }
+        }
+    }
+
+
+    /// 접속 시작의 PROXY protocol v1/v2 Header 읽기 (비동기 Stream)
+    #[cfg(feature = "async")]
+    pub(crate) async fn read_proxy_header_async<R : tokio::io::AsyncRead + Unpin>(stream : &mut R) -> std::io::Result<Option<IpAddr>> {
+        use tokio::io::AsyncReadExt;
+
+        let mut header : Vec<u8> = Vec::with_capacity(V2_HEADER_LENGTH);
+        loop {
+            match parse_proxy_header(&header) {
+                ProxyHeaderParse::INCOMPLETE(length) => {
+                    let start : usize = header.len();
+                    header.resize(length, 0);
+                    stream.read_exact(&mut header[start..]).await?;
+                },
+                ProxyHeaderParse::COMPLETE(address) => return Ok(address),
+                ProxyHeaderParse::INVALID(message) => return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, message))
+            }
+        }
+    }
+
+
+    /// 신뢰할 수 있는 Proxy 가 전달한 클라이언트 주소 확인
+    ///
+    /// 접속한 주소가 신뢰할 수 있는 Proxy 이면 설정한 Header 의 주소를
+    /// 오른쪽 (가장 가까운 Proxy) 부터 확인하여 신뢰할 수 없는 첫 주소를 클라이언트 주소로 사용한다.
+    /// 다른 Header 는 Proxy 가 기록한 값인지 알 수 없으므로 설정한 Header 가 없어도 사용하지 않는다.
+    ///
+    /// # Argument
+    /// trusted_proxies : 신뢰할 수 있는 Proxy 목록
+    ///
+    /// forwarded_header : Proxy 가 클라이언트 주소를 기록하는 Header
+    ///
+    /// client_ip : 접속한 주소 (PROXY protocol 주소 포함)
+    ///
+    /// request : 요청
+    pub(crate) fn forwarded_client_ip(trusted_proxies : &[TrustedProxy], forwarded_header : ForwardedHeader, client_ip : &str, request : &Request) -> String {
+        if !is_trusted(trusted_proxies, client_ip) {
+            return String::from(client_ip);
+        }
+        let addresses : Vec<String> = match forwarded_header {
+            ForwardedHeader::X_FORWARDED_FOR => match request.header("X-Forwarded-For") {
+                Some(forwarded_for) => forwarded_for.split(',').map(|address| address.trim().to_string()).collect(),
+                None => return String::from(client_ip)
+            },
+            ForwardedHeader::FORWARDED => match request.header("Forwarded") {
+                Some(forwarded) => forwarded_for_addresses(forwarded),
+                None => return String::from(client_ip)
+            }
+        };
+
+        let mut resolved : String = String::from(client_ip);
+        for address in addresses.iter().rev() {
+            // 알 수 없는 주소 (`unknown`, 숨긴 이름) 이후는 확인할 수 없다
+            let ip : IpAddr = match strip_port(address).parse::<IpAddr>() {
+                Ok(ip) => ip,
+                Err(_) => break
+            };
+            resolved = ip.to_string();
+            if !is_trusted(trusted_proxies, &resolved) {
+                break;
+            }
+        }
+
+        return resolved;
+    }
+
+
+    /// 신뢰할 수 있는 Proxy 목록에 있는 주소인지 확인
+    fn is_trusted(trusted_proxies : &[TrustedProxy], client_ip : &str) -> bool {
+        return trusted_proxies.iter().any(|proxy| proxy.contains(client_ip));
+    }
+
+
+    /// `Forwarded` Header 의 `for` 주소 목록
+    fn forwarded_for_addresses(forwarded : &str) -> Vec<String> {
+        let mut addresses : Vec<String> = Vec::new();
+        for element in forwarded.split(',') {
+            let address : Option<&str> = element.split(';')
+                .filter_map(|pair| pair.split_once('='))
+                .find(|(name, _)| name.trim().eq_ignore_ascii_case("for"))
+                .map(|(_, value)| value.trim().trim_matches('"'));
+            addresses.push(String::from(address.unwrap_or("unknown")));
+        }
+
+        return addresses;
+    }
+
+
+    /// 주소의 Port 제거 (`192.0.2.1:8080`, `[2001:db8::1]:8080`)
+    fn strip_port(address : &str) -> &str {
+        if let Some(address) = address.strip_prefix('[') {
+            return address.split(']').next().unwrap_or(address);
+        }
+
+        return match address.split_once(':') {
+            // `:` 가 하나뿐이면 IPv4 주소와 Port
+            Some((ip, port)) if !port.contains(':') => ip,
+            _ => address
+        };
+    }
+
+
+    /// 주소가 Network 에 포함되는지 확인
+    fn network_contains(network : &IpAddr, prefix_len : u8, ip : &IpAddr) -> bool {
+        // IPv4-mapped IPv6 주소 (`::ffff:10.0.0.1`) 는 IPv4 주소로 비교
+        let ip : IpAddr = match ip {
+            IpAddr::V6(ipv6) => ipv6.to_ipv4_mapped().map_or(*ip, IpAddr::V4),
+            IpAddr::V4(_) => *ip
+        };
+        let (network, ip, bits) : (u128, u128, u32) = match (network, ip) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => (u32::from(*network) as u128, u32::from(ip) as u128, 32),
+            (IpAddr::V6(network), IpAddr::V6(ip)) => (u128::from(*network), u128::from(ip), 128),
+            _ => return false
+        };
+        let prefix_len : u32 = (prefix_len as u32).min(bits);
+        if prefix_len == 0 {
+            return true;
+        }
+        let shift : u32 = bits - prefix_len;
+
+        return (network >> shift) == (ip >> shift);
+    }
+
+
+    /// 지금까지 받은 PROXY protocol Header 분석
+    fn parse_proxy_header(header : &[u8]) -> ProxyHeaderParse {
+        // v1 과 v2 의 최소 길이보다 짧은 시작 부분으로 구분
+        if header.len() < V2_SIGNATURE.len() {
+            return ProxyHeaderParse::INCOMPLETE(V2_SIGNATURE.len());
+        }
+        if header.starts_with(&V2_SIGNATURE) {
+            return parse_proxy_header_v2(header);
+        }
+        if header.starts_with(V1_PREFIX) {
+            return parse_proxy_header_v1(header);
+        }
+
+        return ProxyHeaderParse::INVALID("Missing PROXY protocol header.");
+    }
+
+
+    /// PROXY protocol v1 (`PROXY TCP4 192.0.2.1 192.0.2.2 56324 443\r\n`) 분석
+    fn parse_proxy_header_v1(header : &[u8]) -> ProxyHeaderParse {
+        if !header.ends_with(b"\r\n") {
+            if header.len() >= V1_MAX_LENGTH {
+                return ProxyHeaderParse::INVALID("PROXY protocol v1 header is too long.");
+            }
+            return ProxyHeaderParse::INCOMPLETE(header.len() + 1);
+        }
+        let line : &str = match std::str::from_utf8(&header[..header.len() - 2]) {
+            Ok(line) => line,
+            Err(_) => return ProxyHeaderParse::INVALID("Invalid PROXY protocol v1 header.")
+        };
+
+        let fields : Vec<&str> = line.split(' ').collect();
+        return match fields.get(1).copied() {
+            Some("UNKNOWN") => ProxyHeaderParse::COMPLETE(None),
+            Some(protocol @ ("TCP4" | "TCP6")) if fields.len() == 6 => match fields[2].parse::<IpAddr>() {
+                Ok(address) if address.is_ipv4() == (protocol == "TCP4") => ProxyHeaderParse::COMPLETE(Some(address)),
+                _ => ProxyHeaderParse::INVALID("Invalid PROXY protocol v1 source address.")
+            },
+            _ => ProxyHeaderParse::INVALID("Invalid PROXY protocol v1 header.")
+        };
+    }
+
+
+    /// PROXY protocol v2 (Binary) 분석
+    fn parse_proxy_header_v2(header : &[u8]) -> ProxyHeaderParse {
+        if header.len() < V2_HEADER_LENGTH {
+            return ProxyHeaderParse::INCOMPLETE(V2_HEADER_LENGTH);
+        }
+        let length : usize = V2_HEADER_LENGTH + u16::from_be_bytes([header[14], header[15]]) as usize;
+        if header.len() < length {
+            return ProxyHeaderParse::INCOMPLETE(length);
+        }
+        if header[12] >> 4 != 0x2 {
+            return ProxyHeaderParse::INVALID("Unsupported PROXY protocol version.");
+        }
+
+        let address : &[u8] = &header[V2_HEADER_LENGTH..length];
+        return match (header[12] & 0x0F, header[13] >> 4) {
+            // LOCAL (Proxy 자체의 상태 확인 등)
+            (0x0, _) => ProxyHeaderParse::COMPLETE(None),
+            // PROXY - AF_INET
+            (0x1, 0x1) if address.len() >= 12 => {
+                let source : [u8; 4] = [address[0], address[1], address[2], address[3]];
+                ProxyHeaderParse::COMPLETE(Some(IpAddr::V4(Ipv4Addr::from(source))))
+            },
+            // PROXY - AF_INET6
+            (0x1, 0x2) if address.len() >= 36 => {
+                let mut source : [u8; 16] = [0; 16];
+                source.copy_from_slice(&address[..16]);
+                ProxyHeaderParse::COMPLETE(Some(IpAddr::V6(Ipv6Addr::from(source))))
+            },
+            // PROXY - AF_UNSPEC, AF_UNIX
+            (0x1, 0x0 | 0x3) => ProxyHeaderParse::COMPLETE(None),
+            _ => ProxyHeaderParse::INVALID("Invalid PROXY protocol v2 header.")
+        };
+    }
+
+
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::io::Cursor;
+        use crate::server::request_parser::request_parser::request_parser;
+
+
+        /// Header 를 읽고 (결과, 읽지 않고 남은 데이터) 반환
+        fn read(data : &[u8]) -> (std::io::Result<Option<IpAddr>>, Vec<u8>) {
+            let mut cursor : Cursor<&[u8]> = Cursor::new(data);
+            let result = read_proxy_header(&mut cursor);
+            let position : usize = cursor.position() as usize;
+            return (result, data[position..].to_vec());
+        }
+
+
+        /// PROXY protocol v2 Header 생성
+        fn v2_header(command : u8, family : u8, address : &[u8]) -> Vec<u8> {
+            let mut header : Vec<u8> = V2_SIGNATURE.to_vec();
+            header.push(0x20 | command);
+            header.push(family << 4 | 0x1);
+            header.extend_from_slice(&(address.len() as u16).to_be_bytes());
+            header.extend_from_slice(address);
+            return header;
+        }
+
+
+        fn header_request(headers : &[&str]) -> Request {
+            let mut lines : Vec<&str> = vec!["GET / HTTP/1.1"];
+            lines.extend_from_slice(headers);
+            return request_parser(&lines);
+        }
+
+
+        #[test]
+        fn reads_v1_header_without_consuming_request() {
+            let (result, rest) = read(b"PROXY TCP4 192.0.2.1 192.0.2.2 56324 443\r\nGET / HTTP/1.1\r\n\r\n");
+            assert_eq!(result.unwrap(), Some("192.0.2.1".parse().unwrap()));
+            assert_eq!(rest, b"GET / HTTP/1.1\r\n\r\n");
+
+            let (result, _) = read(b"PROXY TCP6 2001:db8::1 2001:db8::2 56324 443\r\n");
+            assert_eq!(result.unwrap(), Some("2001:db8::1".parse().unwrap()));
+
+            let (result, _) = read(b"PROXY UNKNOWN\r\n");
+            assert_eq!(result.unwrap(), None);
+        }
+
+
+        #[test]
+        fn rejects_invalid_v1_headers() {
+            for data in [
+                &b"GET / HTTP/1.1\r\n\r\n"[..],
+                &b"PROXY TCP4 2001:db8::1 192.0.2.2 56324 443\r\n"[..],
+                &b"PROXY TCP4 192.0.2.1 192.0.2.2 56324\r\n"[..],
+                &b"PROXY UDP4 192.0.2.1 192.0.2.2 56324 443\r\n"[..]
+            ] {
+                assert_eq!(read(data).0.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+            }
+
+            // CRLF 없이 최대 길이를 넘는 Header
+            let mut data : Vec<u8> = b"PROXY TCP4 ".to_vec();
+            data.extend_from_slice(&[b'1'; 200]);
+            assert_eq!(read(&data).0.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+        }
+
+
+        #[test]
+        fn reads_v2_header_addresses() {
+            let mut data : Vec<u8> = v2_header(0x1, 0x1, &[192, 0, 2, 1, 192, 0, 2, 2, 0xDB, 0xE4, 0x01, 0xBB]);
+            data.extend_from_slice(b"GET /");
+            let (result, rest) = read(&data);
+            assert_eq!(result.unwrap(), Some("192.0.2.1".parse().unwrap()));
+            assert_eq!(rest, b"GET /");
+
+            let mut address : Vec<u8> = "2001:db8::1".parse::<Ipv6Addr>().unwrap().octets().to_vec();
+            address.extend_from_slice(&[0; 20]);
+            assert_eq!(read(&v2_header(0x1, 0x2, &address)).0.unwrap(), Some("2001:db8::1".parse().unwrap()));
+
+            // LOCAL 과 TLV 가 붙은 AF_UNSPEC 은 접속한 주소 사용
+            assert_eq!(read(&v2_header(0x0, 0x1, &[0; 12])).0.unwrap(), None);
+            assert_eq!(read(&v2_header(0x1, 0x0, &[0x04, 0x00, 0x01, 0x00])).0.unwrap(), None);
+        }
+
+
+        #[test]
+        fn rejects_invalid_v2_headers() {
+            // 주소 길이가 Family 보다 짧음
+            assert_eq!(read(&v2_header(0x1, 0x1, &[192, 0, 2, 1])).0.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+
+            let mut data : Vec<u8> = v2_header(0x1, 0x1, &[0; 12]);
+            data[12] = 0x11;
+            assert_eq!(read(&data).0.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+
+            // Header 가 끝나기 전에 접속 종료
+            let data : Vec<u8> = v2_header(0x1, 0x1, &[0; 12]);
+            assert_eq!(read(&data[..20]).0.unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+        }
+
+
+        #[test]
+        fn parses_trusted_proxy_networks() {
+            let network : TrustedProxy = TrustedProxy::parse("10.0.0.0/8").unwrap();
+            assert!(network.contains("10.1.2.3"));
+            assert!(network.contains("::ffff:10.1.2.3"));
+            assert!(!network.contains("11.0.0.1"));
+            assert!(!network.contains(UNIX_CLIENT_ADDRESS));
+
+            assert!(TrustedProxy::parse("fd00::/8").unwrap().contains("fd12::1"));
+            assert!(TrustedProxy::parse("192.0.2.1").unwrap().contains("192.0.2.1"));
+            assert!(TrustedProxy::UNIX_SOCKET.contains(UNIX_CLIENT_ADDRESS));
+            assert_eq!(TrustedProxy::parse("10.0.0.0/33"), None);
+            assert_eq!(TrustedProxy::parse("example.com"), None);
+        }
+
+
+        #[test]
+        fn resolves_forwarded_client_address_from_configured_header() {
+            let trusted : Vec<TrustedProxy> = vec![TrustedProxy::parse("10.0.0.0/8").unwrap()];
+            let request : Request = header_request(&["X-Forwarded-For: 203.0.113.9, 198.51.100.7, 10.0.0.2", "Forwarded: for=192.0.2.60;proto=http, for=\"[2001:db8::7]:4711\""]);
+
+            // 신뢰할 수 없는 첫 주소 (오른쪽부터)
+            assert_eq!(forwarded_client_ip(&trusted, ForwardedHeader::X_FORWARDED_FOR, "10.0.0.1", &request), "198.51.100.7");
+            assert_eq!(forwarded_client_ip(&trusted, ForwardedHeader::FORWARDED, "10.0.0.1", &request), "2001:db8::7");
+            // 신뢰할 수 없는 접속은 Header 를 무시
+            assert_eq!(forwarded_client_ip(&trusted, ForwardedHeader::X_FORWARDED_FOR, "203.0.113.1", &request), "203.0.113.1");
+            // 설정하지 않은 Header 는 사용하지 않는다
+            let request : Request = header_request(&["X-Forwarded-For: 203.0.113.9"]);
+            assert_eq!(forwarded_client_ip(&trusted, ForwardedHeader::FORWARDED, "10.0.0.1", &request), "10.0.0.1");
+            // 알 수 없는 주소 이후는 확인하지 않는다
+            let request : Request = header_request(&["X-Forwarded-For: 203.0.113.9, unknown, 10.0.0.3"]);
+            assert_eq!(forwarded_client_ip(&trusted, ForwardedHeader::X_FORWARDED_FOR, "10.0.0.1", &request), "10.0.0.3");
+        }
+    }
+}
@@ -0,0 +1,278 @@
+pub mod rate_limiter {
+    use std::collections::{HashMap, VecDeque};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+    use crate::server::server_config::server_config::ServerConfig;
+    use crate::server::listener::listener::UNIX_CLIENT_ADDRESS;
+
+
+    /// 오래 사용하지 않은 클라이언트 기록을 정리하는 간격
+    const CLEANUP_INTERVAL : Duration = Duration::from_secs(60);
+
+
+    /// 요청 수 제한 방식 Enum
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum RateLimitPolicy {
+        /// Token Bucket (최대 `capacity` 개까지 연속 요청 허용, 초당 `refill_per_second` 개씩 회복)
+        TOKEN_BUCKET { capacity : u32, refill_per_second : f64 },
+        /// Sliding Window (최근 `window` 동안 최대 `max_requests` 개 요청 허용)
+        SLIDING_WINDOW { max_requests : u32, window : Duration }
+    }
+
+    /// 클라이언트 IP 별 접속 수 및 요청 수 제한기
+    ///
+    /// 제한을 설정하지 않으면 아무 요청도 거부하지 않는다.
+    /// Unix Domain Socket 접속은 모두 같은 주소로 보이므로 (로컬 Proxy 등) 제한하지 않는다.
+    pub struct RateLimiter {
+        policy : Option<RateLimitPolicy>,
+        max_connections_per_ip : Option<usize>,
+        clients : Mutex<Clients>
+    }
+
+    /// 클라이언트 기록
+    struct Clients {
+        entries : HashMap<String, ClientEntry>,
+        last_cleanup : Instant
+    }
+
+    /// 클라이언트 하나의 접속 수와 요청 기록
+    struct ClientEntry {
+        connections : usize,
+        tokens : f64,
+        last_refill : Instant,
+        requests : VecDeque<Instant>
+    }
+
+
+    impl RateLimiter {
+        /// 요청 수 제한기 생성
+        ///
+        /// # Argument
+        /// policy : 요청 수 제한 방식 (None 이면 요청 수 제한 없음)
+        ///
+        /// max_connections_per_ip : IP 별 최대 동시 접속 수 (None 이면 제한 없음)
+        pub fn new(policy : Option<RateLimitPolicy>, max_connections_per_ip : Option<usize>) -> RateLimiter {
+            return RateLimiter {
+                policy,
+                max_connections_per_ip,
+                clients: Mutex::new(Clients {
+                    entries: HashMap::new(),
+                    last_cleanup: Instant::now()
+                })
+            };
+        }
+
+
+        /// 서버 설정으로 요청 수 제한기 생성
+        pub fn from_config(config : &ServerConfig) -> RateLimiter {
+            return RateLimiter::new(config.rate_limit.clone(), config.max_connections_per_ip);
+        }
+
+
+        /// 제한 설정 여부
+        pub fn is_enabled(&self) -> bool {
+            return self.policy.is_some() || self.max_connections_per_ip.is_some();
+        }
+
+
+        /// 새 접속 등록
+        ///
+        /// # Return
+        /// 등록에 성공하면 true, IP 별 최대 동시 접속 수를 초과하면 false
+        pub fn acquire_connection(&self, client_ip : &str) -> bool {
+            if !self.is_enabled() || client_ip == UNIX_CLIENT_ADDRESS {
+                return true;
+            }
+
+            let now : Instant = Instant::now();
+            let mut clients = self.clients.lock().unwrap_or_else(|error| error.into_inner());
+            let entry : &mut ClientEntry = clients.entry(client_ip, &self.policy, now);
+            if let Some(max_connections) = self.max_connections_per_ip {
+                if entry.connections >= max_connections {
+                    return false;
+                }
+            }
+            entry.connections += 1;
+
+            return true;
+        }
+
+
+        /// 종료된 접속 해제 (`acquire_connection` 에 성공한 접속마다 한 번 호출)
+        pub fn release_connection(&self, client_ip : &str) {
+            if !self.is_enabled() || client_ip == UNIX_CLIENT_ADDRESS {
+                return;
+            }
+
+            let mut clients = self.clients.lock().unwrap_or_else(|error| error.into_inner());
+            if let Some(entry) = clients.entries.get_mut(client_ip) {
+                entry.connections = entry.connections.saturating_sub(1);
+            }
+        }
+
+
+        /// 요청 허용 여부 확인
+        ///
+        /// # Return
+        /// 허용되면 Ok, 제한을 초과하면 다시 요청할 수 있을 때까지 남은 시간
+        pub fn check_request(&self, client_ip : &str) -> Result<(), Duration> {
+            let policy : &RateLimitPolicy = match &self.policy {
+                Some(policy) => policy,
+                None => return Ok(())
+            };
+            if client_ip == UNIX_CLIENT_ADDRESS {
+                return Ok(());
+            }
+
+            let now : Instant = Instant::now();
+            let mut clients = self.clients.lock().unwrap_or_else(|error| error.into_inner());
+            let entry : &mut ClientEntry = clients.entry(client_ip, &self.policy, now);
+
+            return match policy {
+                RateLimitPolicy::TOKEN_BUCKET { capacity, refill_per_second } => {
+                    entry.refill(*capacity, *refill_per_second, now);
+                    if entry.tokens >= 1.0 {
+                        entry.tokens -= 1.0;
+                        Ok(())
+                    }else {
+                        Err(Duration::try_from_secs_f64((1.0 - entry.tokens) / refill_per_second).unwrap_or(CLEANUP_INTERVAL))
+                    }
+                },
+                RateLimitPolicy::SLIDING_WINDOW { max_requests, window } => {
+                    while let Some(oldest) = entry.requests.front() {
+                        if now.duration_since(*oldest) < *window {
+                            break;
+                        }
+                        entry.requests.pop_front();
+                    }
+                    if entry.requests.len() < *max_requests as usize {
+                        entry.requests.push_back(now);
+                        Ok(())
+                    }else {
+                        let oldest : Instant = entry.requests.front().copied().unwrap_or(now);
+                        Err(window.saturating_sub(now.duration_since(oldest)))
+                    }
+                }
+            };
+        }
+    }
+
+
+    impl Clients {
+        /// 클라이언트 기록 반환 (없으면 생성)
+        fn entry(&mut self, client_ip : &str, policy : &Option<RateLimitPolicy>, now : Instant) -> &mut ClientEntry {
+            if now.duration_since(self.last_cleanup) >= CLEANUP_INTERVAL {
+                self.cleanup(policy, now);
+            }
+
+            return self.entries.entry(String::from(client_ip)).or_insert_with(|| ClientEntry {
+                connections: 0,
+                tokens: match policy {
+                    Some(RateLimitPolicy::TOKEN_BUCKET { capacity, .. }) => *capacity as f64,
+                    _ => 0.0
+                },
+                last_refill: now,
+                requests: VecDeque::new()
+            });
+        }
+
+
+        /// 접속이 없고 제한 상태가 초기값으로 돌아간 클라이언트 기록 삭제
+        fn cleanup(&mut self, policy : &Option<RateLimitPolicy>, now : Instant) {
+            self.entries.retain(|_, entry| {
+                if entry.connections > 0 {
+                    return true;
+                }
+                return match policy {
+                    Some(RateLimitPolicy::TOKEN_BUCKET { capacity, refill_per_second }) => {
+                        entry.refill(*capacity, *refill_per_second, now);
+                        entry.tokens < *capacity as f64
+                    },
+                    Some(RateLimitPolicy::SLIDING_WINDOW { window, .. }) => {
+                        entry.requests.back().is_some_and(|last| now.duration_since(*last) < *window)
+                    },
+                    None => false
+                };
+            });
+            self.last_cleanup = now;
+        }
+    }
+
+
+    impl ClientEntry {
+        /// 지난 시간만큼 Token 회복
+        fn refill(&mut self, capacity : u32, refill_per_second : f64, now : Instant) {
+            let elapsed : f64 = now.duration_since(self.last_refill).as_secs_f64();
+            self.tokens = (self.tokens + elapsed * refill_per_second).min(capacity as f64);
+            self.last_refill = now;
+        }
+    }
+
+
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+
+        #[test]
+        fn token_bucket_allows_bursts_and_refills() {
+            let limiter : RateLimiter = RateLimiter::new(Some(RateLimitPolicy::TOKEN_BUCKET { capacity: 3, refill_per_second: 10.0 }), None);
+
+            for _ in 0..3 {
+                assert!(limiter.check_request("10.0.0.1").is_ok());
+            }
+            // Token 하나가 회복될 때까지 남은 시간
+            let retry_after : Duration = limiter.check_request("10.0.0.1").unwrap_err();
+            assert!(retry_after > Duration::ZERO && retry_after <= Duration::from_millis(100));
+            // 다른 IP 는 따로 계산
+            assert!(limiter.check_request("10.0.0.2").is_ok());
+
+            std::thread::sleep(retry_after + Duration::from_millis(20));
+            assert!(limiter.check_request("10.0.0.1").is_ok());
+            assert!(limiter.check_request("10.0.0.1").is_err());
+        }
+
+
+        #[test]
+        fn sliding_window_counts_requests_in_the_window() {
+            let limiter : RateLimiter = RateLimiter::new(Some(RateLimitPolicy::SLIDING_WINDOW { max_requests: 2, window: Duration::from_millis(200) }), None);
+
+            assert!(limiter.check_request("10.0.0.1").is_ok());
+            assert!(limiter.check_request("10.0.0.1").is_ok());
+            // 가장 오래된 요청이 Window 를 벗어날 때까지 남은 시간
+            let retry_after : Duration = limiter.check_request("10.0.0.1").unwrap_err();
+            assert!(retry_after > Duration::ZERO && retry_after <= Duration::from_millis(200));
+
+            std::thread::sleep(retry_after + Duration::from_millis(20));
+            assert!(limiter.check_request("10.0.0.1").is_ok());
+        }
+
+
+        #[test]
+        fn limits_concurrent_connections_per_ip() {
+            let limiter : RateLimiter = RateLimiter::new(None, Some(2));
+
+            assert!(limiter.acquire_connection("10.0.0.1"));
+            assert!(limiter.acquire_connection("10.0.0.1"));
+            assert!(!limiter.acquire_connection("10.0.0.1"));
+            assert!(limiter.acquire_connection("10.0.0.2"));
+            // 접속을 닫으면 다시 허용
+            limiter.release_connection("10.0.0.1");
+            assert!(limiter.acquire_connection("10.0.0.1"));
+            // 요청 수 제한은 설정하지 않았다
+            assert!(limiter.check_request("10.0.0.1").is_ok());
+        }
+
+
+        #[test]
+        fn never_limits_unix_socket_clients() {
+            let limiter : RateLimiter = RateLimiter::new(Some(RateLimitPolicy::SLIDING_WINDOW { max_requests: 1, window: Duration::from_secs(60) }), Some(1));
+
+            for _ in 0..3 {
+                assert!(limiter.acquire_connection(UNIX_CLIENT_ADDRESS));
+                assert!(limiter.check_request(UNIX_CLIENT_ADDRESS).is_ok());
+            }
+        }
+    }
+}
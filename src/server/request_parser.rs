@@ -1,7 +1,10 @@
 pub mod request_parser {
     use std::collections::HashMap;
+    use std::sync::Arc;
     use urlencoding::decode;
     use crate::log::{log_text_writer, LogTypeTag};
+    use crate::server::page_manager::page_manager::AllPages;
+    use crate::server::server_config::server_config::ServerConfig;
 
 
     /// 현재 파일 정보 반환
@@ -10,13 +13,13 @@ pub mod request_parser {
     }
 
     /// 요청 Method
-    #[derive(PartialEq)]
+    #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
     pub enum Method {
-        GET, POST, NOT_SUPPORTED
+        GET, HEAD, POST, PUT, DELETE, PATCH, OPTIONS, NOT_SUPPORTED
     }
 
     /// HTTP 버전
-    #[derive(PartialEq)]
+    #[derive(PartialEq, Clone, Copy, Debug)]
     pub enum HttpVersion {
         HTTP_1_0,
         HTTP_1_1,
@@ -25,6 +28,7 @@ pub mod request_parser {
     }
 
     /// Request 데이터
+    #[derive(Clone)]
     pub struct Request {
         pub method : Option<Method>,
         pub target : Option<String>,
@@ -33,7 +37,106 @@ pub mod request_parser {
         pub http_header : Option<HashMap<String, String>>,
         pub cookies: Option<HashMap<String, String>>,
         pub params: Option<HashMap<String, String>>,
-        pub body: Option<String>
+        pub body: Option<String>,
+        /// 요청을 처리하는 서버의 페이지 리스트 (서버가 설정)
+        pub pages: Option<Arc<AllPages>>,
+        /// 요청을 처리하는 서버의 설정 (서버가 설정)
+        pub config: Option<Arc<ServerConfig>>,
+        /// 클라이언트 IP 주소 (서버가 설정, PROXY protocol 과 신뢰할 수 있는 Proxy 의 Header 반영)
+        pub client_ip: Option<String>,
+        /// Router 경로 패턴에서 추출한 Parameter (서버가 설정, 예: `/users/:id` 의 `id`)
+        pub path_params: Option<HashMap<String, String>>
+    }
+
+
+    impl Method {
+        /// Method 이름 반환 (예: `GET`)
+        pub fn as_str(&self) -> &'static str {
+            return match self {
+                Method::GET => "GET",
+                Method::HEAD => "HEAD",
+                Method::POST => "POST",
+                Method::PUT => "PUT",
+                Method::DELETE => "DELETE",
+                Method::PATCH => "PATCH",
+                Method::OPTIONS => "OPTIONS",
+                Method::NOT_SUPPORTED => "NOT_SUPPORTED"
+            };
+        }
+    }
+
+
+    impl Request {
+        /// Header 값 반환 (Header 이름 대소문자 구분 없음)
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use AthenaEngine::server::request_parser::request_parser::request_parser;
+        /// # let request = request_parser(&vec!["POST / HTTP/1.1", "Content-Type: text/plain", ""]);
+        /// request.header("content-type")
+        /// # ;
+        /// ```
+        pub fn header(&self, name : &str) -> Option<&String> {
+            return match &self.http_header {
+                Some(headers) => headers.iter()
+                    .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                    .map(|(_, value)| value),
+                None => None
+            };
+        }
+
+
+        /// Router 경로 Parameter 반환
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use AthenaEngine::server::request_parser::request_parser::request_parser;
+        /// # use std::collections::HashMap;
+        /// # let mut request = request_parser(&vec!["GET /users/42 HTTP/1.1", ""]);
+        /// # request.path_params = Some(HashMap::from([(String::from("id"), String::from("42"))]));
+        /// // `/users/:id` 로 등록한 Route 에 `/users/42` 요청
+        /// request.path_param("id") // Some("42")
+        /// # ;
+        /// ```
+        pub fn path_param(&self, name : &str) -> Option<&str> {
+            return self.path_params.as_ref()?.get(name).map(|value| value.as_str());
+        }
+
+
+        /// 요청 경로 반환 (Query String 제외)
+        pub fn path(&self) -> Option<&str> {
+            return self.target.as_deref().map(|target| target.split('?').next().unwrap_or(target));
+        }
+
+
+        /// Server-Sent Events 재접속 요청의 마지막 이벤트 ID 반환 (`Last-Event-ID` Header)
+        ///
+        /// 클라이언트가 받은 마지막 이벤트 다음부터 이어서 보낼 때 사용한다.
+        pub fn last_event_id(&self) -> Option<&String> {
+            return self.header("Last-Event-ID");
+        }
+
+
+        /// 요청 처리 후 접속을 유지해야 하는지 여부
+        ///
+        /// HTTP/1.1 은 `Connection: close` 가 없으면 유지하고, HTTP/1.0 은 `Connection: keep-alive` 가 있을 때만 유지한다.
+        pub fn is_keep_alive(&self) -> bool {
+            let connection : Option<String> = self.header("Connection").map(|value| value.to_lowercase());
+            let has_token = |token : &str| -> bool {
+                return match &connection {
+                    Some(value) => value.split(',').any(|item| item.trim() == token),
+                    None => false
+                };
+            };
+
+            return match &self.http_version {
+                Some(HttpVersion::HTTP_1_1) => !has_token("close"),
+                Some(HttpVersion::HTTP_1_0) => has_token("keep-alive"),
+                _ => false
+            };
+        }
     }
 
 
@@ -42,7 +145,10 @@ pub mod request_parser {
     /// # Examples
     ///
     /// ```
-    /// request_parser(http_packet)
+    /// # use AthenaEngine::server::request_parser::request_parser::request_parser;
+    /// # let http_packet : Vec<&str> = vec!["GET /hello.html HTTP/1.1", "Host: localhost", ""];
+    /// request_parser(&http_packet)
+    /// # ;
     /// ```
     ///
     /// # Argument
@@ -60,14 +166,15 @@ pub mod request_parser {
             http_header: None,
             cookies: None,
             params: None,
-            body: None
+            body: None,
+            pages: None,
+            config: None,
+            client_ip: None,
+            path_params: None
         };
 
         // Header 길이 확인
-        if packet.len() >= 1 {
-            // Header 추출 기본 정보
-            let tag_cookie : String = String::from("Cookie: ");
-            let tag_host : String = String::from("Host: ");
+        if !packet.is_empty() {
             // Header 추출 데이터
             let mut url : Option<String> = None;
             let mut headers_hashmap: HashMap<String, String> = HashMap::new();
@@ -88,8 +195,8 @@ pub mod request_parser {
             let mut is_write_body : bool = false;
             // Body string 데이터
             let mut body_data : String = String::new();
-            // Header 추출
-            for line in packet {
+            // Header 추출 (첫 줄은 요청 Line)
+            for line in packet.iter().skip(1) {
                 if is_check_body_line {
                     is_write_body = true;
 
@@ -97,39 +204,25 @@ pub mod request_parser {
                     body_data.push_str("\r\n");
                 }else {
                     // Body 구분 라인 확인
-                    if line.replace(" ", "").len() == 0 {
+                    if line.replace(" ", "").is_empty() {
                         // Body 구분 변수 설정
                         is_check_body_line = true;
-                    }else {
-                        if line.to_lowercase().contains(&tag_host.to_lowercase()) || line.to_lowercase().contains(&tag_cookie.to_lowercase()) {
-                            if line.to_lowercase().contains(&tag_cookie.to_lowercase()) { // Request Header : Cookie
-                                let temp_split : Vec<&str> = line.split(": ").collect();
-                                let cookies : String = line.replace(&format!("{}: ", temp_split[0]), "");
-                                if cookies.contains(",") {
-                                    let cookies_root_split : Vec<&str> = cookies.trim().split(",").collect();
-                                    for cookie in cookies_root_split {
-                                        if cookie.contains("=") {
-                                            let cookie_split: Vec<&str> = cookie.split("=").collect();
-                                            if cookie_split.len() == 2 {
-                                                cookies_hashmap.insert(cookie_split[0].to_string(), cookie_split[1].to_string());
-                                            }
-                                        }
-                                    }
+                    }else if let Some((header_name, header_value)) = line.split_once(':') {
+                        // Header 이름은 정확히 비교 (`X-Forwarded-Host` 등 이름에 포함된 경우 제외)
+                        let header_name : &str = header_name.trim();
+                        let header_value : &str = header_value.trim();
+                        if header_name.eq_ignore_ascii_case("Cookie") { // Request Header : Cookie
+                            // `;` 로 구분 (이전 클라이언트의 `,` 구분도 허용)
+                            for cookie in header_value.split([';', ',']) {
+                                if let Some((name, value)) = cookie.split_once("=") {
+                                    cookies_hashmap.insert(name.trim().to_string(), value.trim().to_string());
                                 }
-                            }else if line.to_lowercase().contains(&tag_host.to_lowercase()) { // Request Header : Host
-                                let temp_split : Vec<&str> = line.split(": ").collect();
-                                request.host = Option::from(line.replace(&format!("{}: ", temp_split[0]), "").trim().to_string());
                             }
-                        }else {
-                            if line.contains(": ") { // Request Header : Other header
-                                let mut header_split : Vec<&str> = line.trim().split(": ").collect();
-                                if header_split.len() >= 2 {
-                                    let header_name : String = String::from(header_split[0]);
-                                    let header_value : String = String::from(line.replace(&format!("{}: ", &header_name), ""));
-
-                                    headers_hashmap.insert(header_name, header_value);
-                                }
+                        }else { // Request Header : Host, Other header
+                            if header_name.eq_ignore_ascii_case("Host") {
+                                request.host = Option::from(header_value.to_string());
                             }
+                            headers_hashmap.insert(header_name.to_string(), header_value.to_string());
                         }
                     }
                 }
@@ -141,14 +234,14 @@ pub mod request_parser {
                     let tag_params_root : String = String::from("?");
                     let tag_params_more : String = String::from("&");
                     let tag_params_value : String = String::from("=");
-                    let mut url_full : String = std;
+                    let url_full : String = std;
                     if url_full.contains(&tag_params_root) && url_full.contains(&tag_params_value) {
-                        let mut params_full : Vec<&str> = url_full.split(&tag_params_root).collect();
-                        let mut params_full : String = params_full[1].to_string();
+                        let params_full : Vec<&str> = url_full.split(&tag_params_root).collect();
+                        let params_full : String = params_full[1].to_string();
                         if params_full.contains(&tag_params_more) {
-                            let mut params_root_split : Vec<&str> = params_full.split(&tag_params_more).collect();
+                            let params_root_split : Vec<&str> = params_full.split(&tag_params_more).collect();
                             for params_ket_value in params_root_split {
-                                let mut params_key_value_split : Vec<&str> = params_ket_value.split(&tag_params_value).collect();
+                                let params_key_value_split : Vec<&str> = params_ket_value.split(&tag_params_value).collect();
                                 if params_key_value_split.len() == 2 {
                                     let decoded = decode(params_key_value_split[1]);
                                     match decoded {
@@ -163,7 +256,7 @@ pub mod request_parser {
                                 }
                             }
                         }else if params_full.contains(&tag_params_value) {
-                            let mut params_root_split : Vec<&str> = params_full.split(&tag_params_value).collect();
+                            let params_root_split : Vec<&str> = params_full.split(&tag_params_value).collect();
                             if params_root_split.len() == 2 {
                                 let decoded = decode(params_root_split[1]);
                                 match decoded {
@@ -207,7 +300,12 @@ pub mod request_parser {
     pub fn method_classify(input : &str) -> Method {
         return match input {
             "GET" => Method::GET,
+            "HEAD" => Method::HEAD,
             "POST" => Method::POST,
+            "PUT" => Method::PUT,
+            "DELETE" => Method::DELETE,
+            "PATCH" => Method::PATCH,
+            "OPTIONS" => Method::OPTIONS,
             _ => Method::NOT_SUPPORTED
         }
     }
@@ -233,4 +331,42 @@ pub mod request_parser {
             _ => "HTTP/1.1".to_string()
         }
     }
+
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+
+        fn parse(head : &str) -> Request {
+            let lines : Vec<&str> = head.split("\r\n").collect();
+            return request_parser(&lines);
+        }
+
+
+        #[test]
+        fn reads_host_only_from_the_host_header() {
+            let request : Request = parse("GET / HTTP/1.1\r\nX-Forwarded-Host: evil.example\r\nHost: site.example\r\nX-Host: other.example");
+
+            assert_eq!(request.host.as_deref(), Some("site.example"));
+            let headers : HashMap<String, String> = request.http_header.unwrap();
+            assert_eq!(headers.get("Host").map(String::as_str), Some("site.example"));
+            assert_eq!(headers.get("X-Forwarded-Host").map(String::as_str), Some("evil.example"));
+
+            let request : Request = parse("GET / HTTP/1.1\r\nX-Forwarded-Host: evil.example");
+            assert_eq!(request.host, None);
+        }
+
+
+        #[test]
+        fn reads_cookies_only_from_the_cookie_header() {
+            let request : Request = parse("GET http://site.example:8080/ HTTP/1.1\r\nX-Cookie: fake=1\r\nCookie: session=abc; theme=dark");
+
+            let cookies : HashMap<String, String> = request.cookies.unwrap();
+            assert_eq!(cookies.get("session").map(String::as_str), Some("abc"));
+            assert_eq!(cookies.get("theme").map(String::as_str), Some("dark"));
+            assert!(!cookies.contains_key("fake"));
+            assert!(!request.http_header.unwrap().keys().any(|name| name.starts_with("GET")));
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,832 @@
+pub mod request_reader {
+    use std::io::Read;
+    use std::time::{Duration, Instant};
+    use crate::server::request_parser::request_parser::{Request, request_parser};
+    use crate::server::response_parser::response_parser::{default_http_state_writer, HttpStateCode};
+
+
+    /// Header 종료 구분자
+    const HEADER_TERMINATOR : &[u8] = b"\r\n\r\n";
+
+    /// 한 번의 `read` 호출로 읽을 최대 크기
+    const READ_CHUNK_SIZE : usize = 4096;
+
+    /// Chunk 크기 Line 의 최대 길이 (Chunk 확장 포함)
+    const MAX_CHUNK_SIZE_LINE : usize = 1024;
+
+    /// 최소 Body 전송 속도 검사 전 유예 시간
+    const BODY_RATE_GRACE_PERIOD : Duration = Duration::from_secs(5);
+
+
+    /// Request 읽기 실패 사유 Enum
+    #[derive(Debug)]
+    pub enum RequestReadError {
+        /// 요청 데이터를 받기 전에 접속이 종료됨
+        CONNECTION_CLOSED,
+        /// 요청 데이터를 받는 도중 접속이 종료됨
+        INCOMPLETE,
+        /// Stream 읽기 오류 (Timeout 포함)
+        IO(std::io::Error),
+        /// HTTP/1 요청 Line 에 HTTP/2 이상의 버전 사용 (HTTP/2 는 전환 또는 Preface 로만 시작)
+        VERSION_NOT_SUPPORTED,
+        /// Header 크기 제한 초과
+        HEADER_TOO_LARGE,
+        /// Body 크기 제한 초과
+        BODY_TOO_LARGE,
+        /// 잘못된 요청 (Content-Length 오류, 잘못된 Chunk 형식, Content-Length 와 Transfer-Encoding 동시 사용 등)
+        BAD_REQUEST,
+        /// 요청을 제한 시간 안에 받지 못함 (Header 수신 시간 초과, 최소 Body 전송 속도 미달)
+        REQUEST_TIMEOUT,
+        /// 처리할 수 없는 `Expect` Header
+        EXPECTATION_FAILED,
+        /// `Expect: 100-continue` 요청을 Body 를 받기 전에 거부함
+        REJECTED(HttpStateCode)
+    }
+
+    /// 읽어 들인 HTTP 요청 원본
+    pub struct RawRequest {
+        /// 요청 Line 과 Header (마지막 빈 줄 제외)
+        pub head : String,
+        /// 요청 Body
+        pub body : Vec<u8>
+    }
+
+    /// 요청 읽기 진행 상태
+    enum ReadState {
+        HEAD,
+        BODY { head : String, remaining : usize },
+        CHUNK_SIZE { head : String, body : Vec<u8> },
+        CHUNK_DATA { head : String, body : Vec<u8>, remaining : usize },
+        CHUNK_DATA_END { head : String, body : Vec<u8> },
+        TRAILER { head : String, body : Vec<u8> }
+    }
+
+    /// Body 길이 결정 방식
+    enum BodyLength {
+        CONTENT_LENGTH(usize),
+        CHUNKED
+    }
+
+    /// HTTP 요청 Reader
+    ///
+    /// Header 종료 구분자를 받을 때까지 읽은 뒤 `Content-Length` 만큼 Body 를 읽는다.
+    /// `Transfer-Encoding: chunked` 요청은 Chunk 를 해제하고 Trailer 는 Header 에 추가한다.
+    /// 다음 요청의 데이터가 함께 도착한 경우 내부 Buffer 에 남겨두었다가 다음 호출에서 사용한다.
+    pub struct RequestReader {
+        buffer : Vec<u8>,
+        state : ReadState,
+        max_header_bytes : usize,
+        max_body_bytes : usize,
+        header_timeout : Option<Duration>,
+        min_body_bytes_per_second : usize,
+        /// 현재 요청의 첫 데이터를 받은 시간
+        head_started : Option<Instant>,
+        /// 현재 요청의 Body 를 받기 시작한 시간
+        body_started : Option<Instant>,
+        /// Body 를 받기 시작한 뒤 받은 데이터 크기
+        body_received : usize,
+        /// `Expect: 100-continue` 요청의 Body 를 받기 전 응답 여부 결정 대기
+        expect_continue : bool
+    }
+
+
+    impl RequestReadError {
+        /// 클라이언트에게 응답할 HTTP 상태 코드 반환 (응답 없이 접속을 닫아야 하는 경우 None)
+        pub fn http_state_code(&self) -> Option<HttpStateCode> {
+            return match self {
+                RequestReadError::HEADER_TOO_LARGE => Some(HttpStateCode::HTTP_431),
+                RequestReadError::BODY_TOO_LARGE => Some(HttpStateCode::HTTP_413),
+                RequestReadError::BAD_REQUEST => Some(HttpStateCode::HTTP_400),
+                RequestReadError::VERSION_NOT_SUPPORTED => Some(HttpStateCode::HTTP_505),
+                RequestReadError::REQUEST_TIMEOUT => Some(HttpStateCode::HTTP_408),
+                RequestReadError::EXPECTATION_FAILED => Some(HttpStateCode::HTTP_417),
+                RequestReadError::REJECTED(http_code) => Some(*http_code),
+                _ => None
+            };
+        }
+    }
+
+
+    impl std::fmt::Display for RequestReadError {
+        fn fmt(&self, f : &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            return match self {
+                RequestReadError::CONNECTION_CLOSED => write!(f, "Connection closed by client."),
+                RequestReadError::INCOMPLETE => write!(f, "Connection closed before the request was complete."),
+                RequestReadError::IO(error) => write!(f, "{}", error),
+                RequestReadError::HEADER_TOO_LARGE => write!(f, "Request header is too large."),
+                RequestReadError::BODY_TOO_LARGE => write!(f, "Request body is too large."),
+                RequestReadError::BAD_REQUEST => write!(f, "Malformed request header."),
+                RequestReadError::VERSION_NOT_SUPPORTED => write!(f, "HTTP version is not supported on an HTTP/1 connection."),
+                RequestReadError::REQUEST_TIMEOUT => write!(f, "Client was too slow to send the request, connection dropped."),
+                RequestReadError::EXPECTATION_FAILED => write!(f, "Unsupported Expect header."),
+                RequestReadError::REJECTED(http_code) => write!(f, "Request body rejected before upload. ({})", default_http_state_writer(http_code))
+            };
+        }
+    }
+
+
+    impl RawRequest {
+        /// Request 구조체로 변환
+        pub fn to_request(&self) -> Request {
+            let packet : Vec<&str> = self.head.split("\r\n").collect();
+            let mut request : Request = request_parser(&packet);
+
+            if !self.body.is_empty() {
+                request.body = Some(String::from_utf8_lossy(&self.body).to_string());
+            }
+
+            return request;
+        }
+    }
+
+
+    impl RequestReader {
+        /// Request Reader 생성
+        ///
+        /// # Argument
+        /// max_header_bytes : 요청 Line 과 Header 의 최대 크기
+        ///
+        /// max_body_bytes : Body 의 최대 크기
+        pub fn new(max_header_bytes : usize, max_body_bytes : usize) -> RequestReader {
+            return RequestReader {
+                buffer: Vec::new(),
+                state: ReadState::HEAD,
+                max_header_bytes,
+                max_body_bytes,
+                header_timeout: None,
+                min_body_bytes_per_second: 0,
+                head_started: None,
+                body_started: None,
+                body_received: 0,
+                expect_continue: false
+            };
+        }
+
+
+        /// 느린 클라이언트 (Slowloris) 제한 설정
+        ///
+        /// # Argument
+        /// header_timeout : 요청의 첫 데이터를 받은 뒤 Header 를 모두 받을 때까지의 제한 시간
+        ///
+        /// min_body_bytes_per_second : 최소 Body 전송 속도 (0 이면 제한 없음, 처음 5초는 검사하지 않는다)
+        pub fn with_deadlines(mut self, header_timeout : Option<Duration>, min_body_bytes_per_second : usize) -> RequestReader {
+            self.header_timeout = header_timeout;
+            self.min_body_bytes_per_second = min_body_bytes_per_second;
+            return self;
+        }
+
+
+        /// 읽어 들인 데이터를 내부 Buffer 에 추가
+        pub fn feed(&mut self, data : &[u8]) {
+            if data.is_empty() {
+                return;
+            }
+            if self.head_started.is_none() {
+                self.head_started = Some(Instant::now());
+            }
+            if self.body_started.is_some() {
+                self.body_received += data.len();
+            }
+            self.buffer.extend_from_slice(data);
+        }
+
+
+        /// 현재 요청을 받을 수 있는 남은 시간
+        ///
+        /// # Return
+        /// 제한 시간이 없으면 None, 이미 초과했으면 `RequestReadError::REQUEST_TIMEOUT`
+        pub fn time_left(&self, now : Instant) -> Result<Option<Duration>, RequestReadError> {
+            let deadline : Option<Instant> = match (self.body_started, self.head_started) {
+                (Some(body_started), _) => {
+                    if self.min_body_bytes_per_second == 0 {
+                        None
+                    }else {
+                        // 받은 데이터 크기만큼 제한 시간 연장
+                        let earned : f64 = self.body_received as f64 / self.min_body_bytes_per_second as f64;
+                        Some(body_started + BODY_RATE_GRACE_PERIOD + Duration::from_secs_f64(earned))
+                    }
+                },
+                (None, Some(head_started)) => self.header_timeout.map(|timeout| head_started + timeout),
+                (None, None) => None
+            };
+
+            return match deadline {
+                Some(deadline) if now >= deadline => Err(RequestReadError::REQUEST_TIMEOUT),
+                Some(deadline) => Ok(Some(deadline - now)),
+                None => Ok(None)
+            };
+        }
+
+
+        /// 처리되지 않은 데이터가 Buffer 에 남아 있는지 여부
+        pub fn has_buffered_data(&self) -> bool {
+            return !self.buffer.is_empty();
+        }
+
+
+        /// Buffer 에 남은 데이터 꺼내기 (Upgrade 된 접속으로 넘길 때 사용)
+        pub fn take_buffered_data(&mut self) -> Vec<u8> {
+            return std::mem::take(&mut self.buffer);
+        }
+
+
+        /// Buffer 의 데이터로 요청 하나를 완성할 수 있으면 반환
+        ///
+        /// # Return
+        /// 요청이 완성되면 Some, 데이터가 더 필요하면 None
+        pub fn poll_request(&mut self) -> Result<Option<RawRequest>, RequestReadError> {
+            loop {
+                match std::mem::replace(&mut self.state, ReadState::HEAD) {
+                    ReadState::HEAD => {
+                        // 요청 사이의 빈 줄 무시
+                        while self.buffer.starts_with(b"\r\n") {
+                            self.buffer.drain(..2);
+                        }
+
+                        let header_end = match find_bytes(&self.buffer, HEADER_TERMINATOR) {
+                            Some(index) => index,
+                            None => {
+                                if self.buffer.len() > self.max_header_bytes {
+                                    return Err(RequestReadError::HEADER_TOO_LARGE);
+                                }
+                                return Ok(None);
+                            }
+                        };
+                        if header_end + HEADER_TERMINATOR.len() > self.max_header_bytes {
+                            return Err(RequestReadError::HEADER_TOO_LARGE);
+                        }
+
+                        let head : String = String::from_utf8_lossy(&self.buffer[..header_end]).to_string();
+                        self.buffer.drain(..header_end + HEADER_TERMINATOR.len());
+                        if !is_http1_version(&head) {
+                            return Err(RequestReadError::VERSION_NOT_SUPPORTED);
+                        }
+                        self.body_started = Some(Instant::now());
+                        self.body_received = self.buffer.len();
+
+                        validate_header(&head)?;
+                        let body_length : BodyLength = body_length(&head)?;
+                        if let BodyLength::CONTENT_LENGTH(content_length) = body_length {
+                            if content_length > self.max_body_bytes {
+                                return Err(RequestReadError::BODY_TOO_LARGE);
+                            }
+                        }
+                        // Body 가 있는 HTTP/1.1 요청만 100 Continue 대상
+                        let has_body : bool = !matches!(body_length, BodyLength::CONTENT_LENGTH(0));
+                        self.expect_continue = expect_continue(&head)? && has_body;
+
+                        self.state = match body_length {
+                            BodyLength::CONTENT_LENGTH(content_length) => ReadState::BODY { head, remaining: content_length },
+                            BodyLength::CHUNKED => ReadState::CHUNK_SIZE { head, body: Vec::new() }
+                        };
+                    },
+                    ReadState::BODY { head, remaining } => {
+                        if self.buffer.len() < remaining {
+                            self.state = ReadState::BODY { head, remaining };
+                            return Ok(None);
+                        }
+
+                        let body : Vec<u8> = self.buffer.drain(..remaining).collect();
+                        self.finish_request();
+
+                        return Ok(Some(RawRequest { head, body }));
+                    },
+                    ReadState::CHUNK_SIZE { head, body } => {
+                        let line_end = match find_bytes(&self.buffer, b"\r\n") {
+                            Some(index) => index,
+                            None => {
+                                if self.buffer.len() > MAX_CHUNK_SIZE_LINE {
+                                    return Err(RequestReadError::BAD_REQUEST);
+                                }
+                                self.state = ReadState::CHUNK_SIZE { head, body };
+                                return Ok(None);
+                            }
+                        };
+                        if line_end > MAX_CHUNK_SIZE_LINE {
+                            return Err(RequestReadError::BAD_REQUEST);
+                        }
+
+                        // Chunk 확장 (;name=value) 무시
+                        let line : String = String::from_utf8_lossy(&self.buffer[..line_end]).to_string();
+                        let size_str : &str = line.split(';').next().unwrap_or("").trim();
+                        let size : usize = match usize::from_str_radix(size_str, 16) {
+                            Ok(size) if !size_str.is_empty() && !size_str.starts_with('+') => size,
+                            _ => return Err(RequestReadError::BAD_REQUEST)
+                        };
+                        self.buffer.drain(..line_end + 2);
+
+                        if size == 0 {
+                            self.state = ReadState::TRAILER { head, body };
+                        }else {
+                            if body.len().saturating_add(size) > self.max_body_bytes {
+                                return Err(RequestReadError::BODY_TOO_LARGE);
+                            }
+                            self.state = ReadState::CHUNK_DATA { head, body, remaining: size };
+                        }
+                    },
+                    ReadState::CHUNK_DATA { head, mut body, remaining } => {
+                        let size : usize = remaining.min(self.buffer.len());
+                        body.extend(self.buffer.drain(..size));
+
+                        if size < remaining {
+                            self.state = ReadState::CHUNK_DATA { head, body, remaining: remaining - size };
+                            return Ok(None);
+                        }
+                        self.state = ReadState::CHUNK_DATA_END { head, body };
+                    },
+                    ReadState::CHUNK_DATA_END { head, body } => {
+                        if self.buffer.len() < 2 {
+                            self.state = ReadState::CHUNK_DATA_END { head, body };
+                            return Ok(None);
+                        }
+                        if !self.buffer.starts_with(b"\r\n") {
+                            return Err(RequestReadError::BAD_REQUEST);
+                        }
+                        self.buffer.drain(..2);
+
+                        self.state = ReadState::CHUNK_SIZE { head, body };
+                    },
+                    ReadState::TRAILER { mut head, body } => {
+                        let line_end = match find_bytes(&self.buffer, b"\r\n") {
+                            Some(index) => index,
+                            None => {
+                                if head.len() + self.buffer.len() > self.max_header_bytes {
+                                    return Err(RequestReadError::HEADER_TOO_LARGE);
+                                }
+                                self.state = ReadState::TRAILER { head, body };
+                                return Ok(None);
+                            }
+                        };
+                        if head.len() + line_end > self.max_header_bytes {
+                            return Err(RequestReadError::HEADER_TOO_LARGE);
+                        }
+
+                        let line : String = String::from_utf8_lossy(&self.buffer[..line_end]).to_string();
+                        self.buffer.drain(..line_end + 2);
+
+                        // 빈 줄이면 요청 완료
+                        if line.is_empty() {
+                            self.finish_request();
+                            return Ok(Some(RawRequest { head, body }));
+                        }
+
+                        // 요청 경계나 라우팅에 영향을 주는 Header 는 Trailer 로 받지 않는다
+                        validate_header_line(&line)?;
+                        if let Some((name, _)) = line.split_once(':') {
+                            if !is_forbidden_trailer(name) {
+                                head.push_str("\r\n");
+                                head.push_str(&line);
+                            }
+                        }
+                        self.state = ReadState::TRAILER { head, body };
+                    }
+                }
+            }
+        }
+
+
+        /// Stream 에서 요청 하나를 읽기
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use AthenaEngine::server::request_reader::request_reader::{RequestReadError, RequestReader};
+        /// # fn main() -> Result<(), RequestReadError> {
+        /// # let mut reader = RequestReader::new(8 * 1024, 1024 * 1024);
+        /// # let mut tcp_stream : &[u8] = b"GET / HTTP/1.1\r\nHost: localhost\r\n\r\n";
+        /// let raw_request = reader.read_request(&mut tcp_stream)?;
+        /// # Ok(())
+        /// # }
+        /// ```
+        ///
+        /// # Argument
+        /// stream : 요청을 읽을 Stream
+        ///
+        /// # Return
+        /// RawRequest 구조체
+        pub fn read_request<R : Read>(&mut self, stream : &mut R) -> Result<RawRequest, RequestReadError> {
+            let mut chunk = [0; READ_CHUNK_SIZE];
+
+            loop {
+                if let Some(raw_request) = self.poll_request()? {
+                    return Ok(raw_request);
+                }
+
+                match stream.read(&mut chunk) {
+                    Ok(0) => return Err(self.end_of_stream_error()),
+                    Ok(size) => {
+                        self.feed(&chunk[..size]);
+                        self.time_left(Instant::now())?;
+                    },
+                    Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {},
+                    Err(error) => return Err(self.read_error(error))
+                }
+            }
+        }
+
+
+        /// Stream 에서 한 번 읽어 내부 Buffer 에 추가 (Keep-Alive 접속의 다음 요청 대기에 사용)
+        ///
+        /// # Return
+        /// 읽은 Byte 수, 접속이 종료된 경우 `RequestReadError::CONNECTION_CLOSED`
+        pub fn fill_buffer<R : Read>(&mut self, stream : &mut R) -> Result<usize, RequestReadError> {
+            let mut chunk = [0; READ_CHUNK_SIZE];
+
+            loop {
+                match stream.read(&mut chunk) {
+                    Ok(0) => return Err(RequestReadError::CONNECTION_CLOSED),
+                    Ok(size) => {
+                        self.feed(&chunk[..size]);
+                        return Ok(size);
+                    },
+                    Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {},
+                    Err(error) => return Err(self.read_error(error))
+                }
+            }
+        }
+
+
+        /// `Expect: 100-continue` 요청의 Header 반환 (요청마다 한 번만 반환)
+        ///
+        /// Body 를 받기 전에 `100 Continue` 를 보낼지, 오류 응답으로 거부할지 결정하는 데 사용한다.
+        ///
+        /// # Return
+        /// Body 없이 Header 만 담은 RawRequest, 해당 요청이 아니면 None
+        pub fn take_expect_continue(&mut self) -> Option<RawRequest> {
+            if !std::mem::replace(&mut self.expect_continue, false) {
+                return None;
+            }
+
+            return match &self.state {
+                ReadState::BODY { head, .. } | ReadState::CHUNK_SIZE { head, .. } => Some(RawRequest {
+                    head: head.clone(),
+                    body: Vec::new()
+                }),
+                _ => None
+            };
+        }
+
+
+        /// 요청 하나를 완성한 뒤 제한 시간 초기화 (이미 받은 다음 요청 데이터가 있으면 지금부터 계산)
+        fn finish_request(&mut self) {
+            self.head_started = if self.buffer.is_empty() { None } else { Some(Instant::now()) };
+            self.body_started = None;
+            self.body_received = 0;
+            self.expect_continue = false;
+        }
+
+
+        /// Stream 읽기 오류 변환 (요청을 받는 도중의 Timeout 은 `REQUEST_TIMEOUT`)
+        pub fn read_error(&self, error : std::io::Error) -> RequestReadError {
+            let is_timeout : bool = matches!(error.kind(), std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut);
+            if is_timeout && !self.is_idle() {
+                return RequestReadError::REQUEST_TIMEOUT;
+            }
+            return RequestReadError::IO(error);
+        }
+
+
+        /// Stream 이 끝났을 때의 오류 반환
+        ///
+        /// 요청 사이에서 끝나면 `CONNECTION_CLOSED`, 요청 도중에 끝나면 `INCOMPLETE`
+        pub fn end_of_stream_error(&self) -> RequestReadError {
+            return if self.is_idle() {
+                RequestReadError::CONNECTION_CLOSED
+            }else {
+                RequestReadError::INCOMPLETE
+            };
+        }
+
+
+        /// 다음 요청의 데이터를 아직 받지 않은 상태인지 여부
+        pub fn is_idle(&self) -> bool {
+            return matches!(self.state, ReadState::HEAD) && self.buffer.is_empty();
+        }
+    }
+
+
+    /// 요청 Line 의 HTTP 버전이 HTTP/2 미만인지 확인 (버전을 알 수 없으면 요청 분석에서 처리)
+    ///
+    /// HTTP/2 Connection Preface (`PRI * HTTP/2.0`) 는 HTTP/2 전환에서 처리한다.
+    fn is_http1_version(head : &str) -> bool {
+        if head == "PRI * HTTP/2.0" {
+            return true;
+        }
+        let major : Option<u32> = head.lines().next()
+            .and_then(|line| line.split_whitespace().nth(2))
+            .and_then(|version| version.strip_prefix("HTTP/"))
+            .and_then(|version| version.split('.').next())
+            .and_then(|major| major.parse::<u32>().ok());
+
+        return match major {
+            Some(major) => major < 2,
+            None => true
+        };
+    }
+
+
+    /// 요청 Header 형식 확인
+    ///
+    /// Host 가 여러 개인 요청은 어느 값으로 라우팅할지 정할 수 없으므로 거부한다 (RFC 9112 3.2).
+    fn validate_header(head : &str) -> Result<(), RequestReadError> {
+        let mut has_host : bool = false;
+        for line in head.split("\r\n").skip(1) {
+            validate_header_line(line)?;
+            if line.split_once(':').is_some_and(|(name, _)| name.eq_ignore_ascii_case("Host")) {
+                if has_host {
+                    return Err(RequestReadError::BAD_REQUEST);
+                }
+                has_host = true;
+            }
+        }
+
+        return Ok(());
+    }
+
+
+    /// Header Line 형식 확인
+    ///
+    /// Field 이름과 `:` 사이의 공백이나 줄 이어쓰기 (obs-fold) 는 Header 이름을 다르게 해석하는 Proxy 와의
+    /// Request Smuggling 에 쓰일 수 있으므로 거부한다 (RFC 9112 5.1, 5.2).
+    fn validate_header_line(line : &str) -> Result<(), RequestReadError> {
+        if line.starts_with([' ', '\t']) {
+            return Err(RequestReadError::BAD_REQUEST);
+        }
+        return match line.split_once(':') {
+            Some((name, _)) if name.is_empty() || name.contains(|char : char| char.is_ascii_whitespace()) => Err(RequestReadError::BAD_REQUEST),
+            _ => Ok(())
+        };
+    }
+
+
+    /// Header 에서 Body 길이 결정 방식 추출
+    ///
+    /// Content-Length 와 Transfer-Encoding 을 함께 보낸 요청은 Request Smuggling 방지를 위해 거부한다.
+    fn body_length(head : &str) -> Result<BodyLength, RequestReadError> {
+        let mut content_length : Option<usize> = None;
+        let mut transfer_encoding : Option<String> = None;
+
+        for line in head.split("\r\n").skip(1) {
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("Content-Length") {
+                    // 10진수 숫자만 허용 (`+5`, `0x5`, 빈 값 거부)
+                    let value : &str = value.trim_matches([' ', '\t']);
+                    if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+                        return Err(RequestReadError::BAD_REQUEST);
+                    }
+                    let value : usize = match value.parse() {
+                        Ok(value) => value,
+                        Err(_) => return Err(RequestReadError::BAD_REQUEST)
+                    };
+                    // 서로 다른 Content-Length 가 여러 개인 경우 거부
+                    if content_length.is_some() && content_length != Some(value) {
+                        return Err(RequestReadError::BAD_REQUEST);
+                    }
+                    content_length = Some(value);
+                }else if name.eq_ignore_ascii_case("Transfer-Encoding") {
+                    // Encoding 이름 (Token) 목록만 허용
+                    let value : &str = value.trim_matches([' ', '\t']);
+                    if !value.split(',').all(|item| is_token(item.trim_matches([' ', '\t']))) {
+                        return Err(RequestReadError::BAD_REQUEST);
+                    }
+                    let encoding : &mut String = transfer_encoding.get_or_insert_with(String::new);
+                    if !encoding.is_empty() {
+                        encoding.push(',');
+                    }
+                    encoding.push_str(&value.to_lowercase());
+                }
+            }
+        }
+
+        return match (content_length, transfer_encoding) {
+            (None, None) => Ok(BodyLength::CONTENT_LENGTH(0)),
+            (Some(content_length), None) => Ok(BodyLength::CONTENT_LENGTH(content_length)),
+            (None, Some(encoding)) => {
+                // 마지막 Encoding 이 chunked 가 아니면 Body 길이를 알 수 없다
+                if encoding.split(',').map(|item| item.trim()).next_back() == Some("chunked") {
+                    Ok(BodyLength::CHUNKED)
+                }else {
+                    Err(RequestReadError::BAD_REQUEST)
+                }
+            },
+            (Some(_), Some(_)) => Err(RequestReadError::BAD_REQUEST)
+        };
+    }
+
+
+    /// `Expect: 100-continue` 요청 여부
+    ///
+    /// HTTP/1.0 요청의 Expect Header 는 무시하고, 처리할 수 없는 Expect 값은 거부한다.
+    fn expect_continue(head : &str) -> Result<bool, RequestReadError> {
+        let mut lines = head.split("\r\n");
+        let is_http_1_1 : bool = lines.next().is_some_and(|line| line.trim_end().ends_with("HTTP/1.1"));
+
+        for line in lines {
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("Expect") {
+                    if !is_http_1_1 {
+                        return Ok(false);
+                    }
+                    if value.trim().eq_ignore_ascii_case("100-continue") {
+                        return Ok(true);
+                    }
+                    return Err(RequestReadError::EXPECTATION_FAILED);
+                }
+            }
+        }
+
+        return Ok(false);
+    }
+
+
+    /// HTTP Token 여부 (RFC 9110 5.6.2)
+    fn is_token(value : &str) -> bool {
+        return !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte));
+    }
+
+
+    /// Trailer 로 받을 수 없는 Header 여부
+    fn is_forbidden_trailer(name : &str) -> bool {
+        return ["Content-Length", "Transfer-Encoding", "Host", "Connection", "Trailer", "Content-Type", "Expect", "Cookie"]
+            .iter()
+            .any(|forbidden| name.eq_ignore_ascii_case(forbidden));
+    }
+
+
+    /// Byte 배열에서 패턴 위치 검색
+    fn find_bytes(data : &[u8], pattern : &[u8]) -> Option<usize> {
+        return data.windows(pattern.len()).position(|window| window == pattern);
+    }
+
+
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+
+        /// 데이터를 한 번에 넣고 요청 하나 읽기
+        fn read(data : &[u8]) -> Result<Option<RawRequest>, RequestReadError> {
+            let mut reader : RequestReader = RequestReader::new(1024, 1024);
+            reader.feed(data);
+            return reader.poll_request();
+        }
+
+
+        #[test]
+        fn reads_content_length_body_and_keeps_pipelined_data() {
+            let mut reader : RequestReader = RequestReader::new(1024, 1024);
+            reader.feed(b"POST /a HTTP/1.1\r\nContent-Length: 5\r\n\r\nhelloGET /b HTTP/1.1\r\n\r\n");
+
+            let first : RawRequest = reader.poll_request().unwrap().unwrap();
+            assert_eq!(first.head, "POST /a HTTP/1.1\r\nContent-Length: 5");
+            assert_eq!(first.body, b"hello");
+            assert!(reader.has_buffered_data());
+
+            let second : RawRequest = reader.poll_request().unwrap().unwrap();
+            assert_eq!(second.head, "GET /b HTTP/1.1");
+            assert!(second.body.is_empty());
+            assert!(!reader.has_buffered_data());
+        }
+
+
+        #[test]
+        fn decodes_chunked_body_with_extensions_and_trailers() {
+            let raw_request : RawRequest = read(b"POST / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n4;name=value\r\nWiki\r\n5\r\npedia\r\n0\r\nX-Checksum: abc\r\nContent-Length: 99\r\n\r\n").unwrap().unwrap();
+
+            assert_eq!(raw_request.body, b"Wikipedia");
+            // 허용된 Trailer 만 Header 에 추가
+            assert!(raw_request.head.ends_with("\r\nX-Checksum: abc"));
+            assert!(!raw_request.head.contains("Content-Length"));
+        }
+
+
+        #[test]
+        fn decodes_chunked_body_fed_byte_by_byte() {
+            let data : &[u8] = b"POST / HTTP/1.1\r\nTransfer-Encoding: gzip, chunked\r\n\r\n3\r\nabc\r\n0\r\n\r\n";
+            let mut reader : RequestReader = RequestReader::new(1024, 1024);
+            for (index, byte) in data.iter().enumerate() {
+                reader.feed(&[*byte]);
+                let result : Option<RawRequest> = reader.poll_request().unwrap();
+                if index + 1 < data.len() {
+                    assert!(result.is_none());
+                }else {
+                    assert_eq!(result.unwrap().body, b"abc");
+                }
+            }
+        }
+
+
+        #[test]
+        fn rejects_malformed_chunks() {
+            for data in [
+                &b"POST / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\nzz\r\n"[..],
+                &b"POST / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n+3\r\nabc\r\n"[..],
+                &b"POST / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabcX\r\n"[..],
+                &b"POST / HTTP/1.1\r\nTransfer-Encoding: chunked, gzip\r\n\r\n"[..]
+            ] {
+                assert!(matches!(read(data), Err(RequestReadError::BAD_REQUEST)));
+            }
+        }
+
+
+        #[test]
+        fn rejects_content_length_with_transfer_encoding() {
+            let result = read(b"POST / HTTP/1.1\r\nContent-Length: 3\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n0\r\n\r\n");
+            assert!(matches!(result, Err(RequestReadError::BAD_REQUEST)));
+
+            let result = read(b"POST / HTTP/1.1\r\nContent-Length: 3\r\nContent-Length: 4\r\n\r\nabcd");
+            assert!(matches!(result, Err(RequestReadError::BAD_REQUEST)));
+        }
+
+
+        #[test]
+        fn rejects_content_length_that_is_not_plain_digits() {
+            for value in ["+5", "-5", "0x5", "5 5", "5,5", "", "99999999999999999999999"] {
+                let data : String = format!("POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\nhello", value);
+                assert!(matches!(read(data.as_bytes()), Err(RequestReadError::BAD_REQUEST)), "Content-Length: {}", value);
+            }
+
+            // 값 앞뒤의 공백은 허용
+            assert_eq!(read(b"POST / HTTP/1.1\r\nContent-Length: \t5 \r\n\r\nhello").unwrap().unwrap().body, b"hello");
+        }
+
+
+        #[test]
+        fn rejects_whitespace_before_colon_and_folded_headers() {
+            for data in [
+                &b"POST / HTTP/1.1\r\nContent-Length : 5\r\n\r\nhello"[..],
+                &b"POST / HTTP/1.1\r\nTransfer-Encoding\t: chunked\r\n\r\n0\r\n\r\n"[..],
+                &b"GET / HTTP/1.1\r\nHost: a\r\n X-Folded: b\r\n\r\n"[..],
+                &b"GET / HTTP/1.1\r\n: empty\r\n\r\n"[..],
+                &b"POST / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n0\r\nX-Trailer : a\r\n\r\n"[..]
+            ] {
+                assert!(matches!(read(data), Err(RequestReadError::BAD_REQUEST)));
+            }
+        }
+
+
+        #[test]
+        fn rejects_duplicate_host_headers() {
+            let result = read(b"GET / HTTP/1.1\r\nHost: a.example\r\nhost: b.example\r\n\r\n");
+            assert!(matches!(result, Err(RequestReadError::BAD_REQUEST)));
+
+            assert!(read(b"GET / HTTP/1.1\r\nHost: a.example\r\nX-Forwarded-Host: b.example\r\n\r\n").unwrap().is_some());
+        }
+
+
+        #[test]
+        fn rejects_transfer_encoding_that_is_not_a_token_list() {
+            for value in ["chunked;", "\"chunked\"", "gzip,,chunked", "gzip chunked"] {
+                let data : String = format!("POST / HTTP/1.1\r\nTransfer-Encoding: {}\r\n\r\n0\r\n\r\n", value);
+                assert!(matches!(read(data.as_bytes()), Err(RequestReadError::BAD_REQUEST)), "Transfer-Encoding: {}", value);
+            }
+        }
+
+
+        #[test]
+        fn enforces_size_limits() {
+            let mut reader : RequestReader = RequestReader::new(32, 4);
+            reader.feed(b"GET / HTTP/1.1\r\nX-Long: 0123456789abcdef\r\n\r\n");
+            assert!(matches!(reader.poll_request(), Err(RequestReadError::HEADER_TOO_LARGE)));
+
+            let mut reader : RequestReader = RequestReader::new(1024, 4);
+            reader.feed(b"POST / HTTP/1.1\r\nContent-Length: 5\r\n\r\n");
+            assert!(matches!(reader.poll_request(), Err(RequestReadError::BODY_TOO_LARGE)));
+
+            let mut reader : RequestReader = RequestReader::new(1024, 4);
+            reader.feed(b"POST / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n2\r\n");
+            assert!(matches!(reader.poll_request(), Err(RequestReadError::BODY_TOO_LARGE)));
+        }
+
+
+        #[test]
+        fn rejects_http2_request_lines_with_505() {
+            for data in [&b"GET / HTTP/2.0\r\n\r\n"[..], &b"GET / HTTP/3\r\n\r\n"[..]] {
+                let error : RequestReadError = read(data).err().unwrap();
+                assert!(matches!(error, RequestReadError::VERSION_NOT_SUPPORTED));
+                assert_eq!(error.http_state_code(), Some(HttpStateCode::HTTP_505));
+            }
+
+            // Connection Preface 와 HTTP/1 요청은 그대로 읽는다
+            assert_eq!(read(b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n").unwrap().unwrap().head, "PRI * HTTP/2.0");
+            assert!(read(b"GET / HTTP/1.0\r\n\r\n").unwrap().is_some());
+        }
+
+
+        #[test]
+        fn returns_expect_continue_head_once() {
+            let mut reader : RequestReader = RequestReader::new(1024, 1024);
+            reader.feed(b"POST / HTTP/1.1\r\nExpect: 100-continue\r\nContent-Length: 3\r\n\r\n");
+            assert!(reader.poll_request().unwrap().is_none());
+            assert!(reader.take_expect_continue().is_some_and(|raw_request| raw_request.body.is_empty()));
+            assert!(reader.take_expect_continue().is_none());
+
+            reader.feed(b"abc");
+            assert_eq!(reader.poll_request().unwrap().unwrap().body, b"abc");
+
+            let result = read(b"POST / HTTP/1.1\r\nExpect: something\r\nContent-Length: 3\r\n\r\n");
+            assert!(matches!(result, Err(RequestReadError::EXPECTATION_FAILED)));
+        }
+    }
+}
@@ -1,11 +1,13 @@
 pub mod response_parser {
     use std::collections::HashMap;
-    use std::fmt::format;
-    use std::io::Bytes;
     use chrono::{Datelike, DateTime, Timelike, Utc};
     use crate::log::{log_text_writer, LogTypeTag};
     use crate::server::request_parser::request_parser::{http_version_classify_original, HttpVersion, Method, Request};
-    use crate::server::page_manager::page_manager::{PageFileReadInfo, read_page};
+    use crate::server::page_manager::page_manager::PageFileReadInfo;
+    use crate::server::server_config::server_config::ServerConfig;
+    use crate::server::event_stream::event_stream::EventStream;
+    #[cfg(feature = "legacy-globals")]
+    use crate::server::page_manager::page_manager::read_page;
     use crate::server::page_manager::page_manager::IsPageFileReadSuccess;
 
 
@@ -15,10 +17,6 @@ pub mod response_parser {
     }
 
 
-    /// 서버 이름 반환
-    fn get_server_name() -> String { return String::from("Athena-Engine") }
-
-
     /// Response 데이터
     pub struct Response {
         pub is_success: IsResponseDataCreateSuccess,
@@ -26,7 +24,9 @@ pub mod response_parser {
         pub http_version: Option<HttpVersion>,
         pub headers: Option<HashMap<String, String>>,
         pub cookies: Option<Vec<ResponseCookies>>,
-        pub body: Option<ResponseBody>
+        pub body: Option<ResponseBody>,
+        /// Server-Sent Events 스트림 (`event_stream_response` 로 생성, Some 이면 응답 후 접속을 유지하며 이벤트 전송)
+        pub event_stream: Option<EventStream>
     }
 
     /// Response 쿠키 데이터
@@ -48,8 +48,10 @@ pub mod response_parser {
     }
 
     /// HTTP 상태 응답 코드
-    #[derive(PartialEq)]
+    #[derive(PartialEq, Clone, Copy, Debug)]
     pub enum HttpStateCode {
+        HTTP_100,
+        HTTP_101,
         HTTP_110,
         HTTP_111,
 
@@ -93,12 +95,54 @@ pub mod response_parser {
         HTTP_431,
 
         HTTP_500,
+        HTTP_503,
+        HTTP_505,
     }
     
 
+    impl Response {
+        /// Header 설정 (같은 이름의 Header 는 대소문자 구분 없이 대체)
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use AthenaEngine::server::response_parser::response_parser::{default_state_response_writer, HttpStateCode};
+        /// # let mut response = default_state_response_writer(HttpStateCode::HTTP_200);
+        /// response.set_header("Connection", String::from("close"))
+        /// ```
+        pub fn set_header(&mut self, name : &str, value : String) {
+            let headers : &mut HashMap<String, String> = self.headers.get_or_insert_with(HashMap::new);
+            headers.retain(|key, _| !key.eq_ignore_ascii_case(name));
+            headers.insert(String::from(name), value);
+        }
+
+
+        /// Body 길이 반환 (Byte)
+        pub fn body_len(&self) -> usize {
+            return match &self.body {
+                Some(ResponseBody { body_str: Some(body) }) => body.len(),
+                _ => 0
+            };
+        }
+
+
+        /// `response_parser` 가 이 응답을 그대로 변환할 수 있는지 여부
+        ///
+        /// false 인 경우 `response_parser` 는 기본 응답을 반환한다.
+        pub fn is_complete(&self) -> bool {
+            return self.is_success == IsResponseDataCreateSuccess::SUCCESS
+                && self.http_version.is_some()
+                && self.response_code.is_some()
+                && self.headers.is_some();
+        }
+    }
+
+
     /// HTTP 상태 변환기
     pub fn default_http_state_writer(http_code : &HttpStateCode) -> &'static str {
         return match http_code {
+            HttpStateCode::HTTP_100 => "100 Continue",
+            HttpStateCode::HTTP_101 => "101 Switching Protocols",
             HttpStateCode::HTTP_110 => "110 Connection Timed Out",
             HttpStateCode::HTTP_111 => "111 Connection refused",
             HttpStateCode::HTTP_200 => "200 OK",
@@ -127,10 +171,10 @@ pub mod response_parser {
             HttpStateCode::HTTP_413 => "413 Request Entity Too Large",
             HttpStateCode::HTTP_414 => "414 Request-URI Too Long",
             HttpStateCode::HTTP_415 => "415 Unsupported Media Type",
-            HttpStateCode::HTTP_416 => "Requested Range Not Satisfiable",
-            HttpStateCode::HTTP_417 => "Expectation Failed",
-            HttpStateCode::HTTP_418 => "I'm a teapot (RFC 2324)",
-            HttpStateCode::HTTP_420 => "Enhance Your Calm (Twitter)",
+            HttpStateCode::HTTP_416 => "416 Requested Range Not Satisfiable",
+            HttpStateCode::HTTP_417 => "417 Expectation Failed",
+            HttpStateCode::HTTP_418 => "418 I'm a teapot (RFC 2324)",
+            HttpStateCode::HTTP_420 => "420 Enhance Your Calm (Twitter)",
             HttpStateCode::HTTP_422 => "422 Unprocessable Entity (WebDAV)",
             HttpStateCode::HTTP_423 => "423 Locked (WebDAV)",
             HttpStateCode::HTTP_424 => "424 Failed Dependency (WebDAV)",
@@ -139,6 +183,8 @@ pub mod response_parser {
             HttpStateCode::HTTP_428 => "428 Precondition Required",
             HttpStateCode::HTTP_429 => "429 Too Many Requests",
             HttpStateCode::HTTP_431 => "431 Request Header Fields Too Large",
+            HttpStateCode::HTTP_503 => "503 Service Unavailable",
+            HttpStateCode::HTTP_505 => "505 HTTP Version Not Supported",
         };
     }
 
@@ -154,14 +200,75 @@ pub mod response_parser {
     }
 
 
+    /// 상태 코드 응답 생성기 (요청 분석 전 오류 응답 등에 사용)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::response_parser::response_parser::{default_state_response_writer, HttpStateCode};
+    /// default_state_response_writer(HttpStateCode::HTTP_503)
+    /// # ;
+    /// ```
+    ///
+    /// # Argument
+    /// http_code : HTTP 상태 응답 코드
+    ///
+    /// # Return
+    /// 기본 Body 를 가진 Response 구조체
+    pub fn default_state_response_writer(http_code : HttpStateCode) -> Response {
+        return state_response_writer(http_code, &ServerConfig::default());
+    }
+
+
+    /// 서버 설정의 기본 Header 를 사용하는 상태 코드 응답 생성기
+    ///
+    /// # Argument
+    /// http_code : HTTP 상태 응답 코드
+    ///
+    /// config : 서버 설정
+    ///
+    /// # Return
+    /// 기본 Body 를 가진 Response 구조체
+    pub fn state_response_writer(http_code : HttpStateCode, config : &ServerConfig) -> Response {
+        let body : String = default_body_writer(&http_code);
+
+        let mut header : HashMap<String, String> = response_header_writer(config);
+        header.insert(String::from("Content-Length"), body.len().to_string());
+
+        return Response {
+            is_success: IsResponseDataCreateSuccess::SUCCESS,
+            response_code: Some(http_code),
+            http_version: Some(HttpVersion::HTTP_1_1),
+            headers: Some(header),
+            cookies: None,
+            body: Some(ResponseBody {
+                body_str: Some(body)
+            }),
+            event_stream: None
+        };
+    }
+
+
     /// 기본 응답 Header 생성기
     pub fn default_response_header_writer() -> HashMap<String, String> {
+        return response_header_writer(&ServerConfig::default());
+    }
+
+
+    /// 서버 설정의 기본 Header 로 응답 Header 생성
+    ///
+    /// # Argument
+    /// config : 서버 설정
+    ///
+    /// # Return
+    /// Date 와 `config.default_response_headers` 를 포함한 Header
+    pub fn response_header_writer(config : &ServerConfig) -> HashMap<String, String> {
         let mut header : HashMap<String, String> = HashMap::new();
 
         // 헤더 데이터 설정 - Date
         let header_setting_date: DateTime<Utc> = Utc::now();
         let header_setting_date_month_to_str : &str = match header_setting_date.month() {
-            1 => "Jun",
+            1 => "Jan",
             2 => "Feb",
             3 => "Mar",
             4 => "Apr",
@@ -176,26 +283,18 @@ pub mod response_parser {
             _ => "Jun"
         };
         header.insert(String::from("Date"),
-                      String::from(format!("{}, {:0>2} {} {} {:0>2}:{:0>2}:{:0>2} GMT",
-                                           header_setting_date.weekday(),
-                                           header_setting_date.day(),
-                                           header_setting_date_month_to_str,
-                                           header_setting_date.year(),
-                                           header_setting_date.hour(),
-                                           header_setting_date.minute(),
-                                           header_setting_date.second())));
-        // 헤더 데이터 설정 - Server
-        header.insert(String::from("Server"), get_server_name());
-        // 헤더 데이터 설정 - Connection
-        header.insert(String::from("Connection"), String::from("close"));
-        // 헤더 데이터 설정 - Connection
-        header.insert(String::from("Pragma"), String::from("no-cache"));
-        // 헤더 데이터 설정 - Content-Type
-        header.insert(String::from("Content-Type"), String::from("text/html; charset=UTF-8"));
-        // 헤더 데이터 설정 - Content-Language
-        header.insert(String::from("Content-Language"), String::from("ko-KR"));
-        // 헤더 데이터 설정 - Access-Control-Allow-Origin
-        header.insert(String::from("Access-Control-Allow-Origin"), String::from("*"));
+                      format!("{}, {:0>2} {} {} {:0>2}:{:0>2}:{:0>2} GMT",
+                              header_setting_date.weekday(),
+                              header_setting_date.day(),
+                              header_setting_date_month_to_str,
+                              header_setting_date.year(),
+                              header_setting_date.hour(),
+                              header_setting_date.minute(),
+                              header_setting_date.second()));
+        // 헤더 데이터 설정 - 서버 설정의 기본 Header
+        for (key, value) in &config.default_response_headers {
+            header.insert(key.clone(), value.clone());
+        }
 
         return header;
     }
@@ -207,7 +306,11 @@ pub mod response_parser {
     /// # Examples
     ///
     /// ```
+    /// # use AthenaEngine::server::request_parser::request_parser::request_parser;
+    /// # use AthenaEngine::server::response_parser::response_parser::default_response_writer;
+    /// # let request = request_parser(&vec!["GET /hello.html HTTP/1.1", ""]);
     /// default_response_writer(&request, None, None)
+    /// # ;
     /// ```
     ///
     /// # Argument
@@ -227,7 +330,8 @@ pub mod response_parser {
             http_version: None,
             headers: None,
             cookies: None,
-            body: None
+            body: None,
+            event_stream: None
         };
 
         // 데이터 생성 - HTTP 응답 코드
@@ -241,94 +345,79 @@ pub mod response_parser {
 
         // Method 데이터 추출
         match &request.method {
-            Some(method) => {
-                // Method 지원 여부 확인
-                if method != &Method::NOT_SUPPORTED {
-                    // 데이터 추출 - HTTP 버전
-                    match &request.http_version {
-                        Some(http_version) => {
-                            // HTTP 버전 지원 여부 확인
-                            if http_version != &HttpVersion::NOT_SUPPORTED {
-                                // 데이터 삽입 - HTTP Version
-                                match &request.http_version {
-                                    None => {}
-                                    Some(version) => {
-                                        response_http_version = match version {
-                                            HttpVersion::HTTP_1_0 => HttpVersion::HTTP_1_0,
-                                            HttpVersion::HTTP_1_1 => HttpVersion::HTTP_1_1,
-                                            HttpVersion::HTTP_2_0 => HttpVersion::HTTP_2_0,
-                                            HttpVersion::NOT_SUPPORTED => HttpVersion::HTTP_1_1
-                                        };
-                                    }
-                                }
-
-                                // 데이터 삽입 - Cookies
-                                response.cookies = cookies;
-
-                                // 요청 페이지 읽기
-                                match &request.target {
-                                    Some(request_page) => {
-                                        let path;
-
-                                        if request_page.contains("?") {
-                                            let mut split: Vec<&str> = request_page.split("?").collect();
-                                            path = String::from(split[0]);
-                                        }else {
-                                            path = String::from(request_page);
-                                        }
+            // Method 지원 여부 확인
+            Some(method) if method != &Method::NOT_SUPPORTED => {
+                // 데이터 추출 - HTTP 버전 (지원 여부 확인)
+                match &request.http_version {
+                    Some(http_version) if http_version != &HttpVersion::NOT_SUPPORTED => {
+                        // 데이터 삽입 - HTTP Version
+                        match &request.http_version {
+                            None => {}
+                            Some(version) => {
+                                response_http_version = match version {
+                                    HttpVersion::HTTP_1_0 => HttpVersion::HTTP_1_0,
+                                    HttpVersion::HTTP_1_1 => HttpVersion::HTTP_1_1,
+                                    // HTTP/2 응답은 Frame 으로 보내므로 문자열 응답은 HTTP/1.1
+                                    HttpVersion::HTTP_2_0 => HttpVersion::HTTP_1_1,
+                                    HttpVersion::NOT_SUPPORTED => HttpVersion::HTTP_1_1
+                                };
+                            }
+                        }
 
-                                        // 페이지 정보 불러오기
-                                        let page_read_data : PageFileReadInfo = read_page(String::from(path));
-                                        // 성공 여부 확인
-                                        if page_read_data.is_success == IsPageFileReadSuccess::SUCCESS { // 페이지 읽기 성공
-                                            response_code = HttpStateCode::HTTP_200;
-                                            response_body.body_str = page_read_data.value;
-                                        }else if page_read_data.is_success == IsPageFileReadSuccess::FAIL { // 400 오류 발생
-                                            response_code = HttpStateCode::HTTP_400;
-                                            response_body.body_str = Some(default_body_writer(&response_code));
-                                        }else { // 404 오류 발생
-                                            response_code = HttpStateCode::HTTP_404;
-                                            response_body.body_str = Some(default_body_writer(&response_code))
-                                        }
-                                    },
-                                    None => { // 404 오류 발생
-                                        response_code = HttpStateCode::HTTP_404;
-                                        response_body.body_str = Some(default_body_writer(&response_code));
-                                    }
+                        // 데이터 삽입 - Cookies
+                        response.cookies = cookies;
+
+                        // 요청 페이지 읽기
+                        match &request.target {
+                            Some(request_page) => {
+                                let path : String = if request_page.contains("?") {
+                                    let split: Vec<&str> = request_page.split("?").collect();
+                                    String::from(split[0])
+                                }else {
+                                    String::from(request_page)
+                                };
+
+                                // 페이지 정보 불러오기
+                                let page_read_data : PageFileReadInfo = read_request_page(request, path);
+                                // 성공 여부 확인
+                                if page_read_data.is_success == IsPageFileReadSuccess::SUCCESS { // 페이지 읽기 성공
+                                    response_code = HttpStateCode::HTTP_200;
+                                    response_body.body_str = page_read_data.value;
+                                }else if page_read_data.is_success == IsPageFileReadSuccess::FAIL { // 400 오류 발생
+                                    response_code = HttpStateCode::HTTP_400;
+                                    response_body.body_str = Some(default_body_writer(&response_code));
+                                }else { // 404 오류 발생
+                                    response_code = HttpStateCode::HTTP_404;
+                                    response_body.body_str = Some(default_body_writer(&response_code))
                                 }
-                            }else { // 426 오류 발생
-                                response.is_success = IsResponseDataCreateSuccess::FAIL;
+                            },
+                            None => { // 404 오류 발생
+                                response_code = HttpStateCode::HTTP_404;
+                                response_body.body_str = Some(default_body_writer(&response_code));
                             }
-                        },
-                        None => { // 426 오류 발생
-                            response.is_success = IsResponseDataCreateSuccess::FAIL;
                         }
-                    };
-                }else { // 426 오류 발생
-                    response.is_success = IsResponseDataCreateSuccess::FAIL;
-                }
-            }
-            None => { // 426 오류 발생
+                    },
+                    _ => { // 426 오류 발생
+                        response.is_success = IsResponseDataCreateSuccess::FAIL;
+                    }
+                };
+            },
+            _ => { // 426 오류 발생
                 response.is_success = IsResponseDataCreateSuccess::FAIL;
             }
         }
 
-        // 헤더 데이터 HashMap
-        let mut header : HashMap<String, String> = default_response_header_writer();
-
-        // 헤더 데이터 추가 - Content-Disposition
-        header.insert(String::from("Content-Disposition"), String::from("inline"));
-        // 헤더 데이터 추가 - Cache-Control
-        header.insert(String::from("Cache-Control"), String::from("no-cache"));
+        // 헤더 데이터 HashMap (요청을 처리하는 서버의 설정 사용)
+        let mut header : HashMap<String, String> = match &request.config {
+            Some(config) => response_header_writer(config),
+            None => default_response_header_writer()
+        };
         // Header 추가
-        match input_header {
-            Some(input) => {
-                for (key, value) in input {
-                    // 헤더 데이터 추가
-                    header.insert(key, value);
-                }
-            },
-            None => {}
+        if let Some(input) = input_header {
+            for (key, value) in input {
+                // 헤더 데이터 추가
+                header.insert(key, value);
+            }
         }
 
         // 헤더 데이터 추가 - Content-Length
@@ -358,12 +447,31 @@ pub mod response_parser {
     }
 
 
+    /// 요청을 처리하는 서버의 페이지 리스트에서 페이지 읽기
+    fn read_request_page(request : &Request, path : String) -> PageFileReadInfo {
+        return match &request.pages {
+            Some(pages) => pages.read_page(path),
+            #[cfg(feature = "legacy-globals")]
+            None => read_page(path),
+            #[cfg(not(feature = "legacy-globals"))]
+            None => PageFileReadInfo {
+                value: None,
+                is_success: IsPageFileReadSuccess::NO_DATA
+            }
+        };
+    }
+
+
     /// 응답 Struct 를 String 형식으로 변환
     pub fn response_parser(response : Response) -> String {
         // 기본 Response
-        let default_response = String::from("HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=UTF-8\r\nDate: Wed, 14 Dec 2022 00:25:57 GMT\r\nAccess-Control-Allow-Origin: *\r\nContent-Disposition: inline\r\nContent-Language: ko-KR");
+        let mut default_response = String::from("HTTP/1.1 200 OK\r\n");
+        for (key, value) in default_response_header_writer() {
+            default_response.push_str(&format!("{}: {}\r\n", key, value));
+        }
+        default_response.push_str("Content-Length: 0\r\nConnection: close\r\n\r\n");
         // Response 생성
-        let mut response_str = String::new();
+        let response_str;
         if response.is_success == IsResponseDataCreateSuccess::SUCCESS {
             match &response.http_version {
                 Some(http_version) => {
@@ -378,14 +486,11 @@ pub mod response_parser {
                                     }
 
                                     // 쿠키 설정
-                                    match &response.cookies {
-                                        Some(cookies) => {
-                                            for cookie in cookies {
-                                                let line = String::from(format!("Set-Cookie: {}={}; Path={}\r\n", cookie.name, cookie.value, cookie.path));
-                                                header.push_str(&line);
-                                            }
-                                        },
-                                        None => {}
+                                    if let Some(cookies) = &response.cookies {
+                                        for cookie in cookies {
+                                            let line = format!("Set-Cookie: {}={}; Path={}\r\n", cookie.name, cookie.value, cookie.path);
+                                            header.push_str(&line);
+                                        }
                                     }
 
                                     response_str = response_format(http_version, response_code, header, response.body);
@@ -419,28 +524,25 @@ pub mod response_parser {
             Some(body) => {
                 match &body.body_str {
                     Some(body) => {
-                        String::from(
-                            format!("{} {}\r\n{}\r\n{}",
-                                    http_version_classify_original(http_version),
-                                    default_http_state_writer(response_code),
-                                    header_str,
-                                    body))
+                        format!("{} {}\r\n{}\r\n{}",
+                                http_version_classify_original(http_version),
+                                default_http_state_writer(response_code),
+                                header_str,
+                                body)
                     },
                     None => {
-                        String::from(
-                            format!("{} {}\r\n{}",
-                                    http_version_classify_original(http_version),
-                                    default_http_state_writer(response_code),
-                                    header_str))
+                        format!("{} {}\r\n{}",
+                                http_version_classify_original(http_version),
+                                default_http_state_writer(response_code),
+                                header_str)
                     }
                 }
             }
             None => {
-                String::from(
-                    format!("{} {}\r\n{}",
-                            http_version_classify_original(http_version),
-                            default_http_state_writer(response_code),
-                            header_str))
+                format!("{} {}\r\n{}",
+                        http_version_classify_original(http_version),
+                        default_http_state_writer(response_code),
+                        header_str)
             }
         }
     }
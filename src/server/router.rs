@@ -0,0 +1,882 @@
+pub mod router {
+    use std::cmp::Ordering;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use crate::server::ResponseHandler;
+    use crate::server::middleware::middleware::{run_middlewares, Middleware};
+    use crate::server::request_parser::request_parser::{Method, Request};
+    use crate::server::response_parser::response_parser::{state_response_writer, HttpStateCode, Response, ResponseBody};
+    use crate::server::server_config::server_config::ServerConfig;
+    use crate::error::AthenaError;
+
+
+    /// `Allow` Header 에 표시하는 Method 순서
+    const ALLOW_ORDER : [Method; 7] = [Method::GET, Method::HEAD, Method::POST, Method::PUT, Method::DELETE, Method::PATCH, Method::OPTIONS];
+
+
+    /// 경로 패턴으로 요청을 Handler 에 연결하는 Router
+    ///
+    /// 패턴은 `/` 로 나눈 Segment 로 비교한다.
+    /// `:name` 은 Segment 하나, `*name` 은 (마지막 Segment 에서만) 나머지 경로 전체와 일치하며, 일치한 값은 `Request::path_param` 으로 읽는다.
+    /// 여러 Route 가 일치하면 앞 Segment 부터 고정 경로, `:name`, `*name` 순서로 우선하므로 등록 순서와 관계없이 같은 Route 를 선택한다.
+    /// 고정 경로는 페이지 리스트와 같이 대소문자를 구분하지 않는다.
+    ///
+    /// 경로가 일치하는 Route 에 요청 Method 의 Handler 가 없으면 `405 Method Not Allowed` 와 `Allow` Header 로 응답한다.
+    /// `HEAD` 요청은 `GET` Handler 로 처리하며 서버가 Body 를 보내지 않고, `OPTIONS` 요청은 `Allow` Header 로 자동 응답한다.
+    ///
+    /// Middleware 는 Router 전체 (`middleware`), Route 하나 (`route_middleware`) 순서로 실행한다.
+    ///
+    /// `mount` 로 다른 Router 를 경로 Prefix 아래에 Route 그룹으로 연결한다.
+    /// 그룹의 Middleware 는 그룹 Route 에만 적용되고 (이미 등록된 경로에 다른 Method 로 합친 Route 포함), 그룹의 `fallback` 은 Prefix 아래에서 일치하는 그룹 Route 가 없는 요청을 처리한다 (Prefix 로 시작하지 않는 그룹 밖의 Route 보다 우선).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::error::AthenaError;
+    /// # use AthenaEngine::server::Server;
+    /// # use AthenaEngine::server::router::router::Router;
+    /// # use AthenaEngine::server::request_parser::request_parser::Request;
+    /// # use AthenaEngine::server::response_parser::response_parser::Response;
+    /// # fn user_page(_id : Option<&str>) -> Response { AthenaEngine::server::response_parser::response_parser::default_state_response_writer(AthenaEngine::server::response_parser::response_parser::HttpStateCode::HTTP_200) }
+    /// # fn file_page(_rest : Option<&str>) -> Response { AthenaEngine::server::response_parser::response_parser::default_state_response_writer(AthenaEngine::server::response_parser::response_parser::HttpStateCode::HTTP_200) }
+    /// # fn update_user(_request : &Request) -> Response { AthenaEngine::server::response_parser::response_parser::default_state_response_writer(AthenaEngine::server::response_parser::response_parser::HttpStateCode::HTTP_200) }
+    /// # fn main() -> Result<(), AthenaError> {
+    /// # let admin_router = Router::new();
+    /// let router = Router::new()
+    ///     .get("/users/:id", |request| user_page(request.path_param("id")))?
+    ///     .post("/users/:id", |request| update_user(request))?
+    ///     .route("/files/*rest", |request| file_page(request.path_param("rest")))?
+    ///     .mount("/admin", admin_router)?;
+    /// # let _ =
+    /// Server::builder()
+    ///     .router(router)
+    /// # ;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub struct Router {
+        routes : Vec<Route>,
+        /// 모든 Route 에 적용하는 Middleware
+        middlewares : Vec<Arc<dyn Middleware>>,
+        /// 일치하는 Route 가 없을 때 실행하는 Prefix 별 Handler
+        fallbacks : Vec<Fallback>
+    }
+
+    /// 등록된 Route (경로 패턴 하나의 Method 별 Handler)
+    struct Route {
+        /// 등록한 패턴
+        pattern : String,
+        /// 패턴 Segment
+        segments : Vec<Segment>,
+        /// Method 별 요청 Handler
+        handlers : HashMap<Method, MethodHandler>,
+        /// 모든 Method 요청 Handler (`route` 로 등록)
+        any_handler : Option<MethodHandler>,
+        /// Route 에만 적용하는 Middleware
+        middlewares : Vec<Arc<dyn Middleware>>
+    }
+
+    /// Route 의 Method 하나를 처리하는 Handler
+    struct MethodHandler {
+        /// 요청 Handler
+        handler : ResponseHandler,
+        /// Handler 에만 적용하는 Middleware (이미 등록된 경로에 합친 그룹 Route 의 Middleware)
+        middlewares : Vec<Arc<dyn Middleware>>
+    }
+
+    /// 경로 Prefix 아래에서 일치하는 Route 가 없을 때 실행하는 Handler
+    struct Fallback {
+        /// 경로 Prefix (Router 전체면 빈 문자열)
+        prefix : String,
+        /// Prefix Segment
+        segments : Vec<Segment>,
+        /// 요청 Handler
+        handler : ResponseHandler,
+        /// Fallback 에만 적용하는 Middleware (Mount 한 그룹의 Middleware)
+        middlewares : Vec<Arc<dyn Middleware>>
+    }
+
+    /// 요청과 일치하는 Route
+    pub(crate) struct RouteMatch<'a> {
+        /// 처리 방식
+        pub(crate) target : RouteTarget<'a>,
+        /// 경로 Parameter
+        pub(crate) params : HashMap<String, String>,
+        /// 실행할 Middleware (Router, Route 순서)
+        middlewares : Vec<Arc<dyn Middleware>>
+    }
+
+    /// 요청과 일치하는 Route 의 처리 방식
+    pub(crate) enum RouteTarget<'a> {
+        /// 등록된 Handler 실행
+        HANDLER(&'a ResponseHandler),
+        /// `OPTIONS` 자동 응답 (`Allow` Header 값)
+        OPTIONS(String),
+        /// `405 Method Not Allowed` 응답 (`Allow` Header 값)
+        METHOD_NOT_ALLOWED(String)
+    }
+
+    /// 경로 패턴 Segment
+    #[derive(PartialEq)]
+    enum Segment {
+        /// 고정 경로 (소문자)
+        STATIC(String),
+        /// `:name` - Segment 하나
+        PARAM(String),
+        /// `*name` - 나머지 경로 전체
+        WILDCARD(String)
+    }
+
+
+    impl Router {
+        /// 빈 Router 생성
+        pub fn new() -> Router {
+            return Router {
+                routes: Vec::new(),
+                middlewares: Vec::new(),
+                fallbacks: Vec::new()
+            };
+        }
+
+
+        /// 일치하는 Route 가 없는 요청의 Handler 설정 (예: 그룹 전용 `404 Not Found` 응답)
+        ///
+        /// Router 를 `mount` 하면 Prefix 아래의 경로에만 적용된다.
+        /// 설정하면 Router 에서 처리하는 경로의 요청은 Response 이벤트로 넘어가지 않는다.
+        pub fn fallback<F>(mut self, handler : F) -> Router
+            where F : Fn(&Request) -> Response + Send + Sync + 'static {
+            self.fallbacks.retain(|fallback| !fallback.segments.is_empty());
+            self.fallbacks.push(Fallback {
+                prefix: String::new(),
+                segments: Vec::new(),
+                handler: Box::new(handler),
+                middlewares: Vec::new()
+            });
+            return self;
+        }
+
+
+        /// 다른 Router 를 경로 Prefix 아래에 Route 그룹으로 연결
+        ///
+        /// 그룹의 Route 패턴 앞에 Prefix 를 붙이고, 그룹의 Middleware 는 그룹 Route 와 Fallback 에만 적용한다.
+        /// Prefix 에는 `:name` 을 사용할 수 있으며 일치한 값은 그룹 Route 의 경로 Parameter 에 포함된다.
+        ///
+        /// # Argument
+        /// prefix : 경로 Prefix (예: `/admin`, `/tenants/:tenant`)
+        ///
+        /// router : 연결할 Router
+        ///
+        /// # Return
+        /// Prefix 가 잘못되었거나 그룹의 Route 와 Fallback 이 이미 등록된 경로와 겹치면 오류
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use AthenaEngine::error::AthenaError;
+        /// # use AthenaEngine::server::router::router::Router;
+        /// # use AthenaEngine::server::request_parser::request_parser::Request;
+        /// # use AthenaEngine::server::response_parser::response_parser::Response;
+        /// # use AthenaEngine::server::middleware::middleware::Next;
+        /// # fn dashboard(_request : &Request) -> Response { AthenaEngine::server::response_parser::response_parser::default_state_response_writer(AthenaEngine::server::response_parser::response_parser::HttpStateCode::HTTP_200) }
+        /// # fn user_page(_request : &Request) -> Response { AthenaEngine::server::response_parser::response_parser::default_state_response_writer(AthenaEngine::server::response_parser::response_parser::HttpStateCode::HTTP_200) }
+        /// # fn admin_not_found(_request : &Request) -> Response { AthenaEngine::server::response_parser::response_parser::default_state_response_writer(AthenaEngine::server::response_parser::response_parser::HttpStateCode::HTTP_200) }
+        /// # fn require_login(request : &mut Request, next : Next) -> Response { next.run(request) }
+        /// # fn main() -> Result<(), AthenaError> {
+        /// let admin = Router::new()
+        ///     .get("/", |request| dashboard(request))?
+        ///     .get("/users/:id", |request| user_page(request))?
+        ///     .middleware(require_login)
+        ///     .fallback(|request| admin_not_found(request));
+        /// let router = Router::new()
+        ///     .mount("/admin", admin)?;
+        /// # Ok(())
+        /// # }
+        /// ```
+        pub fn mount(mut self, prefix : &str, router : Router) -> Result<Router, AthenaError> {
+            if !prefix.starts_with('/') {
+                return Err(AthenaError::ROUTE_PATTERN_INVALID(String::from(prefix), String::from("pattern must start with '/'")));
+            }
+            // `/admin/` 과 `/admin` 은 같은 Prefix (`/` 는 Router 전체)
+            let prefix : &str = prefix.trim_end_matches('/');
+            let prefix_segments : Vec<Segment> = parse_prefix(prefix)?;
+            if prefix_segments.iter().any(|segment| matches!(segment, Segment::WILDCARD(_))) {
+                return Err(AthenaError::ROUTE_PATTERN_INVALID(String::from(prefix), String::from("group prefix cannot contain a wildcard")));
+            }
+
+            for route in router.routes {
+                let pattern : String = match route.pattern.as_str() {
+                    "/" if !prefix.is_empty() => String::from(prefix),
+                    pattern => format!("{}{}", prefix, pattern)
+                };
+                let segments : Vec<Segment> = parse_pattern(&pattern)?;
+                let middlewares : Vec<Arc<dyn Middleware>> = router.middlewares.iter().chain(&route.middlewares).cloned().collect();
+                let other : &mut Route = match self.routes.iter().position(|other| is_conflict(&other.segments, &segments)) {
+                    Some(index) => &mut self.routes[index],
+                    None => {
+                        self.routes.push(Route {
+                            pattern,
+                            segments,
+                            handlers: route.handlers,
+                            any_handler: route.any_handler,
+                            middlewares
+                        });
+                        continue;
+                    }
+                };
+
+                // 같은 경로는 `insert` 와 같이 Method 별 Handler 를 합친다 (Parameter 이름까지 같은 패턴만)
+                if other.segments != segments || (route.any_handler.is_some() && other.any_handler.is_some()) {
+                    return Err(AthenaError::ROUTE_CONFLICT(pattern, other.pattern.clone()));
+                }
+                if let Some(method) = route.handlers.keys().find(|method| other.handlers.contains_key(method)) {
+                    return Err(AthenaError::ROUTE_CONFLICT(format_route(Some(*method), &pattern), format_route(Some(*method), &other.pattern)));
+                }
+                // 각 Route 의 Middleware 는 자기 Handler 에만 적용
+                other.move_middlewares_to_handlers();
+                for (method, handler) in route.handlers {
+                    other.handlers.insert(method, handler.wrapped(&middlewares));
+                }
+                if let Some(handler) = route.any_handler {
+                    other.any_handler = Some(handler.wrapped(&middlewares));
+                }
+            }
+            for fallback in router.fallbacks {
+                let fallback_prefix : String = format!("{}{}", prefix, fallback.prefix);
+                let segments : Vec<Segment> = parse_prefix(&fallback_prefix)?;
+                if let Some(other) = self.fallbacks.iter().find(|other| is_conflict(&other.segments, &segments)) {
+                    return Err(AthenaError::ROUTE_CONFLICT(format_fallback(&fallback_prefix), format_fallback(&other.prefix)));
+                }
+                self.fallbacks.push(Fallback {
+                    prefix: fallback_prefix,
+                    segments,
+                    handler: fallback.handler,
+                    middlewares: router.middlewares.iter().chain(&fallback.middlewares).cloned().collect()
+                });
+            }
+
+            return Ok(self);
+        }
+
+
+        /// 모든 Route 에 적용할 Middleware 추가 (먼저 추가한 Middleware 가 바깥쪽에서 실행)
+        pub fn middleware<M>(mut self, middleware : M) -> Router
+            where M : Middleware + 'static {
+            self.middlewares.push(Arc::new(middleware));
+            return self;
+        }
+
+
+        /// 등록된 Route 하나에 적용할 Middleware 추가 (모든 Method 에 적용)
+        ///
+        /// # Argument
+        /// pattern : Route 를 등록한 경로 패턴
+        ///
+        /// middleware : 추가할 Middleware
+        ///
+        /// # Return
+        /// 같은 패턴으로 등록된 Route 가 없으면 오류
+        pub fn route_middleware<M>(mut self, pattern : &str, middleware : M) -> Result<Router, AthenaError>
+            where M : Middleware + 'static {
+            let segments : Vec<Segment> = parse_pattern(pattern)?;
+            return match self.routes.iter_mut().find(|route| route.segments == segments) {
+                Some(route) => {
+                    route.middlewares.push(Arc::new(middleware));
+                    Ok(self)
+                },
+                None => Err(AthenaError::ROUTE_PATTERN_INVALID(String::from(pattern), String::from("route is not registered")))
+            };
+        }
+
+
+        /// 모든 Method 의 Route 등록 (같은 경로에 Method 별 Handler 가 있으면 그 Handler 가 우선)
+        ///
+        /// # Argument
+        /// pattern : 경로 패턴 (예: `/users/:id`, `/files/*rest`)
+        ///
+        /// handler : 요청 Handler
+        ///
+        /// # Return
+        /// 패턴이 잘못되었거나 같은 경로와 일치하는 Route 가 이미 있으면 오류
+        pub fn route<F>(self, pattern : &str, handler : F) -> Result<Router, AthenaError>
+            where F : Fn(&Request) -> Response + Send + Sync + 'static {
+            return self.insert(pattern, None, Box::new(handler));
+        }
+
+
+        /// Method 별 Route 등록
+        ///
+        /// # Argument
+        /// method : 요청 Method (`HEAD`, `OPTIONS` 를 등록하면 자동 응답 대신 사용)
+        ///
+        /// pattern : 경로 패턴 (예: `/users/:id`, `/files/*rest`)
+        ///
+        /// handler : 요청 Handler
+        ///
+        /// # Return
+        /// 패턴이나 Method 가 잘못되었거나 같은 경로와 Method 의 Route 가 이미 있으면 오류
+        pub fn method<F>(self, method : Method, pattern : &str, handler : F) -> Result<Router, AthenaError>
+            where F : Fn(&Request) -> Response + Send + Sync + 'static {
+            if method == Method::NOT_SUPPORTED {
+                return Err(AthenaError::ROUTE_PATTERN_INVALID(String::from(pattern), String::from("unsupported method")));
+            }
+            return self.insert(pattern, Some(method), Box::new(handler));
+        }
+
+
+        /// `GET` Route 등록 (`HEAD` 요청도 처리)
+        pub fn get<F>(self, pattern : &str, handler : F) -> Result<Router, AthenaError>
+            where F : Fn(&Request) -> Response + Send + Sync + 'static {
+            return self.method(Method::GET, pattern, handler);
+        }
+
+
+        /// `POST` Route 등록
+        pub fn post<F>(self, pattern : &str, handler : F) -> Result<Router, AthenaError>
+            where F : Fn(&Request) -> Response + Send + Sync + 'static {
+            return self.method(Method::POST, pattern, handler);
+        }
+
+
+        /// `PUT` Route 등록
+        pub fn put<F>(self, pattern : &str, handler : F) -> Result<Router, AthenaError>
+            where F : Fn(&Request) -> Response + Send + Sync + 'static {
+            return self.method(Method::PUT, pattern, handler);
+        }
+
+
+        /// `DELETE` Route 등록
+        pub fn delete<F>(self, pattern : &str, handler : F) -> Result<Router, AthenaError>
+            where F : Fn(&Request) -> Response + Send + Sync + 'static {
+            return self.method(Method::DELETE, pattern, handler);
+        }
+
+
+        /// `PATCH` Route 등록
+        pub fn patch<F>(self, pattern : &str, handler : F) -> Result<Router, AthenaError>
+            where F : Fn(&Request) -> Response + Send + Sync + 'static {
+            return self.method(Method::PATCH, pattern, handler);
+        }
+
+
+        /// Route 등록 (같은 패턴은 Method 별 Handler 를 합친다)
+        ///
+        /// # Argument
+        /// method : 요청 Method (None 이면 모든 Method)
+        fn insert(mut self, pattern : &str, method : Option<Method>, handler : ResponseHandler) -> Result<Router, AthenaError> {
+            let segments : Vec<Segment> = parse_pattern(pattern)?;
+            let conflict = |route : &Route| AthenaError::ROUTE_CONFLICT(format_route(method, pattern), format_route(method, &route.pattern));
+
+            let route : &mut Route = match self.routes.iter().position(|route| is_conflict(&route.segments, &segments)) {
+                // Parameter 이름까지 같은 패턴만 합칠 수 있다
+                Some(index) if self.routes[index].segments != segments => return Err(conflict(&self.routes[index])),
+                Some(index) => &mut self.routes[index],
+                None => {
+                    self.routes.push(Route {
+                        pattern: String::from(pattern),
+                        segments,
+                        handlers: HashMap::new(),
+                        any_handler: None,
+                        middlewares: Vec::new()
+                    });
+                    let index : usize = self.routes.len() - 1;
+                    &mut self.routes[index]
+                }
+            };
+            match method {
+                Some(method) if route.handlers.contains_key(&method) => return Err(conflict(route)),
+                Some(method) => {
+                    route.handlers.insert(method, MethodHandler { handler, middlewares: Vec::new() });
+                },
+                None if route.any_handler.is_some() => return Err(conflict(route)),
+                None => route.any_handler = Some(MethodHandler { handler, middlewares: Vec::new() })
+            }
+
+            return Ok(self);
+        }
+
+
+        /// 요청 경로와 Method 에 맞는 Route 찾기
+        pub(crate) fn find(&self, path : &str, method : Method) -> Option<RouteMatch<'_>> {
+            let path_segments : Vec<&str> = path.split('/').skip(1).collect();
+            let route : Option<&Route> = self.routes.iter()
+                .filter(|route| match_segments(&route.segments, &path_segments).is_some())
+                .min_by(|left, right| compare_precedence(&left.segments, &right.segments));
+            // 가장 구체적인 Prefix 의 Fallback
+            let fallback : Option<&Fallback> = self.fallbacks.iter()
+                .filter(|fallback| match_prefix(&fallback.segments, &path_segments).is_some())
+                .min_by(|left, right| compare_precedence(&left.segments, &right.segments));
+            let route : &Route = match (route, fallback) {
+                // 그룹 Prefix 아래의 경로는 Prefix 밖에서 등록한 덜 구체적인 Route 보다 그룹의 Fallback 이 우선
+                (Some(route), Some(fallback)) if !has_prefix(&route.segments, &fallback.segments) => return self.fallback_match(fallback, &path_segments),
+                (Some(route), _) => route,
+                (None, Some(fallback)) => return self.fallback_match(fallback, &path_segments),
+                (None, None) => return None
+            };
+            let params : HashMap<String, String> = match_segments(&route.segments, &path_segments)?;
+
+            let handler : Option<&MethodHandler> = route.handlers.get(&method)
+                .or_else(|| if method == Method::HEAD { route.handlers.get(&Method::GET) } else { None })
+                .or(route.any_handler.as_ref());
+            let target : RouteTarget = match handler {
+                Some(handler) => RouteTarget::HANDLER(&handler.handler),
+                None if method == Method::OPTIONS => RouteTarget::OPTIONS(route.allow()),
+                None => RouteTarget::METHOD_NOT_ALLOWED(route.allow())
+            };
+            return Some(RouteMatch {
+                target,
+                params,
+                middlewares: self.middlewares.iter()
+                    .chain(&route.middlewares)
+                    .chain(handler.iter().flat_map(|handler| &handler.middlewares))
+                    .cloned()
+                    .collect()
+            });
+        }
+
+
+        /// Fallback 실행 정보
+        fn fallback_match<'a>(&'a self, fallback : &'a Fallback, path_segments : &[&str]) -> Option<RouteMatch<'a>> {
+            let params : HashMap<String, String> = match_prefix(&fallback.segments, path_segments)?;
+
+            return Some(RouteMatch {
+                target: RouteTarget::HANDLER(&fallback.handler),
+                params,
+                middlewares: self.middlewares.iter().chain(&fallback.middlewares).cloned().collect()
+            });
+        }
+    }
+
+
+    impl Route {
+        /// 경로에서 처리할 수 있는 Method 목록 (`Allow` Header 값)
+        fn allow(&self) -> String {
+            let methods : Vec<&str> = ALLOW_ORDER.iter()
+                .filter(|method| self.any_handler.is_some()
+                    || self.handlers.contains_key(method)
+                    || (**method == Method::HEAD && self.handlers.contains_key(&Method::GET))
+                    || **method == Method::OPTIONS)
+                .map(|method| method.as_str())
+                .collect();
+            return methods.join(", ");
+        }
+
+
+        /// Route 의 Middleware 를 지금 등록된 Handler 로 옮기기 (다른 그룹의 Handler 를 합치기 전)
+        fn move_middlewares_to_handlers(&mut self) {
+            let middlewares : Vec<Arc<dyn Middleware>> = std::mem::take(&mut self.middlewares);
+            for handler in self.handlers.values_mut().chain(self.any_handler.as_mut()) {
+                handler.middlewares = middlewares.iter().chain(&handler.middlewares).cloned().collect();
+            }
+        }
+    }
+
+
+    impl MethodHandler {
+        /// 바깥쪽에서 실행할 Middleware 를 앞에 추가한 Handler
+        fn wrapped(mut self, middlewares : &[Arc<dyn Middleware>]) -> MethodHandler {
+            self.middlewares = middlewares.iter().chain(&self.middlewares).cloned().collect();
+            return self;
+        }
+    }
+
+
+    impl RouteMatch<'_> {
+        /// Middleware 를 거쳐 요청 처리
+        pub(crate) fn respond(&self, request : &mut Request) -> Response {
+            return run_middlewares(&self.middlewares, request, &|request| self.target.respond(request));
+        }
+    }
+
+
+    impl RouteTarget<'_> {
+        /// 요청 처리 (Handler 실행 또는 자동 응답 생성)
+        pub(crate) fn respond(&self, request : &Request) -> Response {
+            let (http_code, allow) : (HttpStateCode, &String) = match self {
+                RouteTarget::HANDLER(handler) => return handler(request),
+                RouteTarget::OPTIONS(allow) => (HttpStateCode::HTTP_200, allow),
+                RouteTarget::METHOD_NOT_ALLOWED(allow) => (HttpStateCode::HTTP_405, allow)
+            };
+
+            let mut response : Response = match &request.config {
+                Some(config) => state_response_writer(http_code, config),
+                None => state_response_writer(http_code, &ServerConfig::default())
+            };
+            if http_code == HttpStateCode::HTTP_200 {
+                response.body = Some(ResponseBody { body_str: Some(String::new()) });
+                response.set_header("Content-Length", String::from("0"));
+            }
+            response.set_header("Allow", allow.clone());
+            return response;
+        }
+    }
+
+
+    impl Default for Router {
+        fn default() -> Self {
+            return Router::new();
+        }
+    }
+
+
+    /// 오류 메시지용 Route 표시 (예: `GET /users/:id`)
+    fn format_route(method : Option<Method>, pattern : &str) -> String {
+        return match method {
+            Some(method) => format!("{} {}", method.as_str(), pattern),
+            None => String::from(pattern)
+        };
+    }
+
+
+    /// 오류 메시지용 Fallback 표시 (예: `/admin/* (fallback)`)
+    fn format_fallback(prefix : &str) -> String {
+        return format!("{}/* (fallback)", prefix);
+    }
+
+
+    /// 경로 Prefix 분석 (빈 문자열은 Router 전체)
+    fn parse_prefix(prefix : &str) -> Result<Vec<Segment>, AthenaError> {
+        if prefix.is_empty() {
+            return Ok(Vec::new());
+        }
+        return parse_pattern(prefix);
+    }
+
+
+    /// 경로 패턴 분석
+    fn parse_pattern(pattern : &str) -> Result<Vec<Segment>, AthenaError> {
+        let invalid = |reason : &str| AthenaError::ROUTE_PATTERN_INVALID(String::from(pattern), String::from(reason));
+
+        let path : &str = match pattern.strip_prefix('/') {
+            Some(path) => path,
+            None => return Err(invalid("pattern must start with '/'"))
+        };
+        let parts : Vec<&str> = path.split('/').collect();
+        let mut segments : Vec<Segment> = Vec::with_capacity(parts.len());
+        for (index, part) in parts.iter().enumerate() {
+            let segment : Segment = if let Some(name) = part.strip_prefix(':') {
+                Segment::PARAM(String::from(name))
+            }else if let Some(name) = part.strip_prefix('*') {
+                if index + 1 != parts.len() {
+                    return Err(invalid("wildcard must be the last segment"));
+                }
+                Segment::WILDCARD(String::from(name))
+            }else {
+                Segment::STATIC(part.to_lowercase())
+            };
+            if let Segment::PARAM(name) | Segment::WILDCARD(name) = &segment {
+                if name.is_empty() {
+                    return Err(invalid("parameter name is empty"));
+                }
+                if segments.iter().any(|item| matches!(item, Segment::PARAM(other) | Segment::WILDCARD(other) if other == name)) {
+                    return Err(invalid("duplicate parameter name"));
+                }
+            }
+            segments.push(segment);
+        }
+
+        return Ok(segments);
+    }
+
+
+    /// 두 패턴이 항상 같은 경로와 일치하는지 확인 (Parameter 이름만 다른 경우 포함)
+    fn is_conflict(left : &[Segment], right : &[Segment]) -> bool {
+        return left.len() == right.len() && left.iter().zip(right).all(|pair| match pair {
+            (Segment::STATIC(left), Segment::STATIC(right)) => left == right,
+            (Segment::PARAM(_), Segment::PARAM(_)) => true,
+            (Segment::WILDCARD(_), Segment::WILDCARD(_)) => true,
+            _ => false
+        });
+    }
+
+
+    /// Route 우선 순위 비교 (앞 Segment 부터 고정 경로, `:name`, `*name` 순서)
+    fn compare_precedence(left : &[Segment], right : &[Segment]) -> Ordering {
+        for (left, right) in left.iter().zip(right) {
+            let ordering : Ordering = segment_rank(left).cmp(&segment_rank(right));
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+
+        // 같은 순위면 더 긴 (구체적인) 패턴 우선
+        return right.len().cmp(&left.len());
+    }
+
+
+    /// Segment 우선 순위 (낮을수록 우선)
+    fn segment_rank(segment : &Segment) -> u8 {
+        return match segment {
+            Segment::STATIC(_) => 0,
+            Segment::PARAM(_) => 1,
+            Segment::WILDCARD(_) => 2
+        };
+    }
+
+
+    /// 패턴과 경로 비교
+    ///
+    /// # Return
+    /// 일치하면 경로 Parameter
+    fn match_segments(segments : &[Segment], path_segments : &[&str]) -> Option<HashMap<String, String>> {
+        let mut params : HashMap<String, String> = HashMap::new();
+        for (index, segment) in segments.iter().enumerate() {
+            match segment {
+                Segment::WILDCARD(name) => {
+                    if index >= path_segments.len() {
+                        return None;
+                    }
+                    params.insert(name.clone(), percent_decode(&path_segments[index..].join("/")));
+                    return Some(params);
+                },
+                Segment::PARAM(name) => {
+                    let value : &str = path_segments.get(index)?;
+                    if value.is_empty() {
+                        return None;
+                    }
+                    params.insert(name.clone(), percent_decode(value));
+                },
+                Segment::STATIC(value) => {
+                    if !path_segments.get(index)?.eq_ignore_ascii_case(value) {
+                        return None;
+                    }
+                }
+            }
+        }
+        if segments.len() != path_segments.len() {
+            return None;
+        }
+
+        return Some(params);
+    }
+
+
+    /// 패턴이 Prefix 로 시작하는지 확인 (Parameter 이름은 비교하지 않음)
+    fn has_prefix(segments : &[Segment], prefix : &[Segment]) -> bool {
+        return segments.len() >= prefix.len() && is_conflict(&segments[..prefix.len()], prefix);
+    }
+
+
+    /// Prefix 와 경로 앞부분 비교
+    ///
+    /// # Return
+    /// 경로가 Prefix 와 같거나 Prefix 아래에 있으면 경로 Parameter
+    fn match_prefix(segments : &[Segment], path_segments : &[&str]) -> Option<HashMap<String, String>> {
+        return match_segments(segments, path_segments.get(..segments.len())?);
+    }
+
+
+    /// `%XX` 인코딩 해제 (잘못된 인코딩은 그대로 유지)
+    fn percent_decode(value : &str) -> String {
+        let bytes : &[u8] = value.as_bytes();
+        let mut decoded : Vec<u8> = Vec::with_capacity(bytes.len());
+        let mut index : usize = 0;
+        while index < bytes.len() {
+            let hex : Option<u8> = match bytes.get(index..index + 3) {
+                Some([b'%', high, low]) if high.is_ascii_hexdigit() && low.is_ascii_hexdigit() => {
+                    std::str::from_utf8(&[*high, *low]).ok().and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                },
+                _ => None
+            };
+            match hex {
+                Some(byte) => {
+                    decoded.push(byte);
+                    index += 3;
+                },
+                None => {
+                    decoded.push(bytes[index]);
+                    index += 1;
+                }
+            }
+        }
+
+        return String::from_utf8_lossy(&decoded).into_owned();
+    }
+
+
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::server::Server;
+        use crate::server::middleware::middleware::Next;
+        use crate::server::test_client::test_client::{TestClient, TestRequest};
+
+
+        /// 고정 Body 를 반환하는 Handler
+        fn text(body : &'static str) -> impl Fn(&Request) -> Response + Send + Sync + 'static {
+            return move |_request : &Request| {
+                let mut response : Response = state_response_writer(HttpStateCode::HTTP_200, &ServerConfig::default());
+                response.body = Some(ResponseBody { body_str: Some(String::from(body)) });
+                response
+            };
+        }
+
+
+        /// 경로 Parameter 를 Body 로 반환하는 Handler
+        fn param(name : &'static str) -> impl Fn(&Request) -> Response + Send + Sync + 'static {
+            return move |request : &Request| {
+                let mut response : Response = state_response_writer(HttpStateCode::HTTP_200, &ServerConfig::default());
+                response.body = Some(ResponseBody { body_str: request.path_param(name).map(String::from) });
+                response
+            };
+        }
+
+
+        fn client(router : Router) -> TestClient {
+            return TestClient::new(&Server::builder().router(router).build());
+        }
+
+
+        #[test]
+        fn prefers_static_then_param_then_wildcard_segments() {
+            let client : TestClient = client(Router::new()
+                .get("/files/*rest", param("rest")).unwrap()
+                .get("/files/:name/raw", text("param raw")).unwrap()
+                .get("/files/readme/:format", text("static readme")).unwrap()
+                .get("/files/:name", param("name")).unwrap());
+
+            client.get("/files/readme/raw").assert_body("static readme");
+            client.get("/files/notes/raw").assert_body("param raw");
+            client.get("/files/notes").assert_body("notes");
+            client.get("/files/a/b/c").assert_body("a/b/c");
+            // 고정 경로는 대소문자를 구분하지 않는다
+            client.get("/FILES/README/raw").assert_body("static readme");
+        }
+
+
+        #[test]
+        fn rejects_conflicting_routes() {
+            assert!(matches!(Router::new().get("/users/:id", text("a")).unwrap().get("/users/:name", text("b")), Err(AthenaError::ROUTE_CONFLICT(_, _))));
+            assert!(matches!(Router::new().get("/users", text("a")).unwrap().get("/USERS", text("b")), Err(AthenaError::ROUTE_CONFLICT(_, _))));
+            assert!(matches!(Router::new().get("users", text("a")), Err(AthenaError::ROUTE_PATTERN_INVALID(_, _))));
+            assert!(matches!(Router::new().get("/files/*rest/raw", text("a")), Err(AthenaError::ROUTE_PATTERN_INVALID(_, _))));
+            // 같은 패턴의 다른 Method 는 합친다
+            assert!(Router::new().get("/users/:id", text("a")).unwrap().post("/users/:id", text("b")).is_ok());
+        }
+
+
+        #[test]
+        fn answers_method_not_allowed_with_allow_header() {
+            let client : TestClient = client(Router::new()
+                .get("/users/:id", text("get")).unwrap()
+                .post("/users/:id", text("post")).unwrap());
+
+            client.send(TestRequest::post("/users/7")).assert_body("post");
+            client.send(TestRequest::new("DELETE", "/users/7"))
+                .assert_status(HttpStateCode::HTTP_405)
+                .assert_header("Allow", "GET, HEAD, POST, OPTIONS");
+        }
+
+
+        #[test]
+        fn answers_head_with_get_handler_and_no_body() {
+            let client : TestClient = client(Router::new().get("/hello", text("hello")).unwrap());
+
+            client.send(TestRequest::new("HEAD", "/hello"))
+                .assert_status(HttpStateCode::HTTP_200)
+                .assert_header("Content-Length", "5")
+                .assert_body("");
+        }
+
+
+        #[test]
+        fn answers_options_with_allow_header() {
+            let client : TestClient = client(Router::new()
+                .get("/hello", text("hello")).unwrap()
+                .route("/any", text("any")).unwrap());
+
+            client.send(TestRequest::new("OPTIONS", "/hello"))
+                .assert_status(HttpStateCode::HTTP_200)
+                .assert_header("Allow", "GET, HEAD, OPTIONS")
+                .assert_body("");
+            client.send(TestRequest::new("OPTIONS", "/any")).assert_body("any");
+        }
+
+
+        #[test]
+        fn answers_unmatched_paths_with_404_when_only_a_router_is_registered() {
+            let client : TestClient = client(Router::new().get("/users/:id", text("user")).unwrap());
+
+            client.get("/users/7/").assert_status(HttpStateCode::HTTP_404);
+            client.get("/missing").assert_status(HttpStateCode::HTTP_404);
+        }
+
+
+        #[test]
+        fn falls_through_to_event_response_when_no_route_matches() {
+            let server : Server = Server::builder()
+                .router(Router::new().get("/users/:id", text("user")).unwrap())
+                .event_response(text("event"))
+                .build();
+            let client : TestClient = TestClient::new(&server);
+
+            client.get("/users/7").assert_body("user");
+            client.get("/missing").assert_body("event");
+        }
+
+
+        #[test]
+        fn mounts_groups_with_prefix_params_and_group_middleware() {
+            let group : Router = Router::new()
+                .get("/", text("group index")).unwrap()
+                .get("/users/:id", param("tenant")).unwrap()
+                .middleware(|request : &mut Request, next : Next<'_>| {
+                    let mut response : Response = next.run(request);
+                    response.set_header("X-Group", String::from("yes"));
+                    response
+                });
+            let client : TestClient = client(Router::new()
+                .get("/health", text("ok")).unwrap()
+                .mount("/tenants/:tenant/", group).unwrap());
+
+            client.get("/tenants/acme").assert_body("group index").assert_header("X-Group", "yes");
+            client.get("/tenants/acme/users/1").assert_body("acme");
+            client.get("/health").assert_body("ok").assert_no_header("X-Group");
+
+            assert!(matches!(Router::new().mount("/files/*rest", Router::new()), Err(AthenaError::ROUTE_PATTERN_INVALID(_, _))));
+            assert!(matches!(Router::new().get("/admin/users", text("a")).unwrap().mount("/admin", Router::new().get("/users", text("b")).unwrap()), Err(AthenaError::ROUTE_CONFLICT(_, _))));
+            assert!(matches!(Router::new().get("/admin/:id", text("a")).unwrap().mount("/admin", Router::new().post("/:name", text("b")).unwrap()), Err(AthenaError::ROUTE_CONFLICT(_, _))));
+        }
+
+
+        #[test]
+        fn merges_group_methods_into_existing_routes() {
+            let admin : Router = Router::new()
+                .post("/users", text("create user")).unwrap()
+                .middleware(|request : &mut Request, next : Next<'_>| {
+                    let mut response : Response = next.run(request);
+                    response.set_header("X-Group", String::from("yes"));
+                    response
+                });
+            let client : TestClient = client(Router::new()
+                .get("/admin/users", text("list users")).unwrap()
+                .mount("/admin", admin).unwrap());
+
+            client.get("/admin/users").assert_body("list users").assert_no_header("X-Group");
+            client.post("/admin/users", "").assert_body("create user").assert_header("X-Group", "yes");
+            client.send(TestRequest::new("OPTIONS", "/admin/users")).assert_header("Allow", "GET, HEAD, POST, OPTIONS");
+        }
+
+
+        #[test]
+        fn prefers_group_fallback_over_less_specific_routes_outside_the_group() {
+            let admin : Router = Router::new()
+                .get("/users", text("admin users")).unwrap()
+                .fallback(text("admin fallback"));
+            let client : TestClient = client(Router::new()
+                .get("/:section/list", text("outer list")).unwrap()
+                .get("/admin/special", text("outer special")).unwrap()
+                .get("/*rest", text("outer wildcard")).unwrap()
+                .mount("/admin", admin).unwrap()
+                .fallback(text("root fallback")));
+
+            client.get("/admin/users").assert_body("admin users");
+            client.get("/admin/list").assert_body("admin fallback");
+            client.get("/admin/missing/deep").assert_body("admin fallback");
+            // Prefix 아래에 등록한 그룹 밖의 Route 는 그대로 사용
+            client.get("/admin/special").assert_body("outer special");
+            client.get("/blog/list").assert_body("outer list");
+            client.get("/other").assert_body("outer wildcard");
+        }
+    }
+}
@@ -0,0 +1,126 @@
+pub mod server_config {
+    use std::collections::HashMap;
+    use std::time::Duration;
+    use crate::server::thread_pool::thread_pool::QueueFullPolicy;
+    use crate::server::rate_limiter::rate_limiter::RateLimitPolicy;
+    use crate::server::proxy::proxy::{ForwardedHeader, TrustedProxy};
+
+
+    /// 서버 이름 반환
+    fn get_server_name() -> String { return String::from("Athena-Engine") }
+
+
+    /// 서버 설정 Struct
+    ///
+    /// Timeout, 요청 크기 제한, 접속 수 제한, 작업 Thread 수, 기본 응답 Header 를 설정한다.
+    /// `ServerConfig::default()` 로 기본값을 만든 뒤 필요한 값만 변경하여 사용한다.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::server_config::server_config::ServerConfig;
+    /// let config = ServerConfig {
+    ///     max_body_bytes: 1024 * 1024,
+    ///     worker_count: 32,
+    ///     ..ServerConfig::default()
+    /// };
+    /// # assert_eq!(config.worker_count, 32);
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct ServerConfig {
+        /// 요청 읽기 Timeout (`read` 호출 1회 기준)
+        pub read_timeout : Duration,
+        /// 응답 쓰기 Timeout (`write` 호출 1회 기준)
+        pub write_timeout : Duration,
+        /// Keep-Alive 접속의 다음 요청 대기 시간
+        pub idle_timeout : Duration,
+        /// Server-Sent Events 스트림에 이벤트가 없을 때 접속 유지용 주석을 보내는 간격
+        pub event_stream_keep_alive : Duration,
+        /// 요청의 첫 데이터를 받은 뒤 Header 를 모두 받을 때까지의 제한 시간 (초과 시 `408 Request Timeout`)
+        pub header_timeout : Duration,
+        /// 최소 Body 전송 속도, 초당 Byte (미달 시 `408 Request Timeout`, 0 이면 제한 없음)
+        pub min_body_bytes_per_second : usize,
+        /// 접속 하나에서 처리할 최대 요청 수 (1 이면 Keep-Alive 사용 안 함)
+        pub max_keep_alive_requests : usize,
+        /// 접속 하나를 Keep-Alive 로 유지할 최대 시간, 접속 시작 기준 (초과 후 응답은 `Connection: close`)
+        pub max_keep_alive_duration : Duration,
+        /// 요청 Line 과 Header 의 최대 크기 (초과 시 `431 Request Header Fields Too Large`)
+        pub max_header_bytes : usize,
+        /// 요청 Body 의 최대 크기 (초과 시 `413 Request Entity Too Large`)
+        pub max_body_bytes : usize,
+        /// 동시에 열어둘 최대 접속 수, 대기열 포함 (초과 시 `503 Service Unavailable`)
+        pub max_connections : usize,
+        /// 동시에 열어둘 최대 스트림 접속 수, WebSocket / HTTP/2 / Server-Sent Events (초과 시 `503 Service Unavailable`)
+        ///
+        /// 스트림 접속은 작업 Thread 대신 접속마다 전용 Thread 를 사용하며 `max_connections` 에도 포함된다.
+        pub max_stream_connections : usize,
+        /// 클라이언트 IP 별 최대 동시 접속 수 (초과 시 `429 Too Many Requests`, None 이면 제한 없음)
+        pub max_connections_per_ip : Option<usize>,
+        /// 클라이언트 IP 별 요청 수 제한 (초과 시 `429 Too Many Requests`, None 이면 제한 없음)
+        pub rate_limit : Option<RateLimitPolicy>,
+        /// 작업 Thread 수
+        pub worker_count : usize,
+        /// 작업 대기열 크기
+        pub queue_size : usize,
+        /// 작업 대기열이 가득 찼을 때 처리 방식
+        pub queue_full_policy : QueueFullPolicy,
+        /// 접속 대기 및 Keep-Alive 대기 중 종료 요청 확인 간격
+        pub shutdown_poll_interval : Duration,
+        /// 모든 접속이 PROXY protocol v1/v2 Header 로 시작하는지 여부 (Header 의 주소를 클라이언트 주소로 사용, 없으면 접속 종료)
+        ///
+        /// 접속 수 제한 (`max_connections_per_ip`) 은 접속한 Proxy 주소, 요청 수 제한 (`rate_limit`) 은 Header 의 주소 기준이다.
+        pub proxy_protocol : bool,
+        /// `forwarded_header` 로 클라이언트 주소를 전달하는 신뢰할 수 있는 Proxy 목록
+        pub trusted_proxies : Vec<TrustedProxy>,
+        /// 신뢰할 수 있는 Proxy 가 클라이언트 주소를 기록하는 Header (다른 Header 는 읽지 않음)
+        pub forwarded_header : ForwardedHeader,
+        /// 모든 응답에 기본으로 추가할 Header (Date, Content-Length, Connection 은 서버가 설정)
+        pub default_response_headers : HashMap<String, String>
+    }
+
+
+    impl Default for ServerConfig {
+        fn default() -> Self {
+            let mut default_response_headers : HashMap<String, String> = HashMap::new();
+            // 헤더 데이터 설정 - Server
+            default_response_headers.insert(String::from("Server"), get_server_name());
+            // 헤더 데이터 설정 - Pragma
+            default_response_headers.insert(String::from("Pragma"), String::from("no-cache"));
+            // 헤더 데이터 설정 - Content-Type
+            default_response_headers.insert(String::from("Content-Type"), String::from("text/html; charset=UTF-8"));
+            // 헤더 데이터 설정 - Content-Language
+            default_response_headers.insert(String::from("Content-Language"), String::from("ko-KR"));
+            // 헤더 데이터 설정 - Access-Control-Allow-Origin
+            default_response_headers.insert(String::from("Access-Control-Allow-Origin"), String::from("*"));
+            // 헤더 데이터 설정 - Content-Disposition
+            default_response_headers.insert(String::from("Content-Disposition"), String::from("inline"));
+            // 헤더 데이터 설정 - Cache-Control
+            default_response_headers.insert(String::from("Cache-Control"), String::from("no-cache"));
+
+            return ServerConfig {
+                read_timeout: Duration::from_secs(15),
+                write_timeout: Duration::from_secs(15),
+                idle_timeout: Duration::from_secs(5),
+                event_stream_keep_alive: Duration::from_secs(15),
+                header_timeout: Duration::from_secs(10),
+                min_body_bytes_per_second: 1024,
+                max_keep_alive_requests: 100,
+                max_keep_alive_duration: Duration::from_secs(60),
+                max_header_bytes: 16 * 1024,
+                max_body_bytes: 10 * 1024 * 1024,
+                max_connections: 1024,
+                max_stream_connections: 256,
+                max_connections_per_ip: None,
+                rate_limit: None,
+                worker_count: 16,
+                queue_size: 128,
+                queue_full_policy: QueueFullPolicy::BLOCK,
+                shutdown_poll_interval: Duration::from_millis(50),
+                proxy_protocol: false,
+                trusted_proxies: Vec::new(),
+                forwarded_header: ForwardedHeader::X_FORWARDED_FOR,
+                default_response_headers
+            };
+        }
+    }
+}
@@ -0,0 +1,175 @@
+pub mod server_handle {
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+    use std::sync::{Arc, Condvar, Mutex};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::{Duration, Instant};
+    use crate::log::{log_more_text_writer, LogTypeTag};
+    use crate::server::listener::listener::ListenAddress;
+
+
+    /// 현재 파일 정보 반환
+    fn get_this_name() -> String {
+        return String::from("main/server/server_handle");
+    }
+
+
+    /// 서버 실행 상태 (접속 대기 Thread, 작업 Thread, ServerHandle 이 공유)
+    pub(crate) struct ServerState {
+        shutdown : AtomicBool,
+        finished : Mutex<bool>,
+        finished_signal : Condvar
+    }
+
+    /// 실행 중인 서버 제어 Handle
+    ///
+    /// `Server::start()` 가 반환하며 복제하여 다른 Thread (Signal Handler, 테스트 등) 에서 서버를 종료할 수 있다.
+    #[derive(Clone)]
+    pub struct ServerHandle {
+        state : Arc<ServerState>,
+        local_addresses : Arc<Vec<ListenAddress>>
+    }
+
+
+    impl ServerState {
+        pub(crate) fn new() -> ServerState {
+            return ServerState {
+                shutdown: AtomicBool::new(false),
+                finished: Mutex::new(false),
+                finished_signal: Condvar::new()
+            };
+        }
+
+
+        /// 서버 종료 요청 여부
+        pub(crate) fn is_shutdown(&self) -> bool {
+            return self.shutdown.load(Ordering::SeqCst);
+        }
+
+
+        /// 서버 종료 요청
+        pub(crate) fn request_shutdown(&self) {
+            self.shutdown.store(true, Ordering::SeqCst);
+        }
+
+
+        /// 모든 작업 종료 알림
+        pub(crate) fn mark_finished(&self) {
+            let mut finished = self.finished.lock().unwrap_or_else(|error| error.into_inner());
+            *finished = true;
+            self.finished_signal.notify_all();
+        }
+
+
+        /// 모든 작업이 종료될 때까지 대기
+        ///
+        /// # Return
+        /// Timeout 전에 종료되면 true
+        fn wait_finished(&self, timeout : Option<Duration>) -> bool {
+            let deadline : Option<Instant> = timeout.map(|timeout| Instant::now() + timeout);
+            let mut finished = self.finished.lock().unwrap_or_else(|error| error.into_inner());
+
+            while !*finished {
+                match deadline {
+                    Some(deadline) => {
+                        let now = Instant::now();
+                        if now >= deadline {
+                            return false;
+                        }
+                        finished = match self.finished_signal.wait_timeout(finished, deadline - now) {
+                            Ok((finished, _)) => finished,
+                            Err(error) => error.into_inner().0
+                        };
+                    },
+                    None => {
+                        finished = self.finished_signal.wait(finished).unwrap_or_else(|error| error.into_inner());
+                    }
+                }
+            }
+
+            return true;
+        }
+    }
+
+
+    impl ServerHandle {
+        pub(crate) fn new(state : Arc<ServerState>, local_addresses : Vec<ListenAddress>) -> ServerHandle {
+            return ServerHandle {
+                state,
+                local_addresses: Arc::new(local_addresses)
+            };
+        }
+
+
+        /// 서버가 실제로 접속을 대기하는 첫 번째 TCP 주소 반환 (Port 0 으로 실행한 경우 할당된 Port 확인)
+        ///
+        /// TCP 주소 없이 Unix Domain Socket 으로만 실행한 경우 `0.0.0.0:0`
+        pub fn local_addr(&self) -> SocketAddr {
+            for address in self.local_addresses.iter() {
+                if let ListenAddress::TCP(address) = address {
+                    return *address;
+                }
+            }
+            return SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0);
+        }
+
+
+        /// 서버가 실제로 접속을 대기하는 모든 주소 반환
+        pub fn local_addrs(&self) -> Vec<ListenAddress> {
+            return self.local_addresses.to_vec();
+        }
+
+
+        /// 로그에 기록할 접속 대기 주소
+        pub(crate) fn address_text(&self) -> String {
+            return self.local_addresses.iter().map(|address| address.to_string()).collect::<Vec<String>>().join(", ");
+        }
+
+
+        /// 서버 실행 여부
+        pub fn is_running(&self) -> bool {
+            return !*self.state.finished.lock().unwrap_or_else(|error| error.into_inner());
+        }
+
+
+        /// 서버 종료
+        ///
+        /// 새 접속 수락을 중단하고 처리 중인 요청이 모두 끝날 때까지 대기한다.
+        pub fn shutdown(&self) {
+            self.shutdown_with_timeout_option(None);
+        }
+
+
+        /// 제한 시간 안에서 서버 종료
+        ///
+        /// # Argument
+        /// timeout : 처리 중인 요청을 기다릴 최대 시간
+        ///
+        /// # Return
+        /// 처리 중인 요청이 모두 끝났으면 true, 시간 초과시 false (남은 작업은 계속 실행된다)
+        pub fn shutdown_with_timeout(&self, timeout : Duration) -> bool {
+            return self.shutdown_with_timeout_option(Some(timeout));
+        }
+
+
+        /// 서버가 종료될 때까지 현재 Thread 대기
+        pub fn wait(&self) {
+            self.state.wait_finished(None);
+        }
+
+
+        fn shutdown_with_timeout_option(&self, timeout : Option<Duration>) -> bool {
+            // 로그 출력
+            println!("{}", log_more_text_writer(String::from("Server shutdown requested."), get_this_name(), LogTypeTag::INFO, self.address_text()));
+
+            self.state.request_shutdown();
+            let finished : bool = self.state.wait_finished(timeout);
+
+            if !finished {
+                // 로그 출력
+                println!("{}", log_more_text_writer(String::from("Server shutdown timed out, in-flight requests are still running."), get_this_name(), LogTypeTag::WARNING, self.address_text()));
+            }
+
+            return finished;
+        }
+    }
+}
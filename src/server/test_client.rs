@@ -0,0 +1,494 @@
+pub mod test_client {
+    use std::sync::Arc;
+    use crate::server::{attach_server_data, finalize_response, new_request_reader, run_event_handlers, state_response_text, strip_head_body, Server, ServerContext};
+    use crate::server::event_stream::event_stream::EventReceive;
+    use crate::server::request_parser::request_parser::{Method, Request};
+    use crate::server::request_reader::request_reader::{RawRequest, RequestReadError, RequestReader};
+    use crate::server::response_parser::response_parser::{default_http_state_writer, HttpStateCode, Response, response_parser};
+
+
+    /// 로그에 표시할 테스트 클라이언트 주소
+    const TEST_CLIENT_IP : &str = "test-client";
+
+
+    /// 접속 없이 서버의 이벤트 Handler 를 실행하는 테스트 클라이언트
+    ///
+    /// 요청을 `RequestReader` 와 `request_parser` 로 분석하고 등록된 Request / Response 이벤트를 실행한 뒤,
+    /// `response_parser` 로 만든 응답을 다시 분석하여 반환한다. Socket 을 사용하지 않으므로 여러 테스트를 병렬로 실행할 수 있다.
+    /// WebSocket, HTTP/2 전환과 IP 별 요청 수 제한은 적용하지 않는다.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::Server;
+    /// # use AthenaEngine::server::router::router::Router;
+    /// # use AthenaEngine::server::response_parser::response_parser::{default_state_response_writer, HttpStateCode, ResponseBody};
+    /// # use AthenaEngine::server::test_client::test_client::TestClient;
+    /// # fn main() -> Result<(), AthenaEngine::error::AthenaError> {
+    /// let router = Router::new().get("/hello", |_request| {
+    ///     let mut response = default_state_response_writer(HttpStateCode::HTTP_200);
+    ///     response.body = Some(ResponseBody { body_str: Some(String::from("Hello")) });
+    ///     response
+    /// })?;
+    /// let server = Server::builder()
+    ///     .router(router)
+    ///     .build();
+    /// let client = TestClient::new(&server);
+    ///
+    /// client.get("/hello")
+    ///     .assert_status(HttpStateCode::HTTP_200)
+    ///     .assert_body_contains("Hello");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[derive(Clone)]
+    pub struct TestClient {
+        context : Arc<ServerContext>
+    }
+
+    /// 테스트 요청 생성기
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::Server;
+    /// # use AthenaEngine::server::test_client::test_client::{TestClient, TestRequest};
+    /// # use AthenaEngine::server::response_parser::response_parser::default_response_writer;
+    /// # let server = Server::builder().event_response(|request| default_response_writer(request, None, None)).build();
+    /// # let client = TestClient::new(&server);
+    /// let request = TestRequest::post("/login")
+    ///     .header("Content-Type", "application/x-www-form-urlencoded")
+    ///     .cookie("session", "abc")
+    ///     .body("id=athena&pw=1234");
+    /// let response = client.send(request);
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct TestRequest {
+        method : String,
+        target : String,
+        headers : Vec<(String, String)>,
+        cookies : Vec<(String, String)>,
+        body : Vec<u8>
+    }
+
+    /// 테스트 응답 (`response_parser` 가 만든 응답을 분석한 결과)
+    #[derive(Clone, Debug)]
+    pub struct TestResponse {
+        /// 응답 HTTP 버전 (예: `HTTP/1.1`)
+        pub http_version : String,
+        /// 상태 코드 (예: 200)
+        pub status : u16,
+        /// 상태 문구 (예: `OK`)
+        pub reason : String,
+        /// 응답 Header (받은 순서, `Set-Cookie` 는 여러 번 나올 수 있다)
+        pub headers : Vec<(String, String)>,
+        /// 응답 Body (Server-Sent Events 응답이면 스트림이 닫힐 때까지 받은 이벤트)
+        pub body : String
+    }
+
+
+    impl TestClient {
+        /// 서버의 이벤트와 페이지, 설정을 사용하는 테스트 클라이언트 생성
+        ///
+        /// # Argument
+        /// server : `ServerBuilder::build` 로 만든 서버 (실행하지 않아도 된다)
+        pub fn new(server : &Server) -> TestClient {
+            return TestClient {
+                context: Arc::clone(&server.context)
+            };
+        }
+
+
+        /// GET 요청 전송
+        pub fn get(&self, target : &str) -> TestResponse {
+            return self.send(TestRequest::get(target));
+        }
+
+
+        /// POST 요청 전송
+        pub fn post(&self, target : &str, body : &str) -> TestResponse {
+            return self.send(TestRequest::post(target).body(body));
+        }
+
+
+        /// 테스트 요청 전송
+        pub fn send(&self, request : TestRequest) -> TestResponse {
+            return self.send_raw(&request.to_bytes());
+        }
+
+
+        /// 직접 작성한 요청 원본 전송 (요청 하나, `\r\n` 줄바꿈)
+        ///
+        /// 잘못된 요청은 서버와 같은 상태 코드 응답 (`400`, `413`, `431` 등) 을 반환한다.
+        ///
+        /// # Panics
+        /// Response 이벤트가 등록되지 않아 서버가 응답 없이 접속을 닫는 경우
+        pub fn send_raw(&self, raw : &[u8]) -> TestResponse {
+            let context : &ServerContext = &self.context;
+
+            // 요청 읽기
+            let mut reader : RequestReader = new_request_reader(&context.config);
+            reader.feed(raw);
+            let raw_request : RawRequest = match reader.poll_request() {
+                Ok(Some(raw_request)) => raw_request,
+                Ok(None) => return read_error_response(context, reader.end_of_stream_error()),
+                Err(error) => return read_error_response(context, error)
+            };
+
+            // Request 패킷 분석
+            let mut request : Request = raw_request.to_request();
+            attach_server_data(context, &mut request, TEST_CLIENT_IP);
+
+            // Request / Response 이벤트 실행
+            let request_method : Option<Method> = request.method;
+            let mut response : Response = match self.run_event_handlers(request) {
+                Some(response) => response,
+                None => panic!("No response EVENT handler is registered, the server would close the connection without a response.")
+            };
+
+            // Server-Sent Events 응답은 스트림이 닫힐 때까지 받은 이벤트를 Body 로 사용
+            if let Some(event_stream) = response.event_stream.take() {
+                let mut events : String = String::new();
+                while let EventReceive::EVENT(event) = event_stream.receive_timeout(context.config.read_timeout) {
+                    events.push_str(&event.to_frame());
+                }
+                if let Some(body) = response.body.as_mut() {
+                    body.body_str.get_or_insert_with(String::new).push_str(&events);
+                }
+            }
+            finalize_response(context, &mut response, false);
+            strip_head_body(request_method, &mut response);
+
+            return TestResponse::parse(&response_parser(response));
+        }
+
+
+        /// 등록된 이벤트 실행 (요청의 Host 에 비동기 Response 이벤트가 있으면 임시 Runtime 에서 실행)
+        fn run_event_handlers(&self, mut request : Request) -> Option<Response> {
+            #[cfg(feature = "async")]
+            if self.context.client_event(&request).is_some_and(|event| event.event_response_async.is_some()) {
+                let runtime = match tokio::runtime::Builder::new_current_thread().enable_time().build() {
+                    Ok(runtime) => runtime,
+                    Err(error) => panic!("Failed to create test runtime: {}", error)
+                };
+                return runtime.block_on(crate::server::async_server::async_server::run_event_handlers_async(&self.context, request, TEST_CLIENT_IP));
+            }
+
+            return run_event_handlers(&self.context, &mut request, TEST_CLIENT_IP);
+        }
+    }
+
+
+    impl TestRequest {
+        /// 요청 생성
+        ///
+        /// # Argument
+        /// method : 요청 Method (예: `GET`)
+        ///
+        /// target : 요청 경로와 Query (예: `/search?q=athena`)
+        pub fn new(method : &str, target : &str) -> TestRequest {
+            return TestRequest {
+                method: String::from(method),
+                target: String::from(target),
+                headers: Vec::new(),
+                cookies: Vec::new(),
+                body: Vec::new()
+            };
+        }
+
+
+        /// GET 요청 생성
+        pub fn get(target : &str) -> TestRequest {
+            return TestRequest::new("GET", target);
+        }
+
+
+        /// POST 요청 생성
+        pub fn post(target : &str) -> TestRequest {
+            return TestRequest::new("POST", target);
+        }
+
+
+        /// Header 추가
+        pub fn header(mut self, name : &str, value : &str) -> TestRequest {
+            self.headers.push((String::from(name), String::from(value)));
+            return self;
+        }
+
+
+        /// Cookie 추가
+        pub fn cookie(mut self, name : &str, value : &str) -> TestRequest {
+            self.cookies.push((String::from(name), String::from(value)));
+            return self;
+        }
+
+
+        /// Body 설정 (`Content-Length` 는 자동으로 설정)
+        pub fn body(mut self, body : &str) -> TestRequest {
+            self.body = body.as_bytes().to_vec();
+            return self;
+        }
+
+
+        /// HTTP/1.1 요청 원본으로 변환
+        pub fn to_bytes(&self) -> Vec<u8> {
+            let has_header = |name : &str| -> bool {
+                return self.headers.iter().any(|(key, _)| key.eq_ignore_ascii_case(name));
+            };
+
+            let mut head : String = format!("{} {} HTTP/1.1\r\n", self.method, self.target);
+            if !has_header("Host") {
+                head.push_str("Host: localhost\r\n");
+            }
+            for (name, value) in &self.headers {
+                head.push_str(&format!("{}: {}\r\n", name, value));
+            }
+            if !self.cookies.is_empty() {
+                let cookies : Vec<String> = self.cookies.iter().map(|(name, value)| format!("{}={}", name, value)).collect();
+                head.push_str(&format!("Cookie: {}\r\n", cookies.join("; ")));
+            }
+            if !self.body.is_empty() && !has_header("Content-Length") && !has_header("Transfer-Encoding") {
+                head.push_str(&format!("Content-Length: {}\r\n", self.body.len()));
+            }
+            head.push_str("\r\n");
+
+            let mut raw : Vec<u8> = head.into_bytes();
+            raw.extend_from_slice(&self.body);
+            return raw;
+        }
+    }
+
+
+    impl TestResponse {
+        /// 응답 문자열 분석
+        ///
+        /// # Panics
+        /// 상태 Line 이 올바르지 않은 경우
+        pub fn parse(raw : &str) -> TestResponse {
+            let (head, body) : (&str, &str) = raw.split_once("\r\n\r\n").unwrap_or((raw, ""));
+            let mut lines = head.split("\r\n");
+            let status_line : &str = lines.next().unwrap_or("");
+
+            let mut parts = status_line.splitn(3, ' ');
+            let http_version : String = parts.next().unwrap_or("").to_string();
+            let status : u16 = match parts.next().and_then(|status| status.parse().ok()) {
+                Some(status) => status,
+                None => panic!("Invalid response status line: {:?}", status_line)
+            };
+            let reason : String = parts.next().unwrap_or("").to_string();
+
+            let headers : Vec<(String, String)> = lines
+                .filter_map(|line| line.split_once(':'))
+                .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+                .collect();
+
+            return TestResponse {
+                http_version,
+                status,
+                reason,
+                headers,
+                body: body.to_string()
+            };
+        }
+
+
+        /// Header 값 반환 (이름 대소문자 구분 없음, 같은 이름이 여러 개면 첫 번째 값)
+        pub fn header(&self, name : &str) -> Option<&str> {
+            return self.headers.iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str());
+        }
+
+
+        /// 같은 이름의 모든 Header 값 반환 (`Set-Cookie` 등)
+        pub fn header_all(&self, name : &str) -> Vec<&str> {
+            return self.headers.iter()
+                .filter(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+                .collect();
+        }
+
+
+        /// 상태 코드 확인
+        #[track_caller]
+        pub fn assert_status(&self, http_code : HttpStateCode) -> &TestResponse {
+            let expected : &str = default_http_state_writer(&http_code);
+            let actual : String = format!("{} {}", self.status, self.reason);
+            assert_eq!(actual, expected, "unexpected response status, body: {:?}", self.body);
+            return self;
+        }
+
+
+        /// Header 값 확인
+        #[track_caller]
+        pub fn assert_header(&self, name : &str, value : &str) -> &TestResponse {
+            assert_eq!(self.header(name), Some(value), "unexpected value for header {:?}", name);
+            return self;
+        }
+
+
+        /// Header 존재 여부 확인
+        #[track_caller]
+        pub fn assert_has_header(&self, name : &str) -> &TestResponse {
+            assert!(self.header(name).is_some(), "missing header {:?}, headers: {:?}", name, self.headers);
+            return self;
+        }
+
+
+        /// Header 가 없는지 확인
+        #[track_caller]
+        pub fn assert_no_header(&self, name : &str) -> &TestResponse {
+            assert!(self.header(name).is_none(), "unexpected header {:?}: {:?}", name, self.header(name));
+            return self;
+        }
+
+
+        /// Body 전체 확인
+        #[track_caller]
+        pub fn assert_body(&self, body : &str) -> &TestResponse {
+            assert_eq!(self.body, body, "unexpected response body");
+            return self;
+        }
+
+
+        /// Body 에 문자열이 포함되어 있는지 확인
+        #[track_caller]
+        pub fn assert_body_contains(&self, text : &str) -> &TestResponse {
+            assert!(self.body.contains(text), "response body does not contain {:?}, body: {:?}", text, self.body);
+            return self;
+        }
+    }
+
+
+    /// 실행 중인 서버의 응답 하나를 읽기 (`Content-Length` 만큼 Body 를 읽으므로 Keep-Alive 접속에서 사용할 수 있다)
+    #[cfg(test)]
+    pub(crate) fn read_response<R : std::io::Read>(stream : &mut R) -> TestResponse {
+        let mut raw : Vec<u8> = Vec::new();
+        let mut byte = [0; 1];
+        while !raw.ends_with(b"\r\n\r\n") {
+            if stream.read(&mut byte).expect("response header") == 0 {
+                break;
+            }
+            raw.push(byte[0]);
+        }
+        let head : TestResponse = TestResponse::parse(&String::from_utf8_lossy(&raw));
+        let mut body : Vec<u8> = vec![0; head.header("Content-Length").and_then(|length| length.parse().ok()).unwrap_or(0)];
+        stream.read_exact(&mut body).expect("response body");
+        raw.extend_from_slice(&body);
+
+        return TestResponse::parse(&String::from_utf8_lossy(&raw));
+    }
+
+
+    /// 요청 읽기 실패시 서버가 보내는 상태 코드 응답 생성
+    fn read_error_response(context : &ServerContext, error : RequestReadError) -> TestResponse {
+        return match error.http_state_code() {
+            Some(http_code) => TestResponse::parse(&state_response_text(&context.config, http_code)),
+            None => panic!("Request could not be read: {}", error)
+        };
+    }
+
+
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::io::{Read, Write};
+        use std::net::TcpStream;
+        use crate::server::ServerBuilder;
+        use crate::server::response_parser::response_parser::{ResponseBody, ResponseCookies, state_response_writer};
+        use crate::server::server_config::server_config::ServerConfig;
+
+
+        /// 요청 내용을 Body 로 돌려주고 Header 와 Cookie 를 설정하는 서버
+        fn echo_server() -> ServerBuilder {
+            return Server::builder().event_response(|request : &Request| {
+                let mut response : Response = state_response_writer(HttpStateCode::HTTP_200, &ServerConfig::default());
+                let name : String = request.header("X-Name").cloned().unwrap_or_default();
+                let session : String = request.cookies.as_ref().and_then(|cookies| cookies.get("session").cloned()).unwrap_or_default();
+                let body : String = request.body.clone().unwrap_or_default();
+                response.set_header("X-Reply", format!("hello {}", name));
+                response.cookies = Some(vec![ResponseCookies {
+                    name: String::from("visited"),
+                    value: String::from("yes"),
+                    path: String::from("/")
+                }]);
+                response.body = Some(ResponseBody {
+                    body_str: Some(format!("{} {} session={} body={}", request.method.map(|method| format!("{:?}", method)).unwrap_or_default(), request.target.clone().unwrap_or_default(), session, body.trim_end()))
+                });
+                response
+            });
+        }
+
+
+        #[test]
+        fn returns_status_headers_and_body_from_handlers() {
+            let client : TestClient = TestClient::new(&echo_server().build());
+
+            let response : TestResponse = client.send(TestRequest::post("/login?next=/")
+                .header("X-Name", "athena")
+                .cookie("session", "abc")
+                .body("id=athena"));
+            response.assert_status(HttpStateCode::HTTP_200)
+                .assert_header("X-Reply", "hello athena")
+                .assert_header("Content-Length", &response.body.len().to_string())
+                .assert_has_header("Date")
+                .assert_has_header("Server")
+                .assert_body("POST /login?next=/ session=abc body=id=athena");
+            assert_eq!(response.http_version, "HTTP/1.1");
+            assert_eq!(response.header_all("Set-Cookie"), vec!["visited=yes; Path=/"]);
+        }
+
+
+        #[test]
+        fn strips_body_from_head_responses() {
+            let client : TestClient = TestClient::new(&echo_server().build());
+
+            let response : TestResponse = client.send(TestRequest::new("HEAD", "/"));
+            response.assert_status(HttpStateCode::HTTP_200)
+                .assert_body("");
+            assert_ne!(response.header("Content-Length"), Some("0"));
+        }
+
+
+        #[test]
+        fn answers_unreadable_requests_with_server_status_codes() {
+            let client : TestClient = TestClient::new(&echo_server()
+                .max_header_bytes(64)
+                .max_body_bytes(4)
+                .build());
+
+            client.send_raw(b"GET / HTTP/1.1\r\nContent-Length: x\r\n\r\n").assert_status(HttpStateCode::HTTP_400);
+            client.send_raw(b"GET / HTTP/1.1\r\nX-Long: 0123456789012345678901234567890123456789012345678901234567890123\r\n\r\n").assert_status(HttpStateCode::HTTP_431);
+            client.post("/", "too long").assert_status(HttpStateCode::HTTP_413);
+        }
+
+
+        #[test]
+        fn matches_responses_of_running_server() {
+            let server : Server = echo_server().bind(String::from("127.0.0.1"), 0).build();
+            let client : TestClient = TestClient::new(&server);
+            let handle = server.start().unwrap();
+
+            let request : TestRequest = TestRequest::post("/form")
+                .header("Connection", "close")
+                .header("X-Name", "tcp")
+                .cookie("session", "xyz")
+                .body("a=1");
+            let mut stream : TcpStream = TcpStream::connect(handle.local_addr()).unwrap();
+            stream.write_all(&request.to_bytes()).unwrap();
+            let mut raw : String = String::new();
+            stream.read_to_string(&mut raw).unwrap();
+            handle.shutdown();
+
+            let expected : TestResponse = client.send(request);
+            let actual : TestResponse = TestResponse::parse(&raw);
+            assert_eq!(actual.status, expected.status);
+            assert_eq!(actual.reason, expected.reason);
+            assert_eq!(actual.body, expected.body);
+            for name in ["X-Reply", "Set-Cookie", "Content-Length", "Content-Type"] {
+                assert_eq!(actual.header(name), expected.header(name), "header {:?}", name);
+            }
+        }
+    }
+}
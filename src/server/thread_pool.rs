@@ -0,0 +1,279 @@
+pub mod thread_pool {
+    use std::sync::{Arc, Mutex};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+    use std::thread::JoinHandle;
+    use crate::log::{log_more_text_writer, LogTypeTag};
+    use crate::error::AthenaError;
+
+
+    /// 현재 파일 정보 반환
+    fn get_this_name() -> String {
+        return String::from("main/server/thread_pool");
+    }
+
+
+    /// 작업 처리 함수 지정
+    pub type TaskHandler<T> = Arc<dyn Send + Sync + Fn(T) + 'static>;
+
+    /// 작업 대기열이 가득 찼을 때 처리 방식 Enum
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    pub enum QueueFullPolicy {
+        /// 대기열에 자리가 생길 때까지 대기
+        BLOCK,
+        /// 작업 거부 (호출자에게 작업 반환)
+        REJECT
+    }
+
+    /// 고정 크기 작업 Thread Pool
+    ///
+    /// 작업자 수와 대기열 크기가 고정되어 있어 접속이 몰려도 OS Thread 가 무한히 늘어나지 않는다.
+    pub struct ThreadPool<T : Send + 'static> {
+        workers : Vec<JoinHandle<()>>,
+        sender : Option<SyncSender<T>>,
+        queue_depth : Arc<AtomicUsize>,
+        queue_size : usize,
+        policy : QueueFullPolicy
+    }
+
+
+    impl<T : Send + 'static> ThreadPool<T> {
+        /// Thread Pool 생성
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use std::sync::Arc;
+        /// # use AthenaEngine::error::AthenaError;
+        /// # use AthenaEngine::server::thread_pool::thread_pool::{QueueFullPolicy, ThreadPool};
+        /// # fn main() -> Result<(), AthenaError> {
+        /// let pool = ThreadPool::new(8, 64, QueueFullPolicy::BLOCK, Arc::new(|task : String| println!("{}", task)))?;
+        /// # let _ = pool.execute(String::from("hello"), "127.0.0.1");
+        /// # Ok(())
+        /// # }
+        /// ```
+        ///
+        /// # Argument
+        /// worker_count : 작업 Thread 수 (최소 1)
+        ///
+        /// queue_size : 작업 대기열 크기 (최소 1)
+        ///
+        /// policy : 대기열이 가득 찼을 때 처리 방식
+        ///
+        /// handler : 작업 처리 함수
+        ///
+        /// # Return
+        /// ThreadPool 구조체, 작업 Thread 생성 실패시 AthenaError (먼저 생성한 작업 Thread 는 종료)
+        pub fn new(worker_count : usize, queue_size : usize, policy : QueueFullPolicy, handler : TaskHandler<T>) -> Result<ThreadPool<T>, AthenaError> {
+            let worker_count = worker_count.max(1);
+            let queue_size = queue_size.max(1);
+            let (sender, receiver) : (SyncSender<T>, Receiver<T>) = mpsc::sync_channel(queue_size);
+            let receiver = Arc::new(Mutex::new(receiver));
+            let queue_depth = Arc::new(AtomicUsize::new(0));
+
+            let mut workers : Vec<JoinHandle<()>> = Vec::with_capacity(worker_count);
+            for index in 0..worker_count {
+                let receiver = Arc::clone(&receiver);
+                let queue_depth = Arc::clone(&queue_depth);
+                let handler = Arc::clone(&handler);
+                let spawn_result = std::thread::Builder::new()
+                    .name(format!("athena-worker-{}", index))
+                    .spawn(move || {
+                        loop {
+                            // 작업 수신 (대기열 Lock 은 수신 시에만 유지)
+                            let task = match receiver.lock() {
+                                Ok(receiver) => receiver.recv(),
+                                Err(_) => return
+                            };
+                            match task {
+                                Ok(task) => {
+                                    queue_depth.fetch_sub(1, Ordering::SeqCst);
+                                    handler(task);
+                                },
+                                Err(_) => { // Pool 종료
+                                    return;
+                                }
+                            }
+                        }
+                    });
+                match spawn_result {
+                    Ok(worker) => workers.push(worker),
+                    Err(error) => {
+                        // 대기열을 닫아 먼저 생성한 작업 Thread 종료
+                        drop(sender);
+                        for worker in workers {
+                            let _ = worker.join();
+                        }
+                        return Err(AthenaError::THREAD_SPAWN_FAILED(error));
+                    }
+                }
+            }
+
+            return Ok(ThreadPool {
+                workers,
+                sender: Some(sender),
+                queue_depth,
+                queue_size,
+                policy
+            });
+        }
+
+
+        /// 작업 전송
+        ///
+        /// # Argument
+        /// task : 처리할 작업
+        ///
+        /// client_ip : 로그에 기록할 클라이언트 IP 주소
+        ///
+        /// # Return
+        /// 대기열이 가득 차 거부된 경우 (`QueueFullPolicy::REJECT`) 또는 Pool 이 종료된 경우 작업을 되돌려준다
+        pub fn execute(&self, task : T, client_ip : &str) -> Result<(), T> {
+            let sender = match &self.sender {
+                Some(sender) => sender,
+                None => return Err(task)
+            };
+
+            self.queue_depth.fetch_add(1, Ordering::SeqCst);
+            // 실패시 작업과 대기열 가득 참 여부 반환
+            let result : Result<(), (T, bool)> = match self.policy {
+                QueueFullPolicy::BLOCK => sender.send(task).map_err(|error| (error.0, false)),
+                QueueFullPolicy::REJECT => match sender.try_send(task) {
+                    Ok(_) => Ok(()),
+                    Err(TrySendError::Full(task)) => Err((task, true)),
+                    Err(TrySendError::Disconnected(task)) => Err((task, false))
+                }
+            };
+
+            return match result {
+                Ok(_) => {
+                    // 로그 출력
+                    println!("{}", log_more_text_writer(String::from("Connection queued."), get_this_name(), LogTypeTag::DEBUG, format!("IP:{} QUEUE:{}/{}", client_ip, self.queue_depth(), self.queue_size)));
+                    Ok(())
+                },
+                Err((task, is_full)) => {
+                    self.queue_depth.fetch_sub(1, Ordering::SeqCst);
+
+                    // 로그 출력
+                    let message : &str = if is_full { "Connection rejected, work queue is full." } else { "Connection rejected, thread pool is shut down." };
+                    println!("{}", log_more_text_writer(String::from(message), get_this_name(), LogTypeTag::WARNING, format!("IP:{} QUEUE:{}/{}", client_ip, self.queue_depth(), self.queue_size)));
+                    Err(task)
+                }
+            };
+        }
+
+
+        /// 현재 대기열에 쌓인 작업 수 반환
+        pub fn queue_depth(&self) -> usize {
+            return self.queue_depth.load(Ordering::SeqCst).min(self.queue_size);
+        }
+
+
+        /// 작업 Thread 수 반환
+        pub fn worker_count(&self) -> usize {
+            return self.workers.len();
+        }
+    }
+
+
+    impl<T : Send + 'static> Drop for ThreadPool<T> {
+        /// 대기열을 닫고 남은 작업을 모두 처리한 뒤 작업 Thread 종료
+        fn drop(&mut self) {
+            drop(self.sender.take());
+
+            for worker in self.workers.drain(..) {
+                let _ = worker.join();
+            }
+        }
+    }
+
+
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::sync::mpsc::Sender;
+        use std::time::{Duration, Instant};
+
+
+        /// `release` 로 신호를 받을 때까지 작업을 멈추는 Pool (처리한 작업은 `done` 으로 전송)
+        fn gated_pool(policy : QueueFullPolicy) -> (ThreadPool<u32>, Sender<()>, Receiver<u32>) {
+            let (release, gate) : (Sender<()>, Receiver<()>) = mpsc::channel();
+            let (done_sender, done) : (Sender<u32>, Receiver<u32>) = mpsc::channel();
+            let gate = Mutex::new(gate);
+            let done_sender = Mutex::new(done_sender);
+            let pool : ThreadPool<u32> = ThreadPool::new(1, 1, policy, Arc::new(move |task : u32| {
+                let _ = gate.lock().unwrap().recv();
+                let _ = done_sender.lock().unwrap().send(task);
+            })).unwrap();
+            return (pool, release, done);
+        }
+
+
+        /// 작업 Thread 가 대기열의 작업을 가져갈 때까지 대기
+        fn wait_until_taken(pool : &ThreadPool<u32>) {
+            let deadline : Instant = Instant::now() + Duration::from_secs(5);
+            while pool.queue_depth() > 0 {
+                assert!(Instant::now() < deadline, "worker did not take the queued task");
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        }
+
+
+        #[test]
+        fn rejects_tasks_beyond_workers_and_queue() {
+            let (pool, release, done) = gated_pool(QueueFullPolicy::REJECT);
+
+            assert!(pool.execute(1, "test").is_ok());
+            wait_until_taken(&pool);
+            assert!(pool.execute(2, "test").is_ok());
+            assert_eq!(pool.queue_depth(), 1);
+            // 작업 Thread 1 개와 대기열 1 칸이 모두 사용 중
+            assert_eq!(pool.execute(3, "test"), Err(3));
+            assert_eq!(pool.queue_depth(), 1);
+
+            release.send(()).unwrap();
+            release.send(()).unwrap();
+            assert_eq!(done.recv_timeout(Duration::from_secs(5)), Ok(1));
+            assert_eq!(done.recv_timeout(Duration::from_secs(5)), Ok(2));
+            assert_eq!(pool.queue_depth(), 0);
+        }
+
+
+        #[test]
+        fn blocks_until_queue_has_room() {
+            let (pool, release, done) = gated_pool(QueueFullPolicy::BLOCK);
+            let pool = Arc::new(pool);
+
+            assert!(pool.execute(1, "test").is_ok());
+            wait_until_taken(&pool);
+            assert!(pool.execute(2, "test").is_ok());
+
+            let blocked_pool = Arc::clone(&pool);
+            let blocked = std::thread::spawn(move || blocked_pool.execute(3, "test"));
+            std::thread::sleep(Duration::from_millis(50));
+            assert!(!blocked.is_finished());
+
+            for _ in 0..3 {
+                release.send(()).unwrap();
+            }
+            assert_eq!(blocked.join().unwrap(), Ok(()));
+            let processed : Vec<u32> = (0..3).map(|_| done.recv_timeout(Duration::from_secs(5)).unwrap()).collect();
+            assert_eq!(processed, vec![1, 2, 3]);
+        }
+
+
+        #[test]
+        fn runs_queued_tasks_before_dropping_workers() {
+            let (pool, release, done) = gated_pool(QueueFullPolicy::BLOCK);
+            assert_eq!(pool.worker_count(), 1);
+
+            assert!(pool.execute(1, "test").is_ok());
+            assert!(pool.execute(2, "test").is_ok());
+            release.send(()).unwrap();
+            release.send(()).unwrap();
+            drop(pool);
+            assert_eq!(done.try_iter().collect::<Vec<u32>>(), vec![1, 2]);
+        }
+    }
+}
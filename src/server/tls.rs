@@ -0,0 +1,124 @@
+pub mod tls {
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::net::TcpStream;
+    use std::sync::Arc;
+    use rustls::{ServerConnection, StreamOwned};
+    use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+    use crate::error::AthenaError;
+    use crate::server::client_stream::client_stream::ClientStream;
+
+
+    /// HTTPS 설정 Struct
+    ///
+    /// PEM 형식의 인증서와 개인키로 생성하며 `ServerBuilder::tls()` 에 전달한다.
+    #[derive(Clone)]
+    pub struct TlsConfig {
+        server_config : Arc<rustls::ServerConfig>
+    }
+
+
+    impl TlsConfig {
+        /// PEM 파일로 HTTPS 설정 생성
+        ///
+        /// # Examples
+        ///
+        /// ```no_run
+        /// # use AthenaEngine::error::AthenaError;
+        /// # use AthenaEngine::server::tls::tls::TlsConfig;
+        /// # fn main() -> Result<(), AthenaError> {
+        /// let tls_config = TlsConfig::from_pem_files(String::from("cert.pem"), String::from("key.pem"))?;
+        /// # Ok(())
+        /// # }
+        /// ```
+        ///
+        /// # Argument
+        /// cert_path : 인증서 PEM 파일 경로 (인증서 체인 포함 가능)
+        ///
+        /// key_path : 개인키 PEM 파일 경로 (PKCS#8, PKCS#1, SEC1)
+        ///
+        /// # Return
+        /// TlsConfig 구조체, 파일 읽기/형식 오류시 AthenaError
+        pub fn from_pem_files(cert_path : String, key_path : String) -> Result<TlsConfig, AthenaError> {
+            let certs : Vec<CertificateDer<'static>> = {
+                let mut reader = BufReader::new(open_pem_file(&cert_path)?);
+                match rustls_pemfile::certs(&mut reader).collect::<Result<Vec<_>, _>>() {
+                    Ok(certs) => certs,
+                    Err(error) => return Err(AthenaError::TLS_CONFIG_FAILED(format!("{}: {}", cert_path, error)))
+                }
+            };
+            if certs.is_empty() {
+                return Err(AthenaError::TLS_CONFIG_FAILED(format!("{}: no certificate found", cert_path)));
+            }
+
+            let key : PrivateKeyDer<'static> = {
+                let mut reader = BufReader::new(open_pem_file(&key_path)?);
+                match rustls_pemfile::private_key(&mut reader) {
+                    Ok(Some(key)) => key,
+                    Ok(None) => return Err(AthenaError::TLS_CONFIG_FAILED(format!("{}: no private key found", key_path))),
+                    Err(error) => return Err(AthenaError::TLS_CONFIG_FAILED(format!("{}: {}", key_path, error)))
+                }
+            };
+
+            return TlsConfig::from_der(certs, key);
+        }
+
+
+        /// DER 형식의 인증서와 개인키로 HTTPS 설정 생성
+        ///
+        /// # Argument
+        /// certs : 인증서 체인
+        ///
+        /// key : 개인키
+        ///
+        /// # Return
+        /// TlsConfig 구조체, 인증서와 개인키가 맞지 않으면 AthenaError
+        pub fn from_der(certs : Vec<CertificateDer<'static>>, key : PrivateKeyDer<'static>) -> Result<TlsConfig, AthenaError> {
+            let provider = Arc::new(rustls::crypto::ring::default_provider());
+            let builder = match rustls::ServerConfig::builder_with_provider(provider).with_safe_default_protocol_versions() {
+                Ok(builder) => builder,
+                Err(error) => return Err(AthenaError::TLS_CONFIG_FAILED(error.to_string()))
+            };
+            let mut server_config : rustls::ServerConfig = match builder.with_no_client_auth().with_single_cert(certs, key) {
+                Ok(server_config) => server_config,
+                Err(error) => return Err(AthenaError::TLS_CONFIG_FAILED(error.to_string()))
+            };
+            server_config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+            return Ok(TlsConfig {
+                server_config: Arc::new(server_config)
+            });
+        }
+
+
+        /// rustls 서버 설정으로 HTTPS 설정 생성 (ALPN, 클라이언트 인증 등 직접 설정)
+        pub fn from_rustls_config(server_config : Arc<rustls::ServerConfig>) -> TlsConfig {
+            return TlsConfig {
+                server_config
+            };
+        }
+
+
+        /// 접속한 TCP Stream 을 TLS Stream 으로 감싼다 (Handshake 는 첫 읽기/쓰기에서 진행)
+        pub(crate) fn accept(&self, tcp_stream : TcpStream) -> Result<ClientStream, rustls::Error> {
+            let connection : ServerConnection = ServerConnection::new(Arc::clone(&self.server_config))?;
+            return Ok(ClientStream::TLS(Box::new(StreamOwned::new(connection, tcp_stream))));
+        }
+
+
+        /// tokio-rustls Acceptor 생성 (`start_async` 의 HTTPS 접속)
+        #[cfg(feature = "async")]
+        pub(crate) fn async_acceptor(&self) -> tokio_rustls::TlsAcceptor {
+            return tokio_rustls::TlsAcceptor::from(Arc::clone(&self.server_config));
+        }
+    }
+
+
+    /// PEM 파일 열기
+    fn open_pem_file(path : &str) -> Result<File, AthenaError> {
+        return match File::open(path) {
+            Ok(file) => Ok(file),
+            Err(error) => Err(AthenaError::TLS_CONFIG_FAILED(format!("{}: {}", path, error)))
+        };
+    }
+}
@@ -0,0 +1,320 @@
+pub mod virtual_host {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use crate::server::{ClientEvent, ResponseHandler};
+    use crate::server::page_manager::page_manager::{AllPages, PageInfo};
+    use crate::server::request_parser::request_parser::Request;
+    use crate::server::response_parser::response_parser::Response;
+    use crate::server::router::router::Router;
+    use crate::server::middleware::middleware::Middleware;
+
+
+    /// Host 이름 하나로 제공하는 사이트 (페이지 리스트와 요청/응답 이벤트)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use AthenaEngine::server::Server;
+    /// # use AthenaEngine::server::page_manager::page_manager::PageInfo;
+    /// # use AthenaEngine::server::response_parser::response_parser::default_response_writer;
+    /// # use AthenaEngine::server::virtual_host::virtual_host::VirtualHost;
+    /// # let blog_index = PageInfo { file_path: String::from("blog/index.html"), is_access: true };
+    /// let blog = VirtualHost::new()
+    ///     .page(String::from("/index.html"), blog_index)
+    ///     .event_response(|request| default_response_writer(request, None, None));
+    /// # let _ =
+    /// Server::builder()
+    ///     .virtual_host(String::from("blog.example.com"), blog)
+    /// # ;
+    /// ```
+    pub struct VirtualHost {
+        pub(crate) pages : Arc<AllPages>,
+        pub(crate) event : ClientEvent
+    }
+
+    /// 서버에 등록된 가상 호스트 목록
+    pub(crate) struct VirtualHosts {
+        /// 정확히 일치하는 Host 이름
+        exact : HashMap<String, VirtualHost>,
+        /// `*.example.com` 형식 (Host 이름 끝부분 `.example.com` 으로 비교, 긴 것부터)
+        wildcard : Vec<(String, VirtualHost)>,
+        /// 일치하는 Host 가 없을 때 사용할 가상 호스트 이름
+        default_host : Option<String>,
+        /// 일치하는 Host 도 기본 Host 도 없을 때의 응답
+        unknown_host : Option<ResponseHandler>
+    }
+
+
+    impl VirtualHost {
+        /// 빈 가상 호스트 생성
+        pub fn new() -> VirtualHost {
+            return VirtualHost {
+                pages: Arc::new(AllPages::new()),
+                event: ClientEvent {
+                    event_request: None,
+                    event_response: None,
+                    router: None,
+                    middlewares: Vec::new(),
+                    #[cfg(feature = "async")]
+                    event_response_async: None
+                }
+            };
+        }
+
+
+        /// 페이지 추가
+        ///
+        /// # Argument
+        /// page_path : HTTP 경로
+        ///
+        /// page_info : 페이지 정보
+        pub fn page(mut self, page_path : String, page_info : PageInfo) -> VirtualHost {
+            // 서버 생성 전에는 다른 곳에서 공유하지 않는다
+            if let Some(pages) = Arc::get_mut(&mut self.pages) {
+                pages.insert_page(page_path, page_info);
+            }
+            return self;
+        }
+
+
+        /// 페이지 리스트 설정 (기존 페이지 리스트 대체)
+        pub fn pages(mut self, pages : AllPages) -> VirtualHost {
+            self.pages = Arc::new(pages);
+            return self;
+        }
+
+
+        /// Request 이벤트 설정
+        pub fn event_request<F>(mut self, handler : F) -> VirtualHost
+            where F : Fn(&Request) + Send + Sync + 'static {
+            self.event.event_request = Some(Box::new(handler));
+            return self;
+        }
+
+
+        /// Response 이벤트 설정
+        pub fn event_response<F>(mut self, handler : F) -> VirtualHost
+            where F : Fn(&Request) -> Response + Send + Sync + 'static {
+            self.event.event_response = Some(Box::new(handler));
+            return self;
+        }
+
+
+        /// Router 설정 (일치하는 Route 가 없으면 Response 이벤트 실행, Response 이벤트가 없으면 `404 Not Found`)
+        pub fn router(mut self, router : Router) -> VirtualHost {
+            self.event.router = Some(router);
+            return self;
+        }
+
+
+        /// 가상 호스트의 모든 요청에 적용할 Middleware 추가 (먼저 추가한 Middleware 가 바깥쪽에서 실행)
+        ///
+        /// 가상 호스트로 온 WebSocket Upgrade 요청도 Handshake 전에 실행한다.
+        pub fn middleware<M>(mut self, middleware : M) -> VirtualHost
+            where M : Middleware + 'static {
+            self.event.middlewares.push(Arc::new(middleware));
+            return self;
+        }
+    }
+
+
+    impl Default for VirtualHost {
+        fn default() -> Self {
+            return VirtualHost::new();
+        }
+    }
+
+
+    impl VirtualHosts {
+        pub(crate) fn new() -> VirtualHosts {
+            return VirtualHosts {
+                exact: HashMap::new(),
+                wildcard: Vec::new(),
+                default_host: None,
+                unknown_host: None
+            };
+        }
+
+
+        /// 가상 호스트 등록 (같은 이름은 대체)
+        ///
+        /// # Argument
+        /// pattern : Host 이름 (`example.com`) 또는 하위 도메인 전체 (`*.example.com`)
+        pub(crate) fn insert(&mut self, pattern : String, host : VirtualHost) {
+            let pattern : String = normalize_host(&pattern);
+            match pattern.strip_prefix('*') {
+                Some(suffix) => {
+                    let suffix : String = suffix.to_string();
+                    self.wildcard.retain(|(item, _)| item != &suffix);
+                    self.wildcard.push((suffix, host));
+                    // 더 구체적인 (긴) 패턴을 먼저 비교
+                    self.wildcard.sort_by_key(|(suffix, _)| std::cmp::Reverse(suffix.len()));
+                },
+                None => {
+                    self.exact.insert(pattern, host);
+                }
+            }
+        }
+
+
+        /// 일치하는 Host 가 없을 때 사용할 가상 호스트 설정
+        pub(crate) fn set_default_host(&mut self, pattern : String) {
+            self.default_host = Some(normalize_host(&pattern));
+        }
+
+
+        /// 알 수 없는 Host 응답 설정
+        pub(crate) fn set_unknown_host(&mut self, handler : ResponseHandler) {
+            self.unknown_host = Some(handler);
+        }
+
+
+        /// 요청의 Host 로 가상 호스트 찾기 (정확한 이름, 긴 Wildcard, 기본 Host 순서)
+        pub(crate) fn resolve(&self, host : Option<&str>) -> Option<&VirtualHost> {
+            if let Some(host) = host {
+                let host : String = normalize_host(strip_port(host));
+                if let Some(virtual_host) = self.find(&host) {
+                    return Some(virtual_host);
+                }
+            }
+
+            return self.default_host.as_deref().and_then(|pattern| self.find_pattern(pattern));
+        }
+
+
+        /// 알 수 없는 Host 응답 Handler
+        pub(crate) fn unknown_host(&self) -> Option<&ResponseHandler> {
+            return self.unknown_host.as_ref();
+        }
+
+
+        /// Host 이름과 일치하는 가상 호스트
+        fn find(&self, host : &str) -> Option<&VirtualHost> {
+            if let Some(virtual_host) = self.exact.get(host) {
+                return Some(virtual_host);
+            }
+
+            return self.wildcard.iter()
+                .find(|(suffix, _)| host.len() > suffix.len() && host.ends_with(suffix.as_str()))
+                .map(|(_, virtual_host)| virtual_host);
+        }
+
+
+        /// 등록한 패턴 그대로 가상 호스트 찾기
+        fn find_pattern(&self, pattern : &str) -> Option<&VirtualHost> {
+            return match pattern.strip_prefix('*') {
+                Some(suffix) => self.wildcard.iter().find(|(item, _)| item == suffix).map(|(_, virtual_host)| virtual_host),
+                None => self.exact.get(pattern)
+            };
+        }
+    }
+
+
+    /// Host 이름 비교 형식으로 변환 (소문자, 끝의 `.` 제거)
+    fn normalize_host(host : &str) -> String {
+        return host.trim().trim_end_matches('.').to_ascii_lowercase();
+    }
+
+
+    /// Host Header 에서 Port 제거 (`example.com:8080`, `[::1]:8080`)
+    fn strip_port(host : &str) -> &str {
+        let host : &str = host.trim();
+        if host.starts_with('[') {
+            return match host.find(']') {
+                Some(end) => &host[..=end],
+                None => host
+            };
+        }
+
+        return match host.rsplit_once(':') {
+            Some((name, port)) if port.bytes().all(|byte| byte.is_ascii_digit()) => name,
+            _ => host
+        };
+    }
+
+
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::server::{Server, ServerBuilder};
+        use crate::server::response_parser::response_parser::{default_state_response_writer, HttpStateCode, ResponseBody};
+        use crate::server::test_client::test_client::{TestClient, TestRequest, TestResponse};
+
+
+        /// 고정 Body 를 반환하는 Handler
+        fn text(body : &'static str) -> impl Fn(&Request) -> Response + Send + Sync + 'static {
+            return move |_request : &Request| {
+                let mut response : Response = default_state_response_writer(HttpStateCode::HTTP_200);
+                response.body = Some(ResponseBody { body_str: Some(String::from(body)) });
+                response
+            };
+        }
+
+
+        fn site(body : &'static str) -> VirtualHost {
+            return VirtualHost::new().event_response(text(body));
+        }
+
+
+        fn builder() -> ServerBuilder {
+            return Server::builder()
+                .event_response(text("server"))
+                .virtual_host(String::from("Example.com"), site("exact"))
+                .virtual_host(String::from("*.example.com"), site("wildcard"))
+                .virtual_host(String::from("*.api.example.com"), site("api wildcard"));
+        }
+
+
+        fn get(client : &TestClient, host : &str) -> TestResponse {
+            return client.send(TestRequest::get("/").header("Host", host));
+        }
+
+
+        #[test]
+        fn resolves_exact_names_before_longer_and_shorter_wildcards() {
+            let client : TestClient = TestClient::new(&builder().build());
+
+            get(&client, "example.com").assert_body("exact");
+            get(&client, "EXAMPLE.COM.:8080").assert_body("exact");
+            get(&client, "blog.example.com").assert_body("wildcard");
+            get(&client, "v1.api.example.com").assert_body("api wildcard");
+            // Wildcard 는 자기 자신의 이름과 일치하지 않는다
+            get(&client, "api.example.com").assert_body("wildcard");
+            get(&client, "other.org").assert_body("server");
+            client.get("/").assert_body("server");
+        }
+
+
+        #[test]
+        fn ignores_forwarded_host_and_rejects_duplicate_hosts() {
+            let client : TestClient = TestClient::new(&builder().build());
+
+            client.send(TestRequest::get("/").header("X-Forwarded-Host", "example.com").header("Host", "other.org")).assert_body("server");
+            client.send(TestRequest::get("/").header("Host", "other.org").header("X-Forwarded-Host", "blog.example.com")).assert_body("server");
+            client.send_raw(b"GET / HTTP/1.1\r\nHost: other.org\r\nHost: example.com\r\n\r\n").assert_status(HttpStateCode::HTTP_400);
+        }
+
+
+        #[test]
+        fn uses_default_host_then_unknown_host_response() {
+            let client : TestClient = TestClient::new(&builder().default_host(String::from("*.example.com")).build());
+            get(&client, "other.org").assert_body("wildcard");
+            get(&client, "example.com").assert_body("exact");
+
+            let client : TestClient = TestClient::new(&builder().unknown_host_response(|_request| default_state_response_writer(HttpStateCode::HTTP_404)).build());
+            get(&client, "other.org").assert_status(HttpStateCode::HTTP_404);
+            get(&client, "example.com").assert_body("exact");
+        }
+
+
+        #[test]
+        fn strips_ports_from_host_names() {
+            assert_eq!(strip_port("example.com:8080"), "example.com");
+            assert_eq!(strip_port("[::1]:8080"), "[::1]");
+            assert_eq!(strip_port("[::1]"), "[::1]");
+            assert_eq!(strip_port("example.com"), "example.com");
+            assert_eq!(normalize_host(" Example.COM. "), "example.com");
+        }
+    }
+}
@@ -0,0 +1,854 @@
+pub mod websocket {
+    use std::io::{Read, Write};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+    use crate::server::client_stream::client_stream::ClientStream;
+    use crate::server::request_parser::request_parser::{HttpVersion, Method, Request};
+    use crate::server::response_parser::response_parser::{default_http_state_writer, HttpStateCode, Response, response_parser, state_response_writer};
+    use crate::server::server_config::server_config::ServerConfig;
+    use crate::server::server_handle::server_handle::ServerState;
+
+
+    /// Sec-WebSocket-Accept 계산에 사용하는 GUID (RFC 6455)
+    const WEBSOCKET_GUID : &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+    /// 지원하는 WebSocket 버전
+    const WEBSOCKET_VERSION : &str = "13";
+
+    /// Control Frame 의 최대 Payload 크기
+    const MAX_CONTROL_PAYLOAD : usize = 125;
+
+    /// 한 번의 `read` 호출로 읽을 최대 크기
+    const READ_CHUNK_SIZE : usize = 4096;
+
+
+    /// WebSocket 메시지
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Message {
+        TEXT(String),
+        BINARY(Vec<u8>),
+        PING(Vec<u8>),
+        PONG(Vec<u8>),
+        /// 접속 종료 (상태 코드가 없는 Close Frame 은 None)
+        CLOSE(Option<CloseFrame>)
+    }
+
+    /// Close Frame 의 상태 코드와 사유
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CloseFrame {
+        pub code : u16,
+        pub reason : String
+    }
+
+    /// WebSocket 오류 Enum
+    #[derive(Debug)]
+    pub enum WebSocketError {
+        /// Close Frame 을 주고받았거나 클라이언트가 접속을 끊음
+        CLOSED,
+        /// 입출력 오류 (Timeout 포함)
+        IO(std::io::Error),
+        /// 프로토콜 위반 (마스킹 없는 Frame, 잘못된 Opcode 등, 1002 로 접속 종료)
+        PROTOCOL_ERROR(&'static str),
+        /// 잘못된 UTF-8 Text 메시지 (1007 로 접속 종료)
+        INVALID_DATA,
+        /// 최대 메시지 크기 초과 (1009 로 접속 종료)
+        MESSAGE_TOO_LARGE
+    }
+
+    /// Upgrade 가 완료된 WebSocket 접속
+    ///
+    /// Route Handler 에 전달되며, Drop 되면 Close Frame 을 보낸 뒤 접속을 닫는다.
+    /// 서버가 종료되면 기다리던 `recv` 는 Close 상태 코드 1001 (Going Away) 을 보내고 `WebSocketError::CLOSED` 를 반환한다.
+    pub struct WebSocket {
+        stream : ClientStream,
+        buffer : Vec<u8>,
+        /// 서버 실행 상태 (메시지를 기다리는 동안 종료 요청 확인)
+        state : Arc<ServerState>,
+        /// 메시지 수신 Timeout (None 이면 무기한 대기)
+        read_timeout : Option<Duration>,
+        /// 진행 중인 `recv` 의 수신 기한
+        read_deadline : Option<Instant>,
+        /// 나뉘어 받는 중인 메시지의 Opcode 와 데이터 (중간에 온 Control Frame 을 반환한 뒤에도 유지)
+        fragments : Option<(u8, Vec<u8>)>,
+        max_message_bytes : usize,
+        frame_size : usize,
+        close_sent : bool,
+        closed : bool
+    }
+
+    /// Handshake 후 Handler 실행을 기다리는 Upgrade 요청
+    pub(crate) struct WebSocketUpgrade {
+        pub(crate) request : Request,
+        /// Handshake 요청 뒤에 이미 받은 데이터
+        pub(crate) buffered : Vec<u8>
+    }
+
+    /// 수신한 Frame
+    struct Frame {
+        fin : bool,
+        opcode : u8,
+        payload : Vec<u8>
+    }
+
+
+    impl std::fmt::Display for WebSocketError {
+        fn fmt(&self, f : &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            return match self {
+                WebSocketError::CLOSED => write!(f, "WebSocket connection closed."),
+                WebSocketError::IO(error) => write!(f, "WebSocket I/O error. ({})", error),
+                WebSocketError::PROTOCOL_ERROR(reason) => write!(f, "WebSocket protocol error. ({})", reason),
+                WebSocketError::INVALID_DATA => write!(f, "WebSocket text message is not valid UTF-8."),
+                WebSocketError::MESSAGE_TOO_LARGE => write!(f, "WebSocket message too large.")
+            };
+        }
+    }
+
+
+    impl WebSocketError {
+        /// 이 오류로 접속을 닫을 때 보낼 Close 상태 코드
+        fn close_code(&self) -> Option<u16> {
+            return match self {
+                WebSocketError::PROTOCOL_ERROR(_) => Some(1002),
+                WebSocketError::INVALID_DATA => Some(1007),
+                WebSocketError::MESSAGE_TOO_LARGE => Some(1009),
+                _ => None
+            };
+        }
+    }
+
+
+    impl WebSocket {
+        /// Upgrade 된 접속으로 WebSocket 생성
+        ///
+        /// 메시지 수신 Timeout 은 없으며 (메시지를 기다리는 동안 접속 유지), `shutdown_poll_interval` 마다 서버 종료 요청을 확인한다.
+        /// 최대 메시지 크기는 `max_body_bytes` 를 따른다.
+        pub(crate) fn new(stream : ClientStream, buffered : Vec<u8>, config : &ServerConfig, state : Arc<ServerState>) -> WebSocket {
+            let _ = stream.set_read_timeout(Some(config.shutdown_poll_interval));
+
+            return WebSocket {
+                stream,
+                buffer: buffered,
+                state,
+                read_timeout: None,
+                read_deadline: None,
+                fragments: None,
+                max_message_bytes: config.max_body_bytes,
+                frame_size: 0,
+                close_sent: false,
+                closed: false
+            };
+        }
+
+
+        /// 메시지 수신 Timeout 설정 (None 이면 무기한 대기)
+        ///
+        /// Timeout 이 지나면 `recv` 는 `WebSocketError::IO` (`TimedOut`) 를 반환하며, 받던 Frame 은 다음 `recv` 에서 이어서 읽는다.
+        pub fn set_read_timeout(&mut self, timeout : Option<Duration>) -> std::io::Result<()> {
+            if timeout == Some(Duration::ZERO) {
+                return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "cannot set a 0 duration timeout"));
+            }
+            self.read_timeout = timeout;
+
+            return Ok(());
+        }
+
+
+        /// 보내는 Text / Binary 메시지를 나눌 Frame 크기 설정 (0 이면 나누지 않는다)
+        pub fn set_frame_size(&mut self, frame_size : usize) {
+            self.frame_size = frame_size;
+        }
+
+
+        /// 최대 수신 메시지 크기 설정 (여러 Frame 으로 나뉜 메시지는 합친 크기 기준)
+        pub fn set_max_message_bytes(&mut self, max_message_bytes : usize) {
+            self.max_message_bytes = max_message_bytes;
+        }
+
+
+        /// 메시지 수신
+        ///
+        /// 여러 Frame 으로 나뉜 메시지는 합쳐서 반환한다. Ping 에는 자동으로 Pong 을 보내고,
+        /// Close 를 받으면 Close 로 응답한 뒤 `Message::CLOSE` 를 반환한다.
+        /// 프로토콜 위반 시 해당 상태 코드로 Close Frame 을 보내고 오류를 반환한다.
+        pub fn recv(&mut self) -> Result<Message, WebSocketError> {
+            if self.closed {
+                return Err(WebSocketError::CLOSED);
+            }
+
+            self.read_deadline = self.read_timeout.map(|timeout| Instant::now() + timeout);
+            return match self.read_message() {
+                Ok(message) => Ok(message),
+                Err(error) => {
+                    if let Some(code) = error.close_code() {
+                        let _ = self.send_close(code, "");
+                        self.closed = true;
+                    }
+                    Err(error)
+                }
+            };
+        }
+
+
+        /// 메시지 전송 (서버가 종료 중이면 1001 로 접속을 닫고 `WebSocketError::CLOSED` 반환)
+        pub fn send(&mut self, message : Message) -> Result<(), WebSocketError> {
+            if self.close_sent {
+                return Err(WebSocketError::CLOSED);
+            }
+            if self.state.is_shutdown() {
+                let _ = self.send_close(1001, "server shutting down");
+                return Err(WebSocketError::CLOSED);
+            }
+
+            return match message {
+                Message::TEXT(text) => self.write_message(0x1, text.as_bytes()),
+                Message::BINARY(data) => self.write_message(0x2, &data),
+                Message::PING(data) => self.write_control(0x9, &data),
+                Message::PONG(data) => self.write_control(0xA, &data),
+                Message::CLOSE(Some(frame)) => self.send_close(frame.code, &frame.reason),
+                Message::CLOSE(None) => {
+                    self.close_sent = true;
+                    self.write_frame(true, 0x8, &[])
+                }
+            };
+        }
+
+
+        /// Text 메시지 전송
+        pub fn send_text(&mut self, text : &str) -> Result<(), WebSocketError> {
+            return self.send(Message::TEXT(String::from(text)));
+        }
+
+
+        /// Binary 메시지 전송
+        pub fn send_binary(&mut self, data : &[u8]) -> Result<(), WebSocketError> {
+            return self.send(Message::BINARY(data.to_vec()));
+        }
+
+
+        /// Ping 전송 (Payload 최대 125 Byte)
+        pub fn ping(&mut self, data : &[u8]) -> Result<(), WebSocketError> {
+            return self.send(Message::PING(data.to_vec()));
+        }
+
+
+        /// Pong 전송 (Payload 최대 125 Byte)
+        pub fn pong(&mut self, data : &[u8]) -> Result<(), WebSocketError> {
+            return self.send(Message::PONG(data.to_vec()));
+        }
+
+
+        /// Close Frame 전송 (이후 `recv` 로 클라이언트의 Close 응답을 받을 수 있다)
+        pub fn close(&mut self, code : u16, reason : &str) -> Result<(), WebSocketError> {
+            return self.send(Message::CLOSE(Some(CloseFrame { code, reason: String::from(reason) })));
+        }
+
+
+        /// Frame 을 읽어 메시지 하나를 완성
+        fn read_message(&mut self) -> Result<Message, WebSocketError> {
+            loop {
+                let frame : Frame = self.read_frame()?;
+                match frame.opcode {
+                    // Control Frame 은 나뉜 메시지 사이에도 올 수 있다
+                    0x8 => {
+                        let close : Option<CloseFrame> = parse_close_payload(&frame.payload)?;
+                        if !self.close_sent {
+                            let code : u16 = close.as_ref().map_or(1000, |close| close.code);
+                            let _ = self.send_close(code, "");
+                        }
+                        self.closed = true;
+                        return Ok(Message::CLOSE(close));
+                    },
+                    0x9 => {
+                        if !self.close_sent {
+                            self.write_control(0xA, &frame.payload)?;
+                        }
+                        return Ok(Message::PING(frame.payload));
+                    },
+                    0xA => return Ok(Message::PONG(frame.payload)),
+                    0x0 => {
+                        let (opcode, mut data) = match self.fragments.take() {
+                            Some(fragments) => fragments,
+                            None => return Err(WebSocketError::PROTOCOL_ERROR("continuation frame without a message"))
+                        };
+                        data.extend_from_slice(&frame.payload);
+                        if frame.fin {
+                            return to_data_message(opcode, data);
+                        }
+                        self.fragments = Some((opcode, data));
+                    },
+                    0x1 | 0x2 => {
+                        if self.fragments.is_some() {
+                            return Err(WebSocketError::PROTOCOL_ERROR("new message before the previous one finished"));
+                        }
+                        if frame.fin {
+                            return to_data_message(frame.opcode, frame.payload);
+                        }
+                        self.fragments = Some((frame.opcode, frame.payload));
+                    },
+                    _ => return Err(WebSocketError::PROTOCOL_ERROR("unknown opcode"))
+                }
+            }
+        }
+
+
+        /// Frame 하나 읽기 (클라이언트 Frame 은 반드시 마스킹되어 있어야 한다)
+        ///
+        /// Frame 전체를 받을 때까지 Buffer 에서 꺼내지 않으므로, 수신 Timeout 뒤에도 같은 Frame 을 이어서 읽을 수 있다.
+        fn read_frame(&mut self) -> Result<Frame, WebSocketError> {
+            self.fill_buffer(2)?;
+            let fin : bool = self.buffer[0] & 0x80 != 0;
+            let opcode : u8 = self.buffer[0] & 0x0F;
+            if self.buffer[0] & 0x70 != 0 {
+                return Err(WebSocketError::PROTOCOL_ERROR("reserved bits set without an extension"));
+            }
+            if self.buffer[1] & 0x80 == 0 {
+                return Err(WebSocketError::PROTOCOL_ERROR("client frame is not masked"));
+            }
+
+            let (header_length, payload_length) : (usize, u64) = match self.buffer[1] & 0x7F {
+                126 => {
+                    self.fill_buffer(4)?;
+                    (4, u16::from_be_bytes([self.buffer[2], self.buffer[3]]) as u64)
+                },
+                127 => {
+                    self.fill_buffer(10)?;
+                    let mut bytes = [0; 8];
+                    bytes.copy_from_slice(&self.buffer[2..10]);
+                    let length : u64 = u64::from_be_bytes(bytes);
+                    if length >> 63 != 0 {
+                        return Err(WebSocketError::PROTOCOL_ERROR("invalid payload length"));
+                    }
+                    (10, length)
+                },
+                length => (2, length as u64)
+            };
+
+            if opcode >= 0x8 && (!fin || payload_length > MAX_CONTROL_PAYLOAD as u64) {
+                return Err(WebSocketError::PROTOCOL_ERROR("invalid control frame"));
+            }
+            // 이어지는 Frame 은 이미 받은 부분까지 더해 제한 (마지막 Frame 포함, Payload 를 읽기 전에 확인)
+            let received : u64 = match opcode {
+                0x0 => self.fragments.as_ref().map_or(0, |(_, data)| data.len() as u64),
+                _ => 0
+            };
+            if received + payload_length > self.max_message_bytes as u64 {
+                return Err(WebSocketError::MESSAGE_TOO_LARGE);
+            }
+
+            let payload_start : usize = header_length + 4;
+            self.fill_buffer(payload_start + payload_length as usize)?;
+            let mut mask = [0; 4];
+            mask.copy_from_slice(&self.buffer[header_length..payload_start]);
+            let payload : Vec<u8> = self.buffer.drain(..payload_start + payload_length as usize)
+                .skip(payload_start)
+                .enumerate()
+                .map(|(index, byte)| byte ^ mask[index % 4])
+                .collect();
+
+            return Ok(Frame { fin, opcode, payload });
+        }
+
+
+        /// Buffer 에 `length` Byte 이상 받기
+        ///
+        /// 읽기 Timeout (`shutdown_poll_interval`) 마다 서버 종료 요청과 수신 기한을 확인한다.
+        fn fill_buffer(&mut self, length : usize) -> Result<(), WebSocketError> {
+            let mut chunk = [0; READ_CHUNK_SIZE];
+            while self.buffer.len() < length {
+                match self.stream.read(&mut chunk) {
+                    Ok(0) => {
+                        self.closed = true;
+                        return Err(WebSocketError::CLOSED);
+                    },
+                    Ok(size) => self.buffer.extend_from_slice(&chunk[..size]),
+                    Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+                    Err(error) if matches!(error.kind(), std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut) => {
+                        // 서버 종료 - Going Away 로 접속 종료
+                        if self.state.is_shutdown() {
+                            if !self.close_sent {
+                                let _ = self.send_close(1001, "server shutting down");
+                            }
+                            self.closed = true;
+                            return Err(WebSocketError::CLOSED);
+                        }
+                        if self.read_deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                            return Err(WebSocketError::IO(error));
+                        }
+                    },
+                    Err(error) => return Err(WebSocketError::IO(error))
+                }
+            }
+
+            return Ok(());
+        }
+
+
+        /// Text / Binary 메시지 전송 (`frame_size` 가 설정되어 있으면 나누어 전송)
+        fn write_message(&mut self, opcode : u8, data : &[u8]) -> Result<(), WebSocketError> {
+            if self.frame_size == 0 || data.len() <= self.frame_size {
+                return self.write_frame(true, opcode, data);
+            }
+
+            let chunks : Vec<&[u8]> = data.chunks(self.frame_size).collect();
+            let last : usize = chunks.len() - 1;
+            for (index, chunk) in chunks.into_iter().enumerate() {
+                let frame_opcode : u8 = if index == 0 { opcode } else { 0x0 };
+                self.write_frame(index == last, frame_opcode, chunk)?;
+            }
+
+            return Ok(());
+        }
+
+
+        /// Control Frame 전송
+        fn write_control(&mut self, opcode : u8, data : &[u8]) -> Result<(), WebSocketError> {
+            if data.len() > MAX_CONTROL_PAYLOAD {
+                return Err(WebSocketError::PROTOCOL_ERROR("control frame payload is larger than 125 bytes"));
+            }
+            return self.write_frame(true, opcode, data);
+        }
+
+
+        /// Close Frame 전송
+        fn send_close(&mut self, code : u16, reason : &str) -> Result<(), WebSocketError> {
+            let mut payload : Vec<u8> = code.to_be_bytes().to_vec();
+            payload.extend_from_slice(reason.as_bytes());
+            self.write_control(0x8, &payload)?;
+            self.close_sent = true;
+
+            return Ok(());
+        }
+
+
+        /// Frame 전송 (서버 Frame 은 마스킹하지 않는다)
+        fn write_frame(&mut self, fin : bool, opcode : u8, payload : &[u8]) -> Result<(), WebSocketError> {
+            let mut frame : Vec<u8> = Vec::with_capacity(payload.len() + 10);
+            frame.push(if fin { 0x80 } else { 0x00 } | opcode);
+            match payload.len() {
+                length if length <= 125 => frame.push(length as u8),
+                length if length <= u16::MAX as usize => {
+                    frame.push(126);
+                    frame.extend_from_slice(&(length as u16).to_be_bytes());
+                },
+                length => {
+                    frame.push(127);
+                    frame.extend_from_slice(&(length as u64).to_be_bytes());
+                }
+            }
+            frame.extend_from_slice(payload);
+
+            if let Err(error) = self.stream.write_all(&frame).and_then(|_| self.stream.flush()) {
+                return Err(WebSocketError::IO(error));
+            }
+
+            return Ok(());
+        }
+    }
+
+
+    impl Drop for WebSocket {
+        /// Close Frame 을 보내지 않았으면 정상 종료 (1000) 를 보낸 뒤 접속 종료
+        fn drop(&mut self) {
+            if !self.close_sent && !self.closed {
+                let _ = self.send_close(1000, "");
+            }
+            self.stream.shutdown();
+        }
+    }
+
+
+    /// 합쳐진 데이터로 Text / Binary 메시지 생성
+    fn to_data_message(opcode : u8, data : Vec<u8>) -> Result<Message, WebSocketError> {
+        if opcode == 0x2 {
+            return Ok(Message::BINARY(data));
+        }
+
+        return match String::from_utf8(data) {
+            Ok(text) => Ok(Message::TEXT(text)),
+            Err(_) => Err(WebSocketError::INVALID_DATA)
+        };
+    }
+
+
+    /// Close Frame Payload 분석
+    fn parse_close_payload(payload : &[u8]) -> Result<Option<CloseFrame>, WebSocketError> {
+        if payload.is_empty() {
+            return Ok(None);
+        }
+        if payload.len() == 1 {
+            return Err(WebSocketError::PROTOCOL_ERROR("close frame payload of one byte"));
+        }
+
+        let code : u16 = u16::from_be_bytes([payload[0], payload[1]]);
+        // 전송 가능한 상태 코드 (RFC 6455 7.4)
+        let valid_code : bool = matches!(code, 1000..=1003 | 1007..=1011 | 3000..=4999);
+        if !valid_code {
+            return Err(WebSocketError::PROTOCOL_ERROR("invalid close code"));
+        }
+        let reason : String = match String::from_utf8(payload[2..].to_vec()) {
+            Ok(reason) => reason,
+            Err(_) => return Err(WebSocketError::INVALID_DATA)
+        };
+
+        return Ok(Some(CloseFrame { code, reason }));
+    }
+
+
+    /// WebSocket Upgrade 요청 확인
+    ///
+    /// # Return
+    /// Handshake 응답에 보낼 `Sec-WebSocket-Accept` 값, Upgrade 할 수 없는 요청이면 None
+    pub fn websocket_accept(request : &Request) -> Option<String> {
+        let has_token = |name : &str, token : &str| -> bool {
+            return match request.header(name) {
+                Some(value) => value.split(',').any(|item| item.trim().eq_ignore_ascii_case(token)),
+                None => false
+            };
+        };
+
+        if request.method != Some(Method::GET)
+            || request.http_version != Some(HttpVersion::HTTP_1_1)
+            || !has_token("Upgrade", "websocket")
+            || !has_token("Connection", "Upgrade")
+            || request.header("Sec-WebSocket-Version").map(|value| value.trim()) != Some(WEBSOCKET_VERSION) {
+            return None;
+        }
+
+        // Key 는 16 Byte 값의 Base64 (24 글자)
+        let key : &str = request.header("Sec-WebSocket-Key")?.trim();
+        if key.len() != 24 || !key.ends_with("==") {
+            return None;
+        }
+
+        return Some(websocket_accept_key(key));
+    }
+
+
+    /// `Sec-WebSocket-Key` 로 `Sec-WebSocket-Accept` 값 계산
+    pub fn websocket_accept_key(key : &str) -> String {
+        return base64_encode(&sha1(format!("{}{}", key, WEBSOCKET_GUID).as_bytes()));
+    }
+
+
+    /// Handshake 성공 응답 (`101 Switching Protocols`)
+    pub(crate) fn switching_protocols_text(accept : &str) -> String {
+        return format!("HTTP/1.1 {}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+                       default_http_state_writer(&HttpStateCode::HTTP_101), accept);
+    }
+
+
+    /// Handshake 실패 응답 (`426 Upgrade Required`)
+    pub(crate) fn upgrade_required_text(config : &ServerConfig) -> String {
+        let mut response : Response = state_response_writer(HttpStateCode::HTTP_426, config);
+        response.set_header("Upgrade", String::from("websocket"));
+        response.set_header("Sec-WebSocket-Version", String::from(WEBSOCKET_VERSION));
+        response.set_header("Connection", String::from("close"));
+
+        return response_parser(response);
+    }
+
+
+    /// SHA-1 Hash (Handshake 용)
+    fn sha1(data : &[u8]) -> [u8; 20] {
+        let mut state : [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+        let mut message : Vec<u8> = data.to_vec();
+        message.push(0x80);
+        while message.len() % 64 != 56 {
+            message.push(0);
+        }
+        message.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+
+        for block in message.chunks(64) {
+            let mut words = [0u32; 80];
+            for index in 0..16 {
+                words[index] = u32::from_be_bytes([block[index * 4], block[index * 4 + 1], block[index * 4 + 2], block[index * 4 + 3]]);
+            }
+            for index in 16..80 {
+                words[index] = (words[index - 3] ^ words[index - 8] ^ words[index - 14] ^ words[index - 16]).rotate_left(1);
+            }
+
+            let [mut a, mut b, mut c, mut d, mut e] = state;
+            for (index, word) in words.iter().enumerate() {
+                let (f, k) = match index {
+                    0..=19 => ((b & c) | (!b & d), 0x5A827999),
+                    20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
+                    40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+                    _ => (b ^ c ^ d, 0xCA62C1D6)
+                };
+                let temp : u32 = a.rotate_left(5).wrapping_add(f).wrapping_add(e).wrapping_add(k).wrapping_add(*word);
+                e = d;
+                d = c;
+                c = b.rotate_left(30);
+                b = a;
+                a = temp;
+            }
+
+            state[0] = state[0].wrapping_add(a);
+            state[1] = state[1].wrapping_add(b);
+            state[2] = state[2].wrapping_add(c);
+            state[3] = state[3].wrapping_add(d);
+            state[4] = state[4].wrapping_add(e);
+        }
+
+        let mut digest = [0u8; 20];
+        for (index, value) in state.iter().enumerate() {
+            digest[index * 4..index * 4 + 4].copy_from_slice(&value.to_be_bytes());
+        }
+
+        return digest;
+    }
+
+
+    /// Base64 Encoding (Padding 포함)
+    fn base64_encode(data : &[u8]) -> String {
+        const ALPHABET : &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+        let mut encoded : String = String::with_capacity(data.len().div_ceil(3) * 4);
+        for chunk in data.chunks(3) {
+            let bytes : [u8; 3] = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+            let value : u32 = ((bytes[0] as u32) << 16) | ((bytes[1] as u32) << 8) | bytes[2] as u32;
+            for index in 0..4 {
+                if index <= chunk.len() {
+                    encoded.push(ALPHABET[((value >> (18 - index * 6)) & 0x3F) as usize] as char);
+                }else {
+                    encoded.push('=');
+                }
+            }
+        }
+
+        return encoded;
+    }
+
+
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::net::{TcpListener, TcpStream};
+        use crate::server::request_parser::request_parser::request_parser;
+
+
+        /// 클라이언트가 보내는 마스킹된 Frame
+        fn client_frame(fin : bool, opcode : u8, payload : &[u8]) -> Vec<u8> {
+            let mask : [u8; 4] = [0x12, 0x34, 0x56, 0x78];
+            let mut frame : Vec<u8> = vec![if fin { 0x80 } else { 0x00 } | opcode];
+            match payload.len() {
+                length if length < 126 => frame.push(0x80 | length as u8),
+                length => {
+                    frame.push(0x80 | 126);
+                    frame.extend_from_slice(&(length as u16).to_be_bytes());
+                }
+            }
+            frame.extend_from_slice(&mask);
+            frame.extend(payload.iter().enumerate().map(|(index, byte)| byte ^ mask[index % 4]));
+            return frame;
+        }
+
+
+        /// Frame 을 이미 받은 상태의 WebSocket 생성 (클라이언트 Socket 도 함께 반환)
+        fn open_websocket(received : Vec<u8>, max_message_bytes : usize) -> (WebSocket, TcpStream) {
+            let listener : TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let client : TcpStream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+            let (server, _) = listener.accept().unwrap();
+
+            let mut websocket : WebSocket = WebSocket::new(ClientStream::TCP(server), received, &ServerConfig::default(), Arc::new(ServerState::new()));
+            websocket.set_max_message_bytes(max_message_bytes);
+            return (websocket, client);
+        }
+
+
+        #[test]
+        fn computes_rfc6455_accept_key() {
+            assert_eq!(websocket_accept_key("dGhlIHNhbXBsZSBub25jZQ=="), "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+        }
+
+
+        #[test]
+        fn hashes_sha1_test_vectors() {
+            let hex = |digest : [u8; 20]| digest.iter().map(|byte| format!("{:02x}", byte)).collect::<String>();
+            assert_eq!(hex(sha1(b"")), "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+            assert_eq!(hex(sha1(b"abc")), "a9993e364706816aba3e25717850c26c9cd0d89d");
+            assert_eq!(hex(sha1(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")), "84983e441c3bd26ebaae4aa1f95129e5e54670f1");
+        }
+
+
+        #[test]
+        fn encodes_base64_with_padding() {
+            assert_eq!(base64_encode(b""), "");
+            assert_eq!(base64_encode(b"f"), "Zg==");
+            assert_eq!(base64_encode(b"fo"), "Zm8=");
+            assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+        }
+
+
+        #[test]
+        fn accepts_only_valid_handshake_requests() {
+            let request = |lines : &[&str]| request_parser(&lines.to_vec());
+            let valid : Vec<&str> = vec!["GET /chat HTTP/1.1", "Host: example.com", "Upgrade: websocket", "Connection: keep-alive, Upgrade",
+                                         "Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==", "Sec-WebSocket-Version: 13"];
+            assert_eq!(websocket_accept(&request(&valid)).as_deref(), Some("s3pPLMBiTxaQ9kYGzzhZRbK+xOo="));
+
+            let mut post : Vec<&str> = valid.clone();
+            post[0] = "POST /chat HTTP/1.1";
+            assert!(websocket_accept(&request(&post)).is_none());
+
+            let mut old_version : Vec<&str> = valid.clone();
+            old_version[5] = "Sec-WebSocket-Version: 8";
+            assert!(websocket_accept(&request(&old_version)).is_none());
+
+            let mut bad_key : Vec<&str> = valid.clone();
+            bad_key[4] = "Sec-WebSocket-Key: short";
+            assert!(websocket_accept(&request(&bad_key)).is_none());
+        }
+
+
+        #[test]
+        fn reassembles_fragmented_messages_around_control_frames() {
+            let mut received : Vec<u8> = client_frame(false, 0x1, b"Hel");
+            received.extend(client_frame(true, 0x9, b"ping"));
+            received.extend(client_frame(true, 0x0, b"lo"));
+            let (mut websocket, _client) = open_websocket(received, 16);
+
+            assert_eq!(websocket.recv().unwrap(), Message::PING(b"ping".to_vec()));
+            assert_eq!(websocket.recv().unwrap(), Message::TEXT(String::from("Hello")));
+        }
+
+
+        #[test]
+        fn limits_fragmented_message_size_including_final_frame() {
+            // 마지막 Frame 을 더하면 제한을 넘는 메시지
+            let mut received : Vec<u8> = client_frame(false, 0x2, &[1; 6]);
+            received.extend(client_frame(true, 0x0, &[2; 6]));
+            let (mut websocket, _client) = open_websocket(received, 10);
+            assert!(matches!(websocket.recv(), Err(WebSocketError::MESSAGE_TOO_LARGE)));
+
+            // 제한과 같은 크기는 허용
+            let mut received : Vec<u8> = client_frame(false, 0x2, &[1; 6]);
+            received.extend(client_frame(true, 0x0, &[2; 4]));
+            let (mut websocket, _client) = open_websocket(received, 10);
+            assert_eq!(websocket.recv().unwrap(), Message::BINARY(vec![1, 1, 1, 1, 1, 1, 2, 2, 2, 2]));
+        }
+
+
+        #[test]
+        fn keeps_partial_frames_across_read_timeouts() {
+            let frame : Vec<u8> = client_frame(true, 0x1, b"hello");
+            let (mut websocket, mut client) = open_websocket(Vec::new(), 16);
+            websocket.set_read_timeout(Some(Duration::from_millis(100))).unwrap();
+
+            client.write_all(&frame[..4]).unwrap();
+            assert!(matches!(websocket.recv(), Err(WebSocketError::IO(error)) if error.kind() == std::io::ErrorKind::WouldBlock || error.kind() == std::io::ErrorKind::TimedOut));
+
+            client.write_all(&frame[4..]).unwrap();
+            assert_eq!(websocket.recv().unwrap(), Message::TEXT(String::from("hello")));
+        }
+
+
+        #[test]
+        fn closes_with_going_away_when_the_server_shuts_down() {
+            let listener : TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let mut client : TcpStream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+            let (server, _) = listener.accept().unwrap();
+            let state : Arc<ServerState> = Arc::new(ServerState::new());
+            let mut websocket : WebSocket = WebSocket::new(ClientStream::TCP(server), Vec::new(), &ServerConfig::default(), Arc::clone(&state));
+
+            let waiting = std::thread::spawn(move || websocket.recv().is_err_and(|error| matches!(error, WebSocketError::CLOSED)));
+            std::thread::sleep(Duration::from_millis(100));
+            state.request_shutdown();
+            assert!(waiting.join().unwrap());
+
+            // 1001 Close Frame
+            let mut head = [0; 4];
+            client.read_exact(&mut head).unwrap();
+            assert_eq!(head[0], 0x88);
+            assert_eq!(u16::from_be_bytes([head[2], head[3]]), 1001);
+        }
+
+
+        /// Handshake 요청을 보내고 응답 Header 를 읽기
+        fn send_handshake(address : std::net::SocketAddr, extra_header : &str) -> (TcpStream, String) {
+            let mut client : TcpStream = TcpStream::connect(address).unwrap();
+            client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+            let request : String = format!("GET /chat HTTP/1.1\r\nHost: localhost\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+                                           Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n{}\r\n", extra_header);
+            client.write_all(request.as_bytes()).unwrap();
+
+            let mut head : Vec<u8> = Vec::new();
+            let mut byte = [0; 1];
+            while !head.ends_with(b"\r\n\r\n") && client.read(&mut byte).unwrap() == 1 {
+                head.push(byte[0]);
+            }
+            return (client, String::from_utf8(head).unwrap());
+        }
+
+
+        #[test]
+        fn runs_site_middleware_before_the_handshake() {
+            use crate::server::Server;
+            use crate::server::middleware::middleware::Next;
+            use crate::server::response_parser::response_parser::default_state_response_writer;
+
+            let handle = Server::builder()
+                .bind(String::from("127.0.0.1"), 0)
+                .middleware(|request : &mut Request, next : Next<'_>| {
+                    let user : String = match request.header("Authorization") {
+                        Some(token) => token.replace("Bearer ", ""),
+                        None => return default_state_response_writer(HttpStateCode::HTTP_401)
+                    };
+                    request.http_header.get_or_insert_with(Default::default).insert(String::from("X-User"), user);
+                    next.run(request)
+                })
+                .websocket(String::from("/chat"), |request, mut websocket| {
+                    let _ = websocket.send_text(request.header("X-User").map_or("", String::as_str));
+                })
+                .build()
+                .start()
+                .unwrap();
+
+            let (mut client, head) = send_handshake(handle.local_addr(), "");
+            assert!(head.starts_with("HTTP/1.1 401"), "{}", head);
+            // 응답 Body 뒤에 접속 종료
+            assert!(client.read_to_end(&mut Vec::new()).is_ok());
+
+            let (mut client, head) = send_handshake(handle.local_addr(), "Authorization: Bearer alice\r\n");
+            assert!(head.starts_with("HTTP/1.1 101"), "{}", head);
+            let mut frame = [0; 7];
+            client.read_exact(&mut frame).unwrap();
+            assert_eq!(&frame, b"\x81\x05alice");
+
+            handle.shutdown();
+        }
+
+
+        #[test]
+        fn server_shutdown_closes_open_websockets() {
+            use crate::server::Server;
+
+            let handle = Server::builder()
+                .bind(String::from("127.0.0.1"), 0)
+                .websocket(String::from("/chat"), |_request, mut websocket| {
+                    while websocket.recv().is_ok() {}
+                })
+                .build()
+                .start()
+                .unwrap();
+
+            let (mut client, head) = send_handshake(handle.local_addr(), "");
+            assert!(head.starts_with("HTTP/1.1 101"), "{}", head);
+            assert!(handle.shutdown_with_timeout(Duration::from_secs(5)));
+
+            let mut frame = [0; 4];
+            client.read_exact(&mut frame).unwrap();
+            assert_eq!(frame[0], 0x88);
+            assert_eq!(u16::from_be_bytes([frame[2], frame[3]]), 1001);
+        }
+
+
+        #[test]
+        fn rejects_unmasked_frames() {
+            let (mut websocket, _client) = open_websocket(vec![0x81, 0x02, b'h', b'i'], 16);
+            assert!(matches!(websocket.recv(), Err(WebSocketError::PROTOCOL_ERROR(_))));
+        }
+    }
+}